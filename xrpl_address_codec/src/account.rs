@@ -0,0 +1,73 @@
+use crate::alloc::string::String;
+use crate::{decode_with_version, encode_with_version, AddressCodecError, ACCOUNT_ID_VERSION};
+use xrpl_types::AccountId;
+
+/// Encodes the given account id as classic address (`r...`), see <https://xrpl.org/accounts.html#address-encoding>
+pub fn encode_classic_address(account_id: &AccountId) -> String {
+    encode_with_version(&ACCOUNT_ID_VERSION, &account_id.0)
+}
+
+/// Decodes the given classic address (`r...`) into an account id, see <https://xrpl.org/accounts.html#address-encoding>
+pub fn decode_classic_address(address: &str) -> Result<AccountId, AddressCodecError> {
+    let decoded = decode_with_version(&ACCOUNT_ID_VERSION, 20, address)?;
+    Ok(AccountId(
+        decoded.try_into().expect("length checked when decoding"),
+    ))
+}
+
+/// Checks if the given string is a valid classic address
+pub fn is_valid_classic_address(address: &str) -> bool {
+    decode_classic_address(address).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+
+    #[test]
+    fn test_encode_classic_address() {
+        let account_id = AccountId(
+            hex::decode("4b4e9c06f24296074f7bc48f92a97916c6dc5ea9")
+                .unwrap()
+                .try_into()
+                .unwrap(),
+        );
+        assert_eq!(
+            encode_classic_address(&account_id),
+            "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"
+        );
+    }
+
+    #[test]
+    fn test_decode_classic_address() {
+        let account_id = decode_classic_address("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn").unwrap();
+        assert_eq!(
+            hex::encode(account_id.0),
+            "4b4e9c06f24296074f7bc48f92a97916c6dc5ea9"
+        );
+    }
+
+    #[test]
+    fn test_decode_classic_address_invalid_checksum() {
+        let result = decode_classic_address("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpm");
+        assert_matches!(result, Err(AddressCodecError::InvalidChecksum));
+    }
+
+    #[test]
+    fn test_decode_classic_address_invalid_version() {
+        let result = decode_classic_address("sn259rEFXrQrWyx3Q7XneWcwV6dfL");
+        assert_matches!(result, Err(AddressCodecError::InvalidVersion(_)));
+    }
+
+    #[test]
+    fn test_is_valid_classic_address() {
+        assert!(is_valid_classic_address(
+            "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"
+        ));
+        assert!(!is_valid_classic_address(
+            "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpm"
+        ));
+        assert!(!is_valid_classic_address("r3wVnsK"));
+    }
+}
@@ -0,0 +1,108 @@
+use crate::alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use crate::AddressCodecError;
+
+/// Version prefix of classic addresses (`r...`), see <https://xrpl.org/base58-encodings.html>
+pub const ACCOUNT_ID_VERSION: [u8; 1] = [0x00];
+
+/// Version prefix of node public keys (`n...`), see <https://xrpl.org/base58-encodings.html>
+pub const NODE_PUBLIC_KEY_VERSION: [u8; 1] = [0x1C];
+
+/// Version prefix of account public keys (`a...`), see <https://xrpl.org/base58-encodings.html>
+pub const ACCOUNT_PUBLIC_KEY_VERSION: [u8; 1] = [0x23];
+
+/// Version prefix of secp256k1 family seeds (`s...`), see <https://xrpl.org/base58-encodings.html>
+pub const FAMILY_SEED_VERSION: [u8; 1] = [0x21];
+
+/// Version prefix of Ed25519 seeds (`sEd...`), see <https://xrpl.org/cryptographic-keys.html#seed>
+pub const ED25519_SEED_VERSION: [u8; 3] = [0x01, 0xE1, 0x4B];
+
+/// Version prefix of mainnet X-addresses (`X...`), see <https://github.com/XRPLF/XRPL-Standards/tree/master/XLS-0005d-tagged-addresses>
+pub const X_ADDRESS_MAINNET_VERSION: [u8; 2] = [0x05, 0x44];
+
+/// Version prefix of testnet X-addresses (`T...`), see <https://github.com/XRPLF/XRPL-Standards/tree/master/XLS-0005d-tagged-addresses>
+pub const X_ADDRESS_TESTNET_VERSION: [u8; 2] = [0x04, 0x93];
+
+/// Encodes the given payload with the given version prefix in base58check with the
+/// XRPL alphabet, see <https://xrpl.org/base58-encodings.html>
+pub fn encode_with_version(version: &[u8], payload: &[u8]) -> String {
+    let mut bytes = Vec::with_capacity(version.len() + payload.len());
+    bytes.extend_from_slice(version);
+    bytes.extend_from_slice(payload);
+    bs58::encode(bytes)
+        .with_alphabet(bs58::Alphabet::RIPPLE)
+        .with_check()
+        .into_string()
+}
+
+/// Decodes the given base58check string with the XRPL alphabet, verifying that it starts
+/// with the given version prefix and that the payload has the expected length. Returns
+/// the payload without the version prefix.
+pub fn decode_with_version(
+    version: &[u8],
+    expected_length: usize,
+    encoded: &str,
+) -> Result<Vec<u8>, AddressCodecError> {
+    let decoded = bs58::decode(encoded)
+        .with_alphabet(bs58::Alphabet::RIPPLE)
+        .with_check(None)
+        .into_vec()
+        .map_err(|err| match err {
+            bs58::decode::Error::InvalidChecksum { .. } => AddressCodecError::InvalidChecksum,
+            err => AddressCodecError::InvalidEncoding(err.to_string()),
+        })?;
+
+    if !decoded.starts_with(version) {
+        return Err(AddressCodecError::InvalidVersion(format!(
+            "expected version prefix {}",
+            hex_string(version)
+        )));
+    }
+
+    let payload = &decoded[version.len()..];
+
+    if payload.len() != expected_length {
+        return Err(AddressCodecError::InvalidLength(format!(
+            "payload does not encode exactly {} bytes",
+            expected_length
+        )));
+    }
+
+    Ok(payload.to_vec())
+}
+
+fn hex_string(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02X}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+
+    #[test]
+    fn test_encode_decode_with_version() {
+        let encoded = encode_with_version(&ED25519_SEED_VERSION, &[0x4C; 16]);
+        assert!(encoded.starts_with("sEd"), "encoded: {encoded}");
+        let decoded = decode_with_version(&ED25519_SEED_VERSION, 16, &encoded).unwrap();
+        assert_eq!(decoded, [0x4C; 16]);
+    }
+
+    #[test]
+    fn test_decode_with_version_invalid_character() {
+        let result = decode_with_version(&ACCOUNT_ID_VERSION, 20, "r0");
+        assert_matches!(result, Err(AddressCodecError::InvalidEncoding(_)));
+    }
+
+    #[test]
+    fn test_decode_with_version_invalid_length() {
+        let encoded = encode_with_version(&ACCOUNT_ID_VERSION, &[0x01; 19]);
+        let result = decode_with_version(&ACCOUNT_ID_VERSION, 20, &encoded);
+        assert_matches!(result, Err(AddressCodecError::InvalidLength(message)) => {
+            assert!(message.contains("does not encode exactly 20 bytes"), "message: {message}");
+        });
+    }
+}
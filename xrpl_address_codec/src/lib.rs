@@ -1,3 +1,22 @@
 //! Serialization for XRPL Protocol addresses.
+//!
+//! Encodes and decodes the base58 payloads used by the XRP Ledger: family seeds,
+//! node and account public keys, classic addresses and X-addresses. See
+//! <https://xrpl.org/base58-encodings.html>
 
-// #TODO
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
+mod account;
+mod codec;
+mod error;
+mod public_key;
+mod seed;
+mod x_address;
+
+pub use account::*;
+pub use codec::*;
+pub use error::*;
+pub use public_key::*;
+pub use seed::*;
+pub use x_address::*;
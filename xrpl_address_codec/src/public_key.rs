@@ -0,0 +1,92 @@
+use crate::alloc::string::String;
+use crate::{
+    decode_with_version, encode_with_version, AddressCodecError, ACCOUNT_PUBLIC_KEY_VERSION,
+    NODE_PUBLIC_KEY_VERSION,
+};
+
+/// Length of public keys in bytes. secp256k1 public keys are compressed and Ed25519 public
+/// keys are prefixed with `0xED`, see <https://xrpl.org/cryptographic-keys.html#public-key>
+pub const PUBLIC_KEY_LENGTH: usize = 33;
+
+/// Encodes the given node (validator) public key (`n...`), see <https://xrpl.org/base58-encodings.html>
+pub fn encode_node_public_key(public_key: &[u8; PUBLIC_KEY_LENGTH]) -> String {
+    encode_with_version(&NODE_PUBLIC_KEY_VERSION, public_key)
+}
+
+/// Decodes the given node (validator) public key (`n...`), see <https://xrpl.org/base58-encodings.html>
+pub fn decode_node_public_key(
+    public_key: &str,
+) -> Result<[u8; PUBLIC_KEY_LENGTH], AddressCodecError> {
+    let decoded = decode_with_version(&NODE_PUBLIC_KEY_VERSION, PUBLIC_KEY_LENGTH, public_key)?;
+    Ok(decoded.try_into().expect("length checked when decoding"))
+}
+
+/// Encodes the given account public key (`a...`), see <https://xrpl.org/base58-encodings.html>
+pub fn encode_account_public_key(public_key: &[u8; PUBLIC_KEY_LENGTH]) -> String {
+    encode_with_version(&ACCOUNT_PUBLIC_KEY_VERSION, public_key)
+}
+
+/// Decodes the given account public key (`a...`), see <https://xrpl.org/base58-encodings.html>
+pub fn decode_account_public_key(
+    public_key: &str,
+) -> Result<[u8; PUBLIC_KEY_LENGTH], AddressCodecError> {
+    let decoded = decode_with_version(&ACCOUNT_PUBLIC_KEY_VERSION, PUBLIC_KEY_LENGTH, public_key)?;
+    Ok(decoded.try_into().expect("length checked when decoding"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+
+    #[test]
+    fn test_encode_node_public_key() {
+        let public_key =
+            hex::decode("0388E5BA87A000CB807240DF8C848EB0B5FFA5C8E5A521BC8E105C0F0A44217828")
+                .unwrap();
+        assert_eq!(
+            encode_node_public_key(&public_key.try_into().unwrap()),
+            "n9MXXueo837zYH36DvMc13BwHcqtfAWNJY5czWVbp7uYTj7x17TH"
+        );
+    }
+
+    #[test]
+    fn test_decode_node_public_key() {
+        let public_key =
+            decode_node_public_key("n9MXXueo837zYH36DvMc13BwHcqtfAWNJY5czWVbp7uYTj7x17TH").unwrap();
+        assert_eq!(
+            hex::encode_upper(public_key),
+            "0388E5BA87A000CB807240DF8C848EB0B5FFA5C8E5A521BC8E105C0F0A44217828"
+        );
+    }
+
+    #[test]
+    fn test_encode_account_public_key() {
+        let public_key =
+            hex::decode("023693F15967AE357D0327974AD46FE3C127113B1110D6044FD41E723689F81CC6")
+                .unwrap();
+        assert_eq!(
+            encode_account_public_key(&public_key.try_into().unwrap()),
+            "aB44YfzW24VDEJQ2UuLPV2PvqcPCSoLnL7y5M1EzhdW4LnK5xMS3"
+        );
+    }
+
+    #[test]
+    fn test_decode_account_public_key() {
+        let public_key =
+            decode_account_public_key("aB44YfzW24VDEJQ2UuLPV2PvqcPCSoLnL7y5M1EzhdW4LnK5xMS3")
+                .unwrap();
+        assert_eq!(
+            hex::encode_upper(public_key),
+            "023693F15967AE357D0327974AD46FE3C127113B1110D6044FD41E723689F81CC6"
+        );
+    }
+
+    /// A node public key is not a valid account public key
+    #[test]
+    fn test_decode_account_public_key_invalid_version() {
+        let result =
+            decode_account_public_key("n9MXXueo837zYH36DvMc13BwHcqtfAWNJY5czWVbp7uYTj7x17TH");
+        assert_matches!(result, Err(AddressCodecError::InvalidVersion(_)));
+    }
+}
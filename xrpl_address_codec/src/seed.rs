@@ -0,0 +1,105 @@
+use crate::alloc::string::String;
+use crate::{
+    decode_with_version, encode_with_version, AddressCodecError, ED25519_SEED_VERSION,
+    FAMILY_SEED_VERSION,
+};
+
+/// Length of the seed entropy in bytes, see <https://xrpl.org/cryptographic-keys.html#seed>
+pub const SEED_LENGTH: usize = 16;
+
+/// Signing algorithm of the keys derived from a seed, see <https://xrpl.org/cryptographic-keys.html#signing-algorithms>
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum KeyType {
+    Secp256k1,
+    Ed25519,
+}
+
+/// Encodes the given seed entropy, see <https://xrpl.org/cryptographic-keys.html#seed>.
+/// secp256k1 seeds are encoded as family seeds (`s...`), Ed25519 seeds have the `sEd...` prefix.
+pub fn encode_seed(entropy: &[u8; SEED_LENGTH], key_type: KeyType) -> String {
+    match key_type {
+        KeyType::Secp256k1 => encode_with_version(&FAMILY_SEED_VERSION, entropy),
+        KeyType::Ed25519 => encode_with_version(&ED25519_SEED_VERSION, entropy),
+    }
+}
+
+/// Decodes the given seed into its entropy and the key type of the keys derived from it,
+/// see <https://xrpl.org/cryptographic-keys.html#seed>
+pub fn decode_seed(seed: &str) -> Result<([u8; SEED_LENGTH], KeyType), AddressCodecError> {
+    let (decoded, key_type) = match decode_with_version(&ED25519_SEED_VERSION, SEED_LENGTH, seed) {
+        Ok(decoded) => (decoded, KeyType::Ed25519),
+        Err(AddressCodecError::InvalidVersion(_)) => (
+            decode_with_version(&FAMILY_SEED_VERSION, SEED_LENGTH, seed)?,
+            KeyType::Secp256k1,
+        ),
+        Err(err) => return Err(err),
+    };
+
+    let entropy = decoded.try_into().expect("length checked when decoding");
+
+    Ok((entropy, key_type))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+
+    #[test]
+    fn test_encode_secp256k1_seed() {
+        let entropy = hex::decode("CF2DE378FBDD7E2EE87D486DFB5A7BFF").unwrap();
+        let seed = encode_seed(&entropy.try_into().unwrap(), KeyType::Secp256k1);
+        assert_eq!(seed, "sn259rEFXrQrWyx3Q7XneWcwV6dfL");
+    }
+
+    #[test]
+    fn test_decode_secp256k1_seed() {
+        let (entropy, key_type) = decode_seed("sn259rEFXrQrWyx3Q7XneWcwV6dfL").unwrap();
+        assert_eq!(
+            hex::encode_upper(entropy),
+            "CF2DE378FBDD7E2EE87D486DFB5A7BFF"
+        );
+        assert_eq!(key_type, KeyType::Secp256k1);
+    }
+
+    #[test]
+    fn test_encode_ed25519_seed() {
+        let entropy = hex::decode("4C3A1D213FBDFB14C7C28D609469B341").unwrap();
+        let seed = encode_seed(&entropy.try_into().unwrap(), KeyType::Ed25519);
+        assert_eq!(seed, "sEdTM1uX8pu2do5XvTnutH6HsouMaM2");
+    }
+
+    #[test]
+    fn test_decode_ed25519_seed() {
+        let (entropy, key_type) = decode_seed("sEdTM1uX8pu2do5XvTnutH6HsouMaM2").unwrap();
+        assert_eq!(
+            hex::encode_upper(entropy),
+            "4C3A1D213FBDFB14C7C28D609469B341"
+        );
+        assert_eq!(key_type, KeyType::Ed25519);
+    }
+
+    /// Test decoding the seed of the genesis account, created from the passphrase `masterpassphrase`
+    #[test]
+    fn test_decode_master_seed() {
+        let (entropy, key_type) = decode_seed("snoPBrXtMeMyMHUVTgbuqAfg1SUTb").unwrap();
+        assert_eq!(
+            hex::encode_upper(entropy),
+            "DEDCE9CE67B451D852FD4E846FCDE31C"
+        );
+        assert_eq!(key_type, KeyType::Secp256k1);
+    }
+
+    #[test]
+    fn test_decode_seed_invalid_checksum() {
+        let result = decode_seed("sn259rEFXrQrWyx3Q7XneWcwV6dfM");
+        assert_matches!(result, Err(AddressCodecError::InvalidChecksum));
+    }
+
+    /// A classic address is not a valid seed
+    #[test]
+    fn test_decode_seed_invalid_version() {
+        let result = decode_seed("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn");
+        assert_matches!(result, Err(AddressCodecError::InvalidVersion(_)));
+    }
+}
@@ -0,0 +1,277 @@
+use crate::alloc::{format, string::String, vec::Vec};
+use crate::{
+    decode_classic_address, decode_with_version, encode_classic_address, encode_with_version,
+    AddressCodecError, X_ADDRESS_MAINNET_VERSION, X_ADDRESS_TESTNET_VERSION,
+};
+use xrpl_types::{AccountId, UInt32};
+
+/// Length of the X-address payload: account id, tag flag, 32-bit tag and 32 reserved bits
+const X_ADDRESS_PAYLOAD_LENGTH: usize = 20 + 1 + 4 + 4;
+
+/// The network an X-address is intended for
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Network {
+    Mainnet,
+    Testnet,
+}
+
+/// An account id combined with an optional destination tag, encoded as a single address.
+/// See <https://xrpl.org/addresses.html#x-addresses> and
+/// <https://github.com/XRPLF/XRPL-Standards/tree/master/XLS-0005d-tagged-addresses>
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct XAddress {
+    pub account_id: AccountId,
+    pub tag: Option<UInt32>,
+    pub network: Network,
+}
+
+impl XAddress {
+    pub fn new(account_id: AccountId, tag: Option<UInt32>, network: Network) -> Self {
+        Self {
+            account_id,
+            tag,
+            network,
+        }
+    }
+
+    pub fn is_test_network(&self) -> bool {
+        self.network == Network::Testnet
+    }
+
+    /// Encodes the X-address (`X...` on mainnet, `T...` on testnet)
+    pub fn encode(&self) -> String {
+        encode_x_address(&self.account_id, self.tag, self.network)
+    }
+
+    /// The classic address (`r...`) of the account
+    pub fn classic_address(&self) -> String {
+        encode_classic_address(&self.account_id)
+    }
+}
+
+impl From<XAddress> for (AccountId, Option<UInt32>) {
+    fn from(x_address: XAddress) -> Self {
+        (x_address.account_id, x_address.tag)
+    }
+}
+
+/// Encodes the given account id and optional tag as X-address
+pub fn encode_x_address(account_id: &AccountId, tag: Option<UInt32>, network: Network) -> String {
+    let mut payload = Vec::with_capacity(X_ADDRESS_PAYLOAD_LENGTH);
+    payload.extend_from_slice(&account_id.0);
+    payload.push(tag.is_some() as u8);
+    payload.extend_from_slice(&tag.unwrap_or(0).to_le_bytes());
+    // Reserved for 64-bit tags
+    payload.extend_from_slice(&[0u8; 4]);
+
+    let version = match network {
+        Network::Mainnet => &X_ADDRESS_MAINNET_VERSION,
+        Network::Testnet => &X_ADDRESS_TESTNET_VERSION,
+    };
+
+    encode_with_version(version, &payload)
+}
+
+/// Decodes the given X-address into account id, optional tag and network
+pub fn decode_x_address(x_address: &str) -> Result<XAddress, AddressCodecError> {
+    let (payload, network) = match decode_with_version(
+        &X_ADDRESS_MAINNET_VERSION,
+        X_ADDRESS_PAYLOAD_LENGTH,
+        x_address,
+    ) {
+        Ok(payload) => (payload, Network::Mainnet),
+        Err(AddressCodecError::InvalidVersion(_)) => (
+            decode_with_version(
+                &X_ADDRESS_TESTNET_VERSION,
+                X_ADDRESS_PAYLOAD_LENGTH,
+                x_address,
+            )?,
+            Network::Testnet,
+        ),
+        Err(err) => return Err(err),
+    };
+
+    let account_id = AccountId(payload[0..20].try_into().expect("length 20"));
+    let tag = UInt32::from_le_bytes(payload[21..25].try_into().expect("length 4"));
+
+    if payload[25..29] != [0u8; 4] {
+        return Err(AddressCodecError::InvalidTag(
+            "64-bit tags are not supported".into(),
+        ));
+    }
+
+    let tag = match payload[20] {
+        0 if tag == 0 => None,
+        0 => {
+            return Err(AddressCodecError::InvalidTag(format!(
+                "tag {} present but tag flag not set",
+                tag
+            )))
+        }
+        1 => Some(tag),
+        flag => {
+            return Err(AddressCodecError::InvalidTag(format!(
+                "unsupported tag flag {}",
+                flag
+            )))
+        }
+    };
+
+    Ok(XAddress {
+        account_id,
+        tag,
+        network,
+    })
+}
+
+/// Converts the given classic address and optional tag to an X-address
+pub fn classic_address_to_x_address(
+    classic_address: &str,
+    tag: Option<UInt32>,
+    network: Network,
+) -> Result<String, AddressCodecError> {
+    let account_id = decode_classic_address(classic_address)?;
+    Ok(encode_x_address(&account_id, tag, network))
+}
+
+/// Converts the given X-address to a classic address and an optional tag
+pub fn x_address_to_classic_address(
+    x_address: &str,
+) -> Result<(String, Option<UInt32>), AddressCodecError> {
+    let x_address = decode_x_address(x_address)?;
+    Ok((x_address.classic_address(), x_address.tag))
+}
+
+/// Checks if the given string is a valid X-address
+pub fn is_valid_x_address(x_address: &str) -> bool {
+    decode_x_address(x_address).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+    use xrpl_types::{Amount, PaymentTransaction};
+
+    const CLASSIC_ADDRESS: &str = "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf";
+
+    #[test]
+    fn test_encode_x_address_no_tag() {
+        let account_id = decode_classic_address(CLASSIC_ADDRESS).unwrap();
+        assert_eq!(
+            encode_x_address(&account_id, None, Network::Mainnet),
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb"
+        );
+        assert_eq!(
+            encode_x_address(&account_id, None, Network::Testnet),
+            "TVE26TYGhfLC7tQDno7G8dGtxSkYQn49b3qD26PK7FcGSKE"
+        );
+    }
+
+    #[test]
+    fn test_encode_x_address_with_tag() {
+        let account_id = decode_classic_address(CLASSIC_ADDRESS).unwrap();
+        assert_eq!(
+            encode_x_address(&account_id, Some(0), Network::Mainnet),
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV8AqEL4xcZj5whKbmc"
+        );
+        assert_eq!(
+            encode_x_address(&account_id, Some(0), Network::Testnet),
+            "TVE26TYGhfLC7tQDno7G8dGtxSkYQnSy8RHqGHoGJ59spi2"
+        );
+        assert_eq!(
+            encode_x_address(&account_id, Some(1), Network::Mainnet),
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC"
+        );
+        assert_eq!(
+            encode_x_address(&account_id, Some(1), Network::Testnet),
+            "TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw"
+        );
+    }
+
+    #[test]
+    fn test_decode_x_address_no_tag() {
+        let x_address =
+            decode_x_address("XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb").unwrap();
+        assert_eq!(x_address.classic_address(), CLASSIC_ADDRESS);
+        assert_eq!(x_address.tag, None);
+        assert_eq!(x_address.network, Network::Mainnet);
+    }
+
+    #[test]
+    fn test_decode_x_address_with_tag() {
+        let x_address =
+            decode_x_address("TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw").unwrap();
+        assert_eq!(x_address.classic_address(), CLASSIC_ADDRESS);
+        assert_eq!(x_address.tag, Some(1));
+        assert!(x_address.is_test_network());
+    }
+
+    #[test]
+    fn test_decode_x_address_zero_tag() {
+        let x_address =
+            decode_x_address("XVLhHMPHU98es4dbozjVtdWzVrDjtV8AqEL4xcZj5whKbmc").unwrap();
+        assert_eq!(x_address.tag, Some(0));
+    }
+
+    #[test]
+    fn test_x_address_max_tag_roundtrip() {
+        let x_address = XAddress::new(
+            decode_classic_address(CLASSIC_ADDRESS).unwrap(),
+            Some(u32::MAX),
+            Network::Mainnet,
+        );
+        assert_eq!(decode_x_address(&x_address.encode()).unwrap(), x_address);
+    }
+
+    #[test]
+    fn test_classic_address_x_address_conversion() {
+        let x_address =
+            classic_address_to_x_address(CLASSIC_ADDRESS, Some(12345), Network::Mainnet).unwrap();
+        assert_eq!(
+            x_address_to_classic_address(&x_address).unwrap(),
+            (CLASSIC_ADDRESS.into(), Some(12345))
+        );
+    }
+
+    #[test]
+    fn test_decode_x_address_invalid_version() {
+        let result = decode_x_address(CLASSIC_ADDRESS);
+        assert_matches!(result, Err(AddressCodecError::InvalidVersion(_)));
+    }
+
+    #[test]
+    fn test_decode_x_address_invalid_tag_flag() {
+        let mut payload = [0u8; X_ADDRESS_PAYLOAD_LENGTH];
+        payload[20] = 2;
+        let x_address = encode_with_version(&X_ADDRESS_MAINNET_VERSION, &payload);
+        let result = decode_x_address(&x_address);
+        assert_matches!(result, Err(AddressCodecError::InvalidTag(message)) => {
+            assert!(message.contains("unsupported tag flag 2"), "message: {message}");
+        });
+    }
+
+    #[test]
+    fn test_is_valid_x_address() {
+        assert!(is_valid_x_address(
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV8AqEL4xcZj5whKbmc"
+        ));
+        assert!(!is_valid_x_address(CLASSIC_ADDRESS));
+    }
+
+    /// Test building a payment to a decoded X-address
+    #[test]
+    fn test_payment_to_x_address() {
+        let x_address =
+            decode_x_address("XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC").unwrap();
+        let (destination, destination_tag) = x_address.into();
+        let tx = PaymentTransaction::new_with_destination_tag(
+            AccountId::from_address("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn").unwrap(),
+            Amount::drops(1_000).unwrap(),
+            destination,
+            destination_tag.unwrap(),
+        );
+        assert_eq!(tx.destination.to_address(), CLASSIC_ADDRESS);
+        assert_eq!(tx.destination_tag, Some(1));
+    }
+}
@@ -60,6 +60,9 @@ pub use tx::*;
 
 // Path and Orderbook methods
 
+pub mod amm_info;
+pub use amm_info::*;
+
 pub mod book_offers;
 pub use book_offers::*;
 
@@ -0,0 +1,194 @@
+//! The amm_info method gets information about an Automated Market Maker (AMM)
+//! instance, selected by the assets of its pool or by its account.
+//!
+//! <https://xrpl.org/amm_info.html>
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Amount, Currency, Request, RetrieveLedgerSpec, ReturnLedgerSpec, WithLedgerSpec};
+
+#[derive(Default, Debug, Clone, Serialize)]
+pub struct AmmInfoRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    asset: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    asset2: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    amm_account: Option<String>,
+    #[serde(flatten)]
+    pub ledger_spec: RetrieveLedgerSpec,
+}
+
+impl Request for AmmInfoRequest {
+    type Response = AmmInfoResponse;
+
+    fn method(&self) -> String {
+        "amm_info".to_owned()
+    }
+}
+
+impl WithLedgerSpec for AmmInfoRequest {
+    fn as_ledger_spec(&self) -> &crate::RetrieveLedgerSpec {
+        &self.ledger_spec
+    }
+
+    fn as_ledger_spec_mut(&mut self) -> &mut crate::RetrieveLedgerSpec {
+        &mut self.ledger_spec
+    }
+}
+
+impl AmmInfoRequest {
+    /// Selects the AMM by the assets of its pool, in any order
+    pub fn new(asset: Currency, asset2: Currency) -> Self {
+        Self {
+            asset: Some(asset),
+            asset2: Some(asset2),
+            ..Default::default()
+        }
+    }
+
+    /// Selects the AMM by its account
+    pub fn with_amm_account(amm_account: &str) -> Self {
+        Self {
+            amm_account: Some(amm_account.to_owned()),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AmmAuthAccount {
+    pub account: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AmmAuctionSlot {
+    pub account: String,
+    pub auth_accounts: Option<Vec<AmmAuthAccount>>,
+    /// Trading fee of the slot holder, in units of 1/100,000
+    pub discounted_fee: u16,
+    /// Expiration of the slot, in ISO 8601 format
+    pub expiration: String,
+    /// Amount of LP tokens paid for the slot
+    pub price: Amount,
+    /// Current 72 minute interval of the 24 hour slot, from 0 to 19
+    pub time_interval: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AmmVoteSlot {
+    pub account: String,
+    /// Proposed trading fee, in units of 1/100,000
+    pub trading_fee: u16,
+    /// Weight of the vote, relative to the LP tokens held, in units of 1/100,000
+    pub vote_weight: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AmmDescription {
+    /// The AMM account
+    pub account: String,
+    /// Pool balance of the first asset
+    pub amount: Amount,
+    /// Pool balance of the second asset
+    pub amount2: Amount,
+    /// Set if the first asset is frozen
+    pub asset_frozen: Option<bool>,
+    /// Set if the second asset is frozen
+    pub asset2_frozen: Option<bool>,
+    pub auction_slot: Option<AmmAuctionSlot>,
+    /// Total outstanding LP tokens
+    pub lp_token: Amount,
+    /// Trading fee of the pool, in units of 1/100,000
+    pub trading_fee: u16,
+    pub vote_slots: Option<Vec<AmmVoteSlot>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AmmInfoResponse {
+    pub amm: AmmDescription,
+    #[serde(flatten)]
+    pub ledger_spec: ReturnLedgerSpec,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_request() {
+        let request = AmmInfoRequest::new(
+            Currency::xrp(),
+            Currency::issued("TST", "rP9jPyP5kyvFRb6ZiRghAGw5u8SGAmU4bd"),
+        );
+        assert_eq!(
+            serde_json::to_string(&request).unwrap(),
+            r#"{"asset":{"currency":"XRP"},"asset2":{"currency":"TST","issuer":"rP9jPyP5kyvFRb6ZiRghAGw5u8SGAmU4bd"}}"#
+        );
+
+        let request = AmmInfoRequest::with_amm_account("rE54zDvgnghAoPopCgvtiqWNq3dU5y836S");
+        assert_eq!(
+            serde_json::to_string(&request).unwrap(),
+            r#"{"amm_account":"rE54zDvgnghAoPopCgvtiqWNq3dU5y836S"}"#
+        );
+    }
+
+    #[test]
+    fn test_deserialize_response() {
+        let json = r#"
+{
+    "amm": {
+        "account": "rE54zDvgnghAoPopCgvtiqWNq3dU5y836S",
+        "amount": "227246211",
+        "amount2": {
+            "currency": "TST",
+            "issuer": "rP9jPyP5kyvFRb6ZiRghAGw5u8SGAmU4bd",
+            "value": "22.5136555671111"
+        },
+        "asset2_frozen": false,
+        "auction_slot": {
+            "account": "rJVUeRqDFNs2xqA7ncVE6ZoAhPUoaJJSQm",
+            "auth_accounts": [
+                {
+                    "account": "rMKXGCbJ5d8LbrqthdG46q3f969MVK2Qeg"
+                }
+            ],
+            "discounted_fee": 60,
+            "expiration": "2023-Nov-13 18:46:00.000000000 UTC",
+            "price": {
+                "currency": "039C99CD9AB0B70B32ECDA51EAAE471625608EA2",
+                "issuer": "rE54zDvgnghAoPopCgvtiqWNq3dU5y836S",
+                "value": "0.8696263565463045"
+            },
+            "time_interval": 0
+        },
+        "lp_token": {
+            "currency": "039C99CD9AB0B70B32ECDA51EAAE471625608EA2",
+            "issuer": "rE54zDvgnghAoPopCgvtiqWNq3dU5y836S",
+            "value": "71150.53584131501"
+        },
+        "trading_fee": 600,
+        "vote_slots": [
+            {
+                "account": "rJVUeRqDFNs2xqA7ncVE6ZoAhPUoaJJSQm",
+                "trading_fee": 600,
+                "vote_weight": 100000
+            }
+        ]
+    },
+    "ledger_current_index": 316745,
+    "validated": false
+}
+"#;
+
+        let response: AmmInfoResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.amm.amount, Amount::drops(227246211));
+        assert_eq!(response.amm.asset_frozen, None);
+        assert_eq!(response.amm.asset2_frozen, Some(false));
+        assert_eq!(response.amm.trading_fee, 600);
+        let auction_slot = response.amm.auction_slot.unwrap();
+        assert_eq!(auction_slot.discounted_fee, 60);
+        assert_eq!(auction_slot.auth_accounts.unwrap().len(), 1);
+        assert_eq!(response.amm.vote_slots.unwrap()[0].vote_weight, 100000);
+    }
+}
@@ -149,3 +149,73 @@ pub struct Ledger<TransactionType> {
     /// in either JSON or binary depending on whether the request specified binary as true.
     pub transactions: Option<Vec<TransactionType>>,
 }
+
+impl<TransactionType> TryFrom<&Ledger<TransactionType>> for xrpl_types::LedgerHeader {
+    type Error = xrpl_types::Error;
+
+    /// Typed ledger header, e.g. to verify the `ledger_hash` of the ledger
+    fn try_from(ledger: &Ledger<TransactionType>) -> Result<Self, Self::Error> {
+        let parse_error =
+            |field: &str| xrpl_types::Error::InvalidData(format!("Invalid ledger {}", field));
+        Ok(Self {
+            ledger_index: ledger
+                .ledger_index
+                .parse()
+                .map_err(|_| parse_error("ledger_index"))?,
+            total_coins: ledger
+                .total_coins
+                .parse()
+                .map_err(|_| parse_error("total_coins"))?,
+            parent_hash: xrpl_types::Hash256::from_hex(&ledger.parent_hash)?,
+            transaction_hash: xrpl_types::Hash256::from_hex(&ledger.transaction_hash)?,
+            account_hash: xrpl_types::Hash256::from_hex(&ledger.account_hash)?,
+            parent_close_time: ledger.parent_close_time,
+            close_time: ledger.close_time,
+            close_time_resolution: ledger
+                .close_time_resolution
+                .try_into()
+                .map_err(|_| parse_error("close_time_resolution"))?,
+            close_flags: ledger
+                .close_flags
+                .try_into()
+                .map_err(|_| parse_error("close_flags"))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ledger_header_try_from() {
+        let ledger: Ledger<String> = serde_json::from_str(
+            r#"{
+                "account_hash": "D9ABF622DA26EEEE48203085D4BC23B0F77DC6F8724AC33D975DA3CA492D2E44",
+                "close_flags": 0,
+                "close_time": 492656470,
+                "close_time_human": "2015-Aug-12 01:01:10.000000000 UTC",
+                "close_time_resolution": 10,
+                "closed": true,
+                "ledger_hash": "F4D865D83EB88C1A1911B9E90641919A1314F36E1B099F8E95FE3B7C77BE3349",
+                "ledger_index": "15202439",
+                "parent_close_time": 492656460,
+                "parent_hash": "12724A65B030C15A1573AA28B1BBB5DF3DA4589AA3623675A31CAE69B23B1C4E",
+                "total_coins": "99998831688050493",
+                "transaction_hash": "325EACC5271322539EEEC2D6A5292471EF1B3E72AE7180533EFC3B8F0AD435C8"
+            }"#,
+        )
+        .unwrap();
+        let header = xrpl_types::LedgerHeader::try_from(&ledger).unwrap();
+        assert_eq!(header.ledger_index, 15202439);
+        assert_eq!(header.total_coins, 99998831688050493);
+        assert_eq!(header.transaction_hash.to_hex(), ledger.transaction_hash);
+        assert_eq!(header.close_time_resolution, 10);
+
+        let ledger = Ledger::<String> {
+            total_coins: "many".to_owned(),
+            ..ledger
+        };
+        assert!(xrpl_types::LedgerHeader::try_from(&ledger).is_err());
+    }
+}
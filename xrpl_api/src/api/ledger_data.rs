@@ -75,6 +75,18 @@ pub struct LedgerData {
     pub index: String,
 }
 
+#[cfg(feature = "binary")]
+impl LedgerData {
+    /// Decodes `data` if the ledger objects were requested in binary format, see
+    /// [`LedgerObject::from_binary`]
+    pub fn decode_data(&self) -> Result<Option<LedgerObject>, xrpl_binary_codec::BinaryCodecError> {
+        self.data
+            .as_deref()
+            .map(|data| LedgerObject::from_binary_hex(data, &self.index))
+            .transpose()
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct LedgerDataResponse {
     /// Array of JSON objects containing data from the ledger's state tree.
@@ -90,3 +102,33 @@ impl WithResponsePagination for LedgerDataResponse {
         &self.pagination
     }
 }
+
+#[cfg(all(test, feature = "binary"))]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+
+    #[test]
+    fn test_decode_binary_ledger_data() {
+        let response: LedgerDataResponse = serde_json::from_str(
+            r#"{
+                "ledger_hash": "842B57C1CC0613299A686D3E9F310EC0422C84D3911E5056389AA7E5808A93C8",
+                "ledger_index": 6885842,
+                "marker": "0002A4D4B8D0B5D0C6B6D2A1F9D6E0F2C6A7B3E9A6D4C3B2A1F0E9D8C7B6A5F4",
+                "state": [
+                    {
+                        "data": "11007222000200002504F49DA137000000000000000038000000000000001A55E08D6E9754025BA2534A78707605E0601F03ACE063687A0CA1BDDACFCD1698C76294C8E648C32990000000000000000000000000005553440000000000000000000000000000000000000000000000000166800000000000000000000000000000000000000055534400000000004B4E9C06F24296074F7BC48F92A97916C6DC5EA967D6C38D7EA4C6800000000000000000000000000055534400000000003E9D4A2B8AA0780F682D136F7A56D6724EF53754",
+                        "index": "9CA88CDEDFF9252B3DE183CE35B038F57282BC9503CDFA1923EF9A95DF0D6F7B"
+                    }
+                ],
+                "validated": true
+            }"#,
+        )
+        .unwrap();
+        let entry = &response.state[0];
+        assert!(entry.object.is_none());
+        assert_matches!(entry.decode_data(), Ok(Some(LedgerObject::RippleState(ripple_state))) => {
+            assert_eq!(ripple_state.index.as_deref(), Some(entry.index.as_str()));
+        });
+    }
+}
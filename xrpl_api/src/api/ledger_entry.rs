@@ -71,3 +71,17 @@ pub struct LedgerEntryResponse {
     #[serde(flatten)]
     pub ledger_spec: ReturnLedgerSpec,
 }
+
+#[cfg(feature = "binary")]
+impl LedgerEntryResponse {
+    /// Decodes `node_binary` if the ledger object was requested in binary format, see
+    /// [`LedgerObject::from_binary`]
+    pub fn decode_node_binary(
+        &self,
+    ) -> Result<Option<LedgerObject>, xrpl_binary_codec::BinaryCodecError> {
+        self.node_binary
+            .as_deref()
+            .map(|node_binary| LedgerObject::from_binary_hex(node_binary, &self.index))
+            .transpose()
+    }
+}
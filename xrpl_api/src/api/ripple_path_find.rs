@@ -14,9 +14,8 @@
 //! <https://xrpl.org/paths.html>
 
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
 
-use crate::{Amount, Request};
+use crate::{to_paths, Amount, PathStep, Request};
 
 // #TODO is Clone really needed?
 #[derive(Default, Debug, Clone, Serialize)]
@@ -68,14 +67,28 @@ impl RipplePathFindRequest {
 
 #[derive(Debug, Deserialize)]
 pub struct Path {
-    // #TODO full types missing
     /// Array of arrays of objects defining payment paths.
-    pub paths_computed: Vec<Vec<Value>>,
+    pub paths_computed: Vec<Vec<PathStep>>,
+    /// Paths in canonical form, only returned by some server versions.
+    #[serde(default)]
+    pub paths_canonical: Vec<Vec<PathStep>>,
     /// Currency Amount that the source would have to send along this path for
     /// the destination to receive the desired amount.
     pub source_amount: Amount,
 }
 
+impl Path {
+    /// The computed paths of this alternative, to be used as the `paths` of an
+    /// `xrpl_types::PaymentTransaction`. The canonical paths are used if present.
+    pub fn to_paths(&self) -> Result<Vec<xrpl_types::Path>, xrpl_types::Error> {
+        if self.paths_canonical.is_empty() {
+            to_paths(&self.paths_computed)
+        } else {
+            to_paths(&self.paths_canonical)
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct RipplePathFindResponse {
     pub alternatives: Vec<Path>,
@@ -1,9 +1,15 @@
 mod account_root;
+mod amm;
+mod check;
 mod offer;
+mod pay_channel;
 mod ripple_state;
 
 pub use account_root::*;
+pub use amm::*;
+pub use check::*;
 pub use offer::*;
+pub use pay_channel::*;
 pub use ripple_state::*;
 use serde::Deserialize;
 
@@ -12,9 +18,11 @@ use serde::Deserialize;
 #[serde(tag = "LedgerEntryType")]
 pub enum LedgerObject {
     AccountRoot(AccountRoot),
+    #[serde(rename = "AMM")]
+    Amm(Amm),
     // TODO add model for remaining obejcts
     Amendments,
-    Check,
+    Check(Check),
     DepositPreauth,
     DirectoryNode,
     Escrow,
@@ -24,8 +32,97 @@ pub enum LedgerObject {
     NFTokenOffer,
     NFTokenPage,
     Offer(Offer),
-    PayChannel,
+    PayChannel(PayChannel),
     RippleState(RippleState),
     SignerList,
     Ticket,
 }
+
+#[cfg(feature = "binary")]
+impl LedgerObject {
+    /// Decodes a ledger object from the binary format <https://xrpl.org/serialization.html>, e.g.
+    /// the `node_binary` of `ledger_entry` or the `data` of `ledger_data` requested with `binary`.
+    /// The decoded object has the given `index`.
+    pub fn from_binary(
+        data: &[u8],
+        index: &str,
+    ) -> Result<Self, xrpl_binary_codec::BinaryCodecError> {
+        let mut json = xrpl_binary_codec::json::decode(data)?;
+        if let Some(object) = json.as_object_mut() {
+            object.insert("index".to_owned(), index.into());
+        }
+        serde_json::from_value(json).map_err(|err| {
+            xrpl_binary_codec::BinaryCodecError::InvalidField(format!(
+                "Ledger object not supported: {}",
+                err
+            ))
+        })
+    }
+
+    /// Decodes a ledger object from the binary format as hex, see [`LedgerObject::from_binary`]
+    pub fn from_binary_hex(
+        data: &str,
+        index: &str,
+    ) -> Result<Self, xrpl_binary_codec::BinaryCodecError> {
+        Self::from_binary(&xrpl_types::Blob::from_hex(data)?.0, index)
+    }
+}
+
+#[cfg(all(test, feature = "binary"))]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+
+    /// Ledger entry from `xrpl_binary_codec/fixtures/json-decode.json`
+    const RIPPLE_STATE_BINARY: &str = "11007222000200002504F49DA137000000000000000038000000000000001A55E08D6E9754025BA2534A78707605E0601F03ACE063687A0CA1BDDACFCD1698C76294C8E648C32990000000000000000000000000005553440000000000000000000000000000000000000000000000000166800000000000000000000000000000000000000055534400000000004B4E9C06F24296074F7BC48F92A97916C6DC5EA967D6C38D7EA4C6800000000000000000000000000055534400000000003E9D4A2B8AA0780F682D136F7A56D6724EF53754";
+
+    #[test]
+    fn test_ledger_object_from_binary() {
+        let index = "9CA88CDEDFF9252B3DE183CE35B038F57282BC9503CDFA1923EF9A95DF0D6F7B";
+        let object = LedgerObject::from_binary_hex(RIPPLE_STATE_BINARY, index).unwrap();
+        assert_matches!(object, LedgerObject::RippleState(ripple_state) => {
+            assert_eq!(ripple_state.balance.value, "-25.05");
+            assert_eq!(ripple_state.low_limit.issuer, "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn");
+            assert_eq!(ripple_state.high_node.as_deref(), Some("1a"));
+            assert_eq!(ripple_state.previous_txn_lgr_seq, Some(83140001));
+            assert_eq!(ripple_state.index.as_deref(), Some(index));
+        });
+    }
+
+    #[test]
+    fn test_ledger_object_from_binary_account_root() {
+        let json = serde_json::json!({
+            "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "AccountTxnID": "0D5FB50FA65C9FE1538FD7E398FFFE9D1908DFA4576D8D7A020040686F93C77D",
+            "Balance": "148446663",
+            "Domain": "6D64756F31332E636F6D",
+            "EmailHash": "98B4375E1D753E5B91627516F6D70977",
+            "Flags": 8388608,
+            "LedgerEntryType": "AccountRoot",
+            "MessageKey": "0000000000000000000000070000000300",
+            "OwnerCount": 3,
+            "PreviousTxnID": "0D5FB50FA65C9FE1538FD7E398FFFE9D1908DFA4576D8D7A020040686F93C77D",
+            "PreviousTxnLgrSeq": 14091160,
+            "Sequence": 336,
+            "TransferRate": 1004999999
+        });
+        let data = xrpl_binary_codec::json::encode(&json).unwrap();
+        let index = "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8";
+        let object = LedgerObject::from_binary(&data, index).unwrap();
+        assert_matches!(object, LedgerObject::AccountRoot(account_root) => {
+            assert_eq!(account_root.account, "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn");
+            assert_eq!(account_root.balance.as_deref(), Some("148446663"));
+            assert_eq!(account_root.sequence, 336);
+            assert_eq!(account_root.transfer_rate, Some(1004999999));
+            assert_eq!(account_root.index.as_deref(), Some(index));
+        });
+    }
+
+    #[test]
+    fn test_ledger_object_from_binary_invalid() {
+        let result = LedgerObject::from_binary_hex("11", "00");
+        assert!(result.is_err());
+        let result = LedgerObject::from_binary_hex("not hex", "00");
+        assert!(result.is_err());
+    }
+}
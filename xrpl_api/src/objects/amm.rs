@@ -0,0 +1,182 @@
+use crate::{Amount, AuthAccountEntry, Currency};
+use serde::{Deserialize, Serialize};
+use xrpl_types::LedgerTimestamp;
+
+/// An automated market maker (AMM) instance in the ledger. The assets of the pool are held
+/// by the AMM account.
+///
+/// <https://xrpl.org/amm.html>
+///
+/// {
+///     "Account": "rE54zDvgnghAoPopCgvtiqWNq3dU5y836S",
+///     "Asset": {
+///         "currency": "XRP"
+///     },
+///     "Asset2": {
+///         "currency": "TST",
+///         "issuer": "rP9jPyP5kyvFRb6ZiRghAGw5u8SGAmU4bd"
+///     },
+///     "AuctionSlot": {
+///         "Account": "rJVUeRqDFNs2xqA7ncVE6ZoAhPUoaJJSQm",
+///         "AuthAccounts": [
+///             {
+///                 "AuthAccount": {
+///                     "Account": "rMKXGCbJ5d8LbrqthdG46q3f969MVK2Qeg"
+///                 }
+///             }
+///         ],
+///         "DiscountedFee": 60,
+///         "Expiration": 721870180,
+///         "Price": {
+///             "currency": "039C99CD9AB0B70B32ECDA51EAAE471625608EA2",
+///             "issuer": "rE54zDvgnghAoPopCgvtiqWNq3dU5y836S",
+///             "value": "0.8696263565463045"
+///         }
+///     },
+///     "Flags": 0,
+///     "LPTokenBalance": {
+///         "currency": "039C99CD9AB0B70B32ECDA51EAAE471625608EA2",
+///         "issuer": "rE54zDvgnghAoPopCgvtiqWNq3dU5y836S",
+///         "value": "71150.53584131501"
+///     },
+///     "LedgerEntryType": "AMM",
+///     "OwnerNode": "0",
+///     "TradingFee": 600,
+///     "VoteSlots": [
+///         {
+///             "VoteEntry": {
+///                 "Account": "rJVUeRqDFNs2xqA7ncVE6ZoAhPUoaJJSQm",
+///                 "TradingFee": 600,
+///                 "VoteWeight": 100000
+///             }
+///         }
+///     ],
+///     "index": "648D7B2F2E6C4F5C8B4E6E1E6C43FA1C6F4DF4E1B0A0C8A2F3CA7D04C3C3A3F2"
+/// }
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Amm {
+    /// The AMM account, holding the assets of the pool and issuing the LP tokens
+    pub account: String,
+    pub asset: Currency,
+    pub asset2: Currency,
+    /// Total outstanding LP tokens
+    #[serde(rename = "LPTokenBalance")]
+    pub lp_token_balance: Amount,
+    /// Trading fee of the pool, in units of 1/100,000
+    pub trading_fee: u16,
+    pub auction_slot: Option<AuctionSlot>,
+    pub vote_slots: Option<Vec<VoteSlot>>,
+    #[serde(default)]
+    pub flags: u32,
+    /// This field is mandatory on `AMM` object, but we leave it optional, such
+    /// that we can parse the object from `CreateNode` fields where it is not set. See <https://xrpl.org/transaction-metadata.html>
+    pub owner_node: Option<String>,
+    /// Declared optional since it is not part of transaction metadata fields <https://xrpl.org/transaction-metadata.html#modifiednode-fields>
+    #[serde(rename = "PreviousTxnID")]
+    pub previous_txn_id: Option<String>,
+    /// Declared optional since it is not part of transaction metadata fields <https://xrpl.org/transaction-metadata.html#modifiednode-fields>
+    pub previous_txn_lgr_seq: Option<u32>,
+    #[serde(rename = "index")]
+    pub index: Option<String>,
+}
+
+/// Auction slot of an AMM, giving the holder a discounted trading fee
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct AuctionSlot {
+    /// Current holder of the slot
+    pub account: String,
+    /// Additional accounts that trade at the discounted fee
+    pub auth_accounts: Option<Vec<AuthAccountEntry>>,
+    /// Trading fee of the slot holder, in units of 1/100,000
+    #[serde(default)]
+    pub discounted_fee: u16,
+    pub expiration: LedgerTimestamp,
+    /// Amount of LP tokens paid for the slot
+    pub price: Amount,
+}
+
+/// Element of the `VoteSlots` array
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct VoteSlot {
+    pub vote_entry: VoteEntry,
+}
+
+/// Trading fee vote of a liquidity provider
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct VoteEntry {
+    pub account: String,
+    /// Proposed trading fee, in units of 1/100,000
+    pub trading_fee: u16,
+    /// Weight of the vote, relative to the LP tokens held, in units of 1/100,000
+    pub vote_weight: u32,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_amm() {
+        let json = r#"
+{
+    "Account": "rE54zDvgnghAoPopCgvtiqWNq3dU5y836S",
+    "Asset": {
+        "currency": "XRP"
+    },
+    "Asset2": {
+        "currency": "TST",
+        "issuer": "rP9jPyP5kyvFRb6ZiRghAGw5u8SGAmU4bd"
+    },
+    "AuctionSlot": {
+        "Account": "rJVUeRqDFNs2xqA7ncVE6ZoAhPUoaJJSQm",
+        "AuthAccounts": [
+            {
+                "AuthAccount": {
+                    "Account": "rMKXGCbJ5d8LbrqthdG46q3f969MVK2Qeg"
+                }
+            }
+        ],
+        "DiscountedFee": 60,
+        "Expiration": 721870180,
+        "Price": {
+            "currency": "039C99CD9AB0B70B32ECDA51EAAE471625608EA2",
+            "issuer": "rE54zDvgnghAoPopCgvtiqWNq3dU5y836S",
+            "value": "0.8696263565463045"
+        }
+    },
+    "Flags": 0,
+    "LPTokenBalance": {
+        "currency": "039C99CD9AB0B70B32ECDA51EAAE471625608EA2",
+        "issuer": "rE54zDvgnghAoPopCgvtiqWNq3dU5y836S",
+        "value": "71150.53584131501"
+    },
+    "LedgerEntryType": "AMM",
+    "OwnerNode": "0",
+    "TradingFee": 600,
+    "VoteSlots": [
+        {
+            "VoteEntry": {
+                "Account": "rJVUeRqDFNs2xqA7ncVE6ZoAhPUoaJJSQm",
+                "TradingFee": 600,
+                "VoteWeight": 100000
+            }
+        }
+    ],
+    "index": "648D7B2F2E6C4F5C8B4E6E1E6C43FA1C6F4DF4E1B0A0C8A2F3CA7D04C3C3A3F2"
+}
+"#;
+
+        let amm: Amm = serde_json::from_str(json).unwrap();
+        assert_eq!(amm.asset, Currency::xrp());
+        assert_eq!(amm.trading_fee, 600);
+        let auction_slot = amm.auction_slot.unwrap();
+        assert_eq!(auction_slot.discounted_fee, 60);
+        assert_eq!(auction_slot.auth_accounts.unwrap().len(), 1);
+        let vote_slots = amm.vote_slots.unwrap();
+        assert_eq!(vote_slots[0].vote_entry.vote_weight, 100000);
+    }
+}
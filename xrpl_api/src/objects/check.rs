@@ -0,0 +1,86 @@
+use crate::Amount;
+use serde::{Deserialize, Serialize};
+use xrpl_types::LedgerTimestamp;
+
+/// A check in the ledger.
+///
+/// <https://xrpl.org/check.html>
+///
+/// {
+///     "Account": "rUn84CUYbNjRoTQ6mSW7BVJPSVJNLb1QLo",
+///     "Destination": "rfkE1aSy9G8Upk4JssnwBxhEv5p4mn2KTy",
+///     "DestinationNode": "0000000000000000",
+///     "DestinationTag": 1,
+///     "Expiration": 570113521,
+///     "Flags": 0,
+///     "InvoiceID": "46060241FABCF692D4D934BA2A6C4427CD4279083E38C77CBE642243E43BE291",
+///     "LedgerEntryType": "Check",
+///     "OwnerNode": "0000000000000000",
+///     "PreviousTxnID": "5463C6E08862A1FAE5EDAC12D70ADB16546A1F674930521295BC082494B62924",
+///     "PreviousTxnLgrSeq": 6,
+///     "SendMax": "100000000",
+///     "Sequence": 2,
+///     "index": "49647F0D748DC3FE26BDACBC57F251AADEFFF391403EC9BF87C97F67E9977FB0"
+/// }
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Check {
+    /// The sender of the check
+    pub account: String,
+    /// The account that can cash the check
+    pub destination: String,
+    /// Maximum amount the check can debit from the sender
+    pub send_max: Amount,
+    /// Sequence number of the `CheckCreate` transaction that created the check
+    pub sequence: u32,
+    pub destination_node: Option<String>,
+    pub destination_tag: Option<u32>,
+    pub expiration: Option<LedgerTimestamp>,
+    #[serde(rename = "InvoiceID")]
+    pub invoice_id: Option<String>,
+    pub source_tag: Option<u32>,
+    #[serde(default)]
+    pub flags: u32,
+    /// This field is mandatory on `Check` object, but we leave it optional, such
+    /// that we can parse the object from `CreateNode` fields where it is not set. See <https://xrpl.org/transaction-metadata.html>
+    pub owner_node: Option<String>,
+    /// Declared optional since it is not part of transaction metadata fields <https://xrpl.org/transaction-metadata.html#modifiednode-fields>
+    #[serde(rename = "PreviousTxnID")]
+    pub previous_txn_id: Option<String>,
+    /// Declared optional since it is not part of transaction metadata fields <https://xrpl.org/transaction-metadata.html#modifiednode-fields>
+    pub previous_txn_lgr_seq: Option<u32>,
+    #[serde(rename = "index")]
+    pub index: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_check() {
+        let json = r#"
+{
+    "Account": "rUn84CUYbNjRoTQ6mSW7BVJPSVJNLb1QLo",
+    "Destination": "rfkE1aSy9G8Upk4JssnwBxhEv5p4mn2KTy",
+    "DestinationNode": "0000000000000000",
+    "DestinationTag": 1,
+    "Expiration": 570113521,
+    "Flags": 0,
+    "InvoiceID": "46060241FABCF692D4D934BA2A6C4427CD4279083E38C77CBE642243E43BE291",
+    "LedgerEntryType": "Check",
+    "OwnerNode": "0000000000000000",
+    "PreviousTxnID": "5463C6E08862A1FAE5EDAC12D70ADB16546A1F674930521295BC082494B62924",
+    "PreviousTxnLgrSeq": 6,
+    "SendMax": "100000000",
+    "Sequence": 2,
+    "index": "49647F0D748DC3FE26BDACBC57F251AADEFFF391403EC9BF87C97F67E9977FB0"
+}
+"#;
+
+        let check: Check = serde_json::from_str(json).unwrap();
+        assert_eq!(check.send_max, Amount::drops(100000000));
+        assert_eq!(check.sequence, 2);
+        assert!(check.invoice_id.is_some());
+    }
+}
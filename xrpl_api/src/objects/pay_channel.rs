@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+use xrpl_types::LedgerTimestamp;
+
+/// A payment channel in the ledger.
+///
+/// <https://xrpl.org/paychannel.html>
+///
+/// {
+///     "Account": "rBqb89MRQJnMPq8wTwEbtz4kvxrEDfcYvt",
+///     "Destination": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+///     "Amount": "4325800",
+///     "Balance": "2323423",
+///     "PublicKey": "32D2471DB72B27E3310F355BB33E339BF26F8392D5A93D3BC0FC3B566612DA0F0A",
+///     "SettleDelay": 3600,
+///     "Expiration": 536027313,
+///     "CancelAfter": 536891313,
+///     "SourceTag": 0,
+///     "DestinationTag": 1002341,
+///     "DestinationNode": "0000000000000000",
+///     "Flags": 0,
+///     "LedgerEntryType": "PayChannel",
+///     "OwnerNode": "0000000000000000",
+///     "PreviousTxnID": "F0AB71E777B2DA54B86231E19B82554EF1F8211F92ECA473121C655BFC5329BF",
+///     "PreviousTxnLgrSeq": 14524914,
+///     "index": "96F76F27D8A327FC48753167EC04A46AA0E382E6F57F32FD12274144D00F1797"
+/// }
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PayChannel {
+    /// The source account of the channel
+    pub account: String,
+    pub destination: String,
+    /// Total XRP, in drops, allocated to the channel
+    pub amount: String,
+    /// Total XRP, in drops, paid out by the channel. The XRP left in the channel is
+    /// `amount - balance`.
+    pub balance: String,
+    /// Public key of the key pair that signs claims against the channel, in hex
+    pub public_key: String,
+    /// Seconds the source must wait before closing a channel with unclaimed XRP
+    pub settle_delay: u32,
+    pub expiration: Option<LedgerTimestamp>,
+    pub cancel_after: Option<LedgerTimestamp>,
+    pub source_tag: Option<u32>,
+    pub destination_tag: Option<u32>,
+    pub destination_node: Option<String>,
+    #[serde(default)]
+    pub flags: u32,
+    /// This field is mandatory on `PayChannel` object, but we leave it optional, such
+    /// that we can parse the object from `CreateNode` fields where it is not set. See <https://xrpl.org/transaction-metadata.html>
+    pub owner_node: Option<String>,
+    /// Declared optional since it is not part of transaction metadata fields <https://xrpl.org/transaction-metadata.html#modifiednode-fields>
+    #[serde(rename = "PreviousTxnID")]
+    pub previous_txn_id: Option<String>,
+    /// Declared optional since it is not part of transaction metadata fields <https://xrpl.org/transaction-metadata.html#modifiednode-fields>
+    pub previous_txn_lgr_seq: Option<u32>,
+    #[serde(rename = "index")]
+    pub index: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_pay_channel() {
+        let json = r#"
+{
+    "Account": "rBqb89MRQJnMPq8wTwEbtz4kvxrEDfcYvt",
+    "Destination": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+    "Amount": "4325800",
+    "Balance": "2323423",
+    "PublicKey": "32D2471DB72B27E3310F355BB33E339BF26F8392D5A93D3BC0FC3B566612DA0F0A",
+    "SettleDelay": 3600,
+    "Expiration": 536027313,
+    "CancelAfter": 536891313,
+    "SourceTag": 0,
+    "DestinationTag": 1002341,
+    "DestinationNode": "0000000000000000",
+    "Flags": 0,
+    "LedgerEntryType": "PayChannel",
+    "OwnerNode": "0000000000000000",
+    "PreviousTxnID": "F0AB71E777B2DA54B86231E19B82554EF1F8211F92ECA473121C655BFC5329BF",
+    "PreviousTxnLgrSeq": 14524914,
+    "index": "96F76F27D8A327FC48753167EC04A46AA0E382E6F57F32FD12274144D00F1797"
+}
+"#;
+
+        let pay_channel: PayChannel = serde_json::from_str(json).unwrap();
+        assert_eq!(pay_channel.settle_delay, 3600);
+        assert_eq!(pay_channel.expiration, Some(536027313));
+        assert_eq!(pay_channel.destination_tag, Some(1002341));
+    }
+}
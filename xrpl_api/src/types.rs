@@ -3,6 +3,7 @@ mod currency;
 mod ledger_index;
 mod meta;
 mod nf_token_offer;
+mod path;
 mod transaction;
 
 pub use amount::*;
@@ -10,4 +11,5 @@ pub use currency::*;
 pub use ledger_index::*;
 pub use meta::*;
 pub use nf_token_offer::*;
+pub use path::*;
 pub use transaction::*;
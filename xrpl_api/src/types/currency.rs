@@ -1,4 +1,4 @@
-use serde::{Serialize, Serializer};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 /// An XRP Ledger currency. Can be either an Issued Currency (IOU) or the native
 /// XRP digital asset. See <https://xrpl.org/currency-formats.html#specifying-without-amounts>
@@ -70,6 +70,29 @@ impl Serialize for Currency {
     }
 }
 
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Debug, Clone, Deserialize)]
+        struct CurrencyRaw {
+            currency: String,
+            issuer: Option<String>,
+        }
+
+        let CurrencyRaw { currency, issuer } = CurrencyRaw::deserialize(deserializer)?;
+        match issuer {
+            Some(issuer) => Ok(Currency::Issued { currency, issuer }),
+            None if currency == "XRP" => Ok(Currency::Xrp),
+            None => Err(de::Error::custom(format!(
+                "Issuer missing for currency {}",
+                currency
+            ))),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::Currency;
@@ -97,4 +120,21 @@ mod test {
             String::from_utf8(v).unwrap()
         );
     }
+
+    #[test]
+    fn test_deserialize() {
+        let currency: Currency = serde_json::from_str(r#"{"currency":"XRP"}"#).unwrap();
+        assert_eq!(currency, Currency::xrp());
+
+        let currency: Currency = serde_json::from_str(
+            r#"{"currency":"USD","issuer":"rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            currency,
+            Currency::issued("USD", "rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq")
+        );
+
+        assert!(serde_json::from_str::<Currency>(r#"{"currency":"USD"}"#).is_err());
+    }
 }
@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+
+/// Step of a payment path, see <https://xrpl.org/paths.html#path-specifications>
+#[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct PathStep {
+    /// Account to ripple through
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<String>,
+    /// Currency to convert to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// Issuer of the currency to convert to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<String>,
+    /// Bits indicating which fields are present, informational only
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub step_type: Option<u8>,
+    /// Hex representation of `type`, informational only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_hex: Option<String>,
+}
+
+impl TryFrom<&PathStep> for xrpl_types::PathStep {
+    type Error = xrpl_types::Error;
+
+    fn try_from(step: &PathStep) -> Result<Self, Self::Error> {
+        xrpl_types::PathStep::new(
+            step.account
+                .as_deref()
+                .map(xrpl_types::AccountId::from_address)
+                .transpose()?,
+            step.currency.as_deref().map(str::parse).transpose()?,
+            step.issuer
+                .as_deref()
+                .map(xrpl_types::AccountId::from_address)
+                .transpose()?,
+        )
+    }
+}
+
+/// Converts a path set, e.g. the `paths_computed` of a `ripple_path_find` alternative, to the
+/// `Paths` of an `xrpl_types::PaymentTransaction`
+pub fn to_paths(paths: &[Vec<PathStep>]) -> Result<Vec<xrpl_types::Path>, xrpl_types::Error> {
+    paths
+        .iter()
+        .map(|path| {
+            path.iter()
+                .map(xrpl_types::PathStep::try_from)
+                .collect::<Result<_, _>>()
+                .map(xrpl_types::Path)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assert_matches::assert_matches;
+
+    #[test]
+    fn test_to_paths() {
+        let paths: Vec<Vec<PathStep>> = serde_json::from_str(
+            r#"[
+                [
+                    {"currency": "USD", "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B", "type": 48, "type_hex": "0000000000000030"},
+                    {"account": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B", "type": 1, "type_hex": "0000000000000001"}
+                ],
+                [
+                    {"currency": "XRP", "type": 16, "type_hex": "0000000000000010"}
+                ]
+            ]"#,
+        )
+        .unwrap();
+        let issuer =
+            xrpl_types::AccountId::from_address("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B").unwrap();
+
+        let paths = to_paths(&paths).unwrap();
+        assert_eq!(
+            paths,
+            vec![
+                xrpl_types::Path(vec![
+                    xrpl_types::PathStep::currency("USD".parse().unwrap(), Some(issuer)),
+                    xrpl_types::PathStep::account(issuer),
+                ]),
+                xrpl_types::Path(vec![xrpl_types::PathStep::currency(
+                    xrpl_types::CurrencyCode::xrp(),
+                    None
+                )]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_paths_invalid() {
+        let empty_step = vec![vec![PathStep::default()]];
+        assert_matches!(
+            to_paths(&empty_step),
+            Err(xrpl_types::Error::InvalidData(_))
+        );
+
+        let invalid_account = vec![vec![PathStep {
+            account: Some("invalid".to_owned()),
+            ..Default::default()
+        }]];
+        assert!(to_paths(&invalid_account).is_err());
+    }
+}
@@ -7,36 +7,62 @@ pub use common::*;
 
 pub use variants::account_delete::*;
 pub use variants::account_set::*;
+pub use variants::amm_bid::*;
+pub use variants::amm_create::*;
+pub use variants::amm_delete::*;
+pub use variants::amm_deposit::*;
+pub use variants::amm_vote::*;
+pub use variants::amm_withdraw::*;
+pub use variants::check_cancel::*;
+pub use variants::check_cash::*;
+pub use variants::check_create::*;
+pub use variants::escrow_cancel::*;
+pub use variants::escrow_create::*;
+pub use variants::escrow_finish::*;
+pub use variants::nftoken_accept_offer::*;
+pub use variants::nftoken_burn::*;
+pub use variants::nftoken_cancel_offer::*;
+pub use variants::nftoken_create_offer::*;
+pub use variants::nftoken_mint::*;
 pub use variants::offer_cancel::*;
 pub use variants::offer_create::*;
 pub use variants::payment::*;
+pub use variants::payment_channel_claim::*;
+pub use variants::payment_channel_create::*;
+pub use variants::payment_channel_fund::*;
 pub use variants::trust_set::*;
 
 /// Ledger transaction. See <https://xrpl.org/transaction-formats.html>
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "TransactionType")]
 pub enum Transaction {
+    AMMBid(AMMBidTransaction),
+    AMMCreate(AMMCreateTransaction),
+    AMMDelete(AMMDeleteTransaction),
+    AMMDeposit(AMMDepositTransaction),
+    AMMVote(AMMVoteTransaction),
+    AMMWithdraw(AMMWithdrawTransaction),
     AccountDelete(AccountDeleteTransaction),
     AccountSet(AccountSetTransaction),
+    CheckCancel(CheckCancelTransaction),
+    CheckCash(CheckCashTransaction),
+    CheckCreate(CheckCreateTransaction),
     // TODO add model for remaining transactions
-    CheckCancel(TransactionCommon),
-    CheckCash(TransactionCommon),
-    CheckCreate(TransactionCommon),
     DepositPreauth(TransactionCommon),
-    EscrowCancel(TransactionCommon),
-    EscrowCreate(TransactionCommon),
-    EscrowFinish(TransactionCommon),
-    NFTokenAcceptOffer(TransactionCommon),
-    NFTokenBurn(TransactionCommon),
-    NFTokenCancelOffer(TransactionCommon),
-    NFTokenCreateOffer(TransactionCommon),
-    NFTokenMint(TransactionCommon),
+    EscrowCancel(EscrowCancelTransaction),
+    EscrowCreate(EscrowCreateTransaction),
+    EscrowFinish(EscrowFinishTransaction),
+    NFTokenAcceptOffer(NFTokenAcceptOfferTransaction),
+    NFTokenBurn(NFTokenBurnTransaction),
+    NFTokenCancelOffer(NFTokenCancelOfferTransaction),
+    NFTokenCreateOffer(NFTokenCreateOfferTransaction),
+    NFTokenMint(NFTokenMintTransaction),
     OfferCancel(OfferCancelTransaction),
     OfferCreate(OfferCreateTransaction),
     Payment(PaymentTransaction),
-    PaymentChannelClaim(TransactionCommon),
-    PaymentChannelCreate(TransactionCommon),
-    PaymentChannelFund(TransactionCommon),
+    PaymentChannelClaim(PaymentChannelClaimTransaction),
+    PaymentChannelCreate(PaymentChannelCreateTransaction),
+    PaymentChannelFund(PaymentChannelFundTransaction),
     SetRegularKey(TransactionCommon),
     SignerListSet(TransactionCommon),
     TicketCreate(TransactionCommon),
@@ -46,27 +72,33 @@ pub enum Transaction {
 impl Transaction {
     pub fn common(&self) -> &TransactionCommon {
         match self {
+            Transaction::AMMBid(t) => &t.common,
+            Transaction::AMMCreate(t) => &t.common,
+            Transaction::AMMDelete(t) => &t.common,
+            Transaction::AMMDeposit(t) => &t.common,
+            Transaction::AMMVote(t) => &t.common,
+            Transaction::AMMWithdraw(t) => &t.common,
             Transaction::AccountDelete(t) => &t.common,
             Transaction::AccountSet(t) => &t.common,
+            Transaction::CheckCancel(t) => &t.common,
+            Transaction::CheckCash(t) => &t.common,
+            Transaction::CheckCreate(t) => &t.common,
+            Transaction::EscrowCancel(t) => &t.common,
+            Transaction::EscrowCreate(t) => &t.common,
+            Transaction::EscrowFinish(t) => &t.common,
+            Transaction::NFTokenAcceptOffer(t) => &t.common,
+            Transaction::NFTokenBurn(t) => &t.common,
+            Transaction::NFTokenCancelOffer(t) => &t.common,
+            Transaction::NFTokenCreateOffer(t) => &t.common,
+            Transaction::NFTokenMint(t) => &t.common,
             Transaction::OfferCancel(t) => &t.common,
             Transaction::OfferCreate(t) => &t.common,
             Transaction::Payment(t) => &t.common,
+            Transaction::PaymentChannelClaim(t) => &t.common,
+            Transaction::PaymentChannelCreate(t) => &t.common,
+            Transaction::PaymentChannelFund(t) => &t.common,
             Transaction::TrustSet(t) => &t.common,
-            Transaction::CheckCancel(t) => t,
-            Transaction::CheckCash(t) => t,
-            Transaction::CheckCreate(t) => t,
             Transaction::DepositPreauth(t) => t,
-            Transaction::EscrowCancel(t) => t,
-            Transaction::EscrowCreate(t) => t,
-            Transaction::EscrowFinish(t) => t,
-            Transaction::NFTokenAcceptOffer(t) => t,
-            Transaction::NFTokenBurn(t) => t,
-            Transaction::NFTokenCancelOffer(t) => t,
-            Transaction::NFTokenCreateOffer(t) => t,
-            Transaction::NFTokenMint(t) => t,
-            Transaction::PaymentChannelClaim(t) => t,
-            Transaction::PaymentChannelCreate(t) => t,
-            Transaction::PaymentChannelFund(t) => t,
             Transaction::SetRegularKey(t) => t,
             Transaction::SignerListSet(t) => t,
             Transaction::TicketCreate(t) => t,
@@ -77,27 +109,33 @@ impl Transaction {
 impl Transaction {
     pub fn common_mut(&mut self) -> &mut TransactionCommon {
         match self {
+            Transaction::AMMBid(t) => &mut t.common,
+            Transaction::AMMCreate(t) => &mut t.common,
+            Transaction::AMMDelete(t) => &mut t.common,
+            Transaction::AMMDeposit(t) => &mut t.common,
+            Transaction::AMMVote(t) => &mut t.common,
+            Transaction::AMMWithdraw(t) => &mut t.common,
             Transaction::AccountDelete(t) => &mut t.common,
             Transaction::AccountSet(t) => &mut t.common,
+            Transaction::CheckCancel(t) => &mut t.common,
+            Transaction::CheckCash(t) => &mut t.common,
+            Transaction::CheckCreate(t) => &mut t.common,
+            Transaction::EscrowCancel(t) => &mut t.common,
+            Transaction::EscrowCreate(t) => &mut t.common,
+            Transaction::EscrowFinish(t) => &mut t.common,
+            Transaction::NFTokenAcceptOffer(t) => &mut t.common,
+            Transaction::NFTokenBurn(t) => &mut t.common,
+            Transaction::NFTokenCancelOffer(t) => &mut t.common,
+            Transaction::NFTokenCreateOffer(t) => &mut t.common,
+            Transaction::NFTokenMint(t) => &mut t.common,
             Transaction::OfferCancel(t) => &mut t.common,
             Transaction::OfferCreate(t) => &mut t.common,
             Transaction::Payment(t) => &mut t.common,
+            Transaction::PaymentChannelClaim(t) => &mut t.common,
+            Transaction::PaymentChannelCreate(t) => &mut t.common,
+            Transaction::PaymentChannelFund(t) => &mut t.common,
             Transaction::TrustSet(t) => &mut t.common,
-            Transaction::CheckCancel(t) => t,
-            Transaction::CheckCash(t) => t,
-            Transaction::CheckCreate(t) => t,
             Transaction::DepositPreauth(t) => t,
-            Transaction::EscrowCancel(t) => t,
-            Transaction::EscrowCreate(t) => t,
-            Transaction::EscrowFinish(t) => t,
-            Transaction::NFTokenAcceptOffer(t) => t,
-            Transaction::NFTokenBurn(t) => t,
-            Transaction::NFTokenCancelOffer(t) => t,
-            Transaction::NFTokenCreateOffer(t) => t,
-            Transaction::NFTokenMint(t) => t,
-            Transaction::PaymentChannelClaim(t) => t,
-            Transaction::PaymentChannelCreate(t) => t,
-            Transaction::PaymentChannelFund(t) => t,
             Transaction::SetRegularKey(t) => t,
             Transaction::SignerListSet(t) => t,
             Transaction::TicketCreate(t) => t,
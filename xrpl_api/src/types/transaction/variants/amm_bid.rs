@@ -0,0 +1,81 @@
+use crate::{Amount, Currency, TransactionCommon};
+use enumflags2::BitFlags;
+use serde::{Deserialize, Serialize};
+use xrpl_types::AMMBidFlags;
+
+/// An `AMMBid` transaction <https://xrpl.org/ammbid.html>
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct AMMBidTransaction {
+    #[serde(flatten)]
+    pub common: TransactionCommon,
+    #[serde(default)]
+    pub flags: BitFlags<AMMBidFlags>,
+    pub asset: Currency,
+    pub asset2: Currency,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bid_min: Option<Amount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bid_max: Option<Amount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_accounts: Option<Vec<AuthAccountEntry>>,
+}
+
+/// Element of an `AuthAccounts` array
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct AuthAccountEntry {
+    pub auth_account: AuthAccount,
+}
+
+/// Account authorized to trade at the discounted fee of an auction slot
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct AuthAccount {
+    pub account: String,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::AMMBidTransaction;
+
+    #[test]
+    fn test_amm_bid_deserialize() {
+        let json = r#"
+{
+    "TransactionType": "AMMBid",
+    "Account": "rJVUeRqDFNs2xqA7ncVE6ZoAhPUoaJJSQm",
+    "Asset": {
+        "currency": "XRP"
+    },
+    "Asset2": {
+        "currency": "TST",
+        "issuer": "rP9jPyP5kyvFRb6ZiRghAGw5u8SGAmU4bd"
+    },
+    "AuthAccounts": [
+        {
+            "AuthAccount": {
+                "Account": "rMKXGCbJ5d8LbrqthdG46q3f969MVK2Qeg"
+            }
+        }
+    ],
+    "BidMax": {
+        "currency": "03930D02208264E2E40EC1B0C09E4DB96EE197B1",
+        "issuer": "rE54zDvgnghAoPopCgvtiqWNq3dU5y836S",
+        "value": "100"
+    },
+    "Fee": "10",
+    "Flags": 2147483648,
+    "Sequence": 9
+}
+        "#;
+
+        let tx: AMMBidTransaction = serde_json::from_str(json).unwrap();
+        assert_eq!(tx.bid_min, None);
+        let auth_accounts = tx.auth_accounts.unwrap();
+        assert_eq!(
+            auth_accounts[0].auth_account.account,
+            "rMKXGCbJ5d8LbrqthdG46q3f969MVK2Qeg"
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{Amount, TransactionCommon};
+use enumflags2::BitFlags;
+use serde::{Deserialize, Serialize};
+use xrpl_types::AMMCreateFlags;
+
+/// An `AMMCreate` transaction <https://xrpl.org/ammcreate.html>
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct AMMCreateTransaction {
+    #[serde(flatten)]
+    pub common: TransactionCommon,
+    #[serde(default)]
+    pub flags: BitFlags<AMMCreateFlags>,
+    pub amount: Amount,
+    pub amount2: Amount,
+    /// Trading fee of the pool, in units of 1/100,000
+    pub trading_fee: u16,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{AMMCreateTransaction, Amount};
+
+    #[test]
+    fn test_amm_create_deserialize() {
+        let json = r#"
+{
+    "TransactionType": "AMMCreate",
+    "Account": "rJVUeRqDFNs2xqA7ncVE6ZoAhPUoaJJSQm",
+    "Amount": {
+        "currency": "TST",
+        "issuer": "rP9jPyP5kyvFRb6ZiRghAGw5u8SGAmU4bd",
+        "value": "25"
+    },
+    "Amount2": "250000000",
+    "Fee": "2000000",
+    "Flags": 2147483648,
+    "Sequence": 6,
+    "TradingFee": 500
+}
+        "#;
+
+        let tx: AMMCreateTransaction = serde_json::from_str(json).unwrap();
+        assert_eq!(tx.amount2, Amount::drops(250000000));
+        assert_eq!(tx.trading_fee, 500);
+    }
+}
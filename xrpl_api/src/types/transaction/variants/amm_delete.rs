@@ -0,0 +1,44 @@
+use crate::{Currency, TransactionCommon};
+use enumflags2::BitFlags;
+use serde::{Deserialize, Serialize};
+use xrpl_types::AMMDeleteFlags;
+
+/// An `AMMDelete` transaction <https://xrpl.org/ammdelete.html>
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct AMMDeleteTransaction {
+    #[serde(flatten)]
+    pub common: TransactionCommon,
+    #[serde(default)]
+    pub flags: BitFlags<AMMDeleteFlags>,
+    pub asset: Currency,
+    pub asset2: Currency,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{AMMDeleteTransaction, Currency};
+
+    #[test]
+    fn test_amm_delete_deserialize() {
+        let json = r#"
+{
+    "TransactionType": "AMMDelete",
+    "Account": "rJVUeRqDFNs2xqA7ncVE6ZoAhPUoaJJSQm",
+    "Asset": {
+        "currency": "XRP"
+    },
+    "Asset2": {
+        "currency": "TST",
+        "issuer": "rP9jPyP5kyvFRb6ZiRghAGw5u8SGAmU4bd"
+    },
+    "Fee": "10",
+    "Flags": 0,
+    "Sequence": 10
+}
+        "#;
+
+        let tx: AMMDeleteTransaction = serde_json::from_str(json).unwrap();
+        assert_eq!(tx.asset, Currency::xrp());
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{Amount, Currency, TransactionCommon};
+use enumflags2::BitFlags;
+use serde::{Deserialize, Serialize};
+use xrpl_types::AMMDepositFlags;
+
+/// An `AMMDeposit` transaction <https://xrpl.org/ammdeposit.html>. The deposit mode is
+/// selected by the flags.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct AMMDepositTransaction {
+    #[serde(flatten)]
+    pub common: TransactionCommon,
+    #[serde(default)]
+    pub flags: BitFlags<AMMDepositFlags>,
+    pub asset: Currency,
+    pub asset2: Currency,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<Amount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount2: Option<Amount>,
+    #[serde(rename = "EPrice", skip_serializing_if = "Option::is_none")]
+    pub e_price: Option<Amount>,
+    #[serde(rename = "LPTokenOut", skip_serializing_if = "Option::is_none")]
+    pub lp_token_out: Option<Amount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trading_fee: Option<u16>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{AMMDepositTransaction, Amount, Currency};
+    use xrpl_types::AMMDepositFlags;
+
+    #[test]
+    fn test_amm_deposit_deserialize() {
+        let json = r#"
+{
+    "TransactionType": "AMMDeposit",
+    "Account": "rJVUeRqDFNs2xqA7ncVE6ZoAhPUoaJJSQm",
+    "Asset": {
+        "currency": "XRP"
+    },
+    "Asset2": {
+        "currency": "TST",
+        "issuer": "rP9jPyP5kyvFRb6ZiRghAGw5u8SGAmU4bd"
+    },
+    "Amount": {
+        "currency": "TST",
+        "issuer": "rP9jPyP5kyvFRb6ZiRghAGw5u8SGAmU4bd",
+        "value": "2.5"
+    },
+    "Amount2": "30000000",
+    "Fee": "10",
+    "Flags": 1048576,
+    "Sequence": 7
+}
+        "#;
+
+        let tx: AMMDepositTransaction = serde_json::from_str(json).unwrap();
+        assert_eq!(tx.flags, AMMDepositFlags::TwoAsset);
+        assert_eq!(tx.asset, Currency::xrp());
+        assert_eq!(
+            tx.asset2,
+            Currency::issued("TST", "rP9jPyP5kyvFRb6ZiRghAGw5u8SGAmU4bd")
+        );
+        assert_eq!(tx.amount2, Some(Amount::drops(30000000)));
+        assert_eq!(tx.lp_token_out, None);
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{Currency, TransactionCommon};
+use enumflags2::BitFlags;
+use serde::{Deserialize, Serialize};
+use xrpl_types::AMMVoteFlags;
+
+/// An `AMMVote` transaction <https://xrpl.org/ammvote.html>
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct AMMVoteTransaction {
+    #[serde(flatten)]
+    pub common: TransactionCommon,
+    #[serde(default)]
+    pub flags: BitFlags<AMMVoteFlags>,
+    pub asset: Currency,
+    pub asset2: Currency,
+    /// Proposed trading fee, in units of 1/100,000
+    pub trading_fee: u16,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::AMMVoteTransaction;
+
+    #[test]
+    fn test_amm_vote_deserialize() {
+        let json = r#"
+{
+    "TransactionType": "AMMVote",
+    "Account": "rJVUeRqDFNs2xqA7ncVE6ZoAhPUoaJJSQm",
+    "Asset": {
+        "currency": "XRP"
+    },
+    "Asset2": {
+        "currency": "TST",
+        "issuer": "rP9jPyP5kyvFRb6ZiRghAGw5u8SGAmU4bd"
+    },
+    "TradingFee": 600,
+    "Fee": "10",
+    "Flags": 2147483648,
+    "Sequence": 9
+}
+        "#;
+
+        let tx: AMMVoteTransaction = serde_json::from_str(json).unwrap();
+        assert_eq!(tx.trading_fee, 600);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{Amount, Currency, TransactionCommon};
+use enumflags2::BitFlags;
+use serde::{Deserialize, Serialize};
+use xrpl_types::AMMWithdrawFlags;
+
+/// An `AMMWithdraw` transaction <https://xrpl.org/ammwithdraw.html>. The withdrawal mode is
+/// selected by the flags.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct AMMWithdrawTransaction {
+    #[serde(flatten)]
+    pub common: TransactionCommon,
+    #[serde(default)]
+    pub flags: BitFlags<AMMWithdrawFlags>,
+    pub asset: Currency,
+    pub asset2: Currency,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<Amount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount2: Option<Amount>,
+    #[serde(rename = "EPrice", skip_serializing_if = "Option::is_none")]
+    pub e_price: Option<Amount>,
+    #[serde(rename = "LPTokenIn", skip_serializing_if = "Option::is_none")]
+    pub lp_token_in: Option<Amount>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{AMMWithdrawTransaction, Amount};
+    use xrpl_types::AMMWithdrawFlags;
+
+    #[test]
+    fn test_amm_withdraw_deserialize() {
+        let json = r#"
+{
+    "TransactionType": "AMMWithdraw",
+    "Account": "rJVUeRqDFNs2xqA7ncVE6ZoAhPUoaJJSQm",
+    "Asset": {
+        "currency": "XRP"
+    },
+    "Asset2": {
+        "currency": "TST",
+        "issuer": "rP9jPyP5kyvFRb6ZiRghAGw5u8SGAmU4bd"
+    },
+    "LPTokenIn": {
+        "currency": "03930D02208264E2E40EC1B0C09E4DB96EE197B1",
+        "issuer": "rE54zDvgnghAoPopCgvtiqWNq3dU5y836S",
+        "value": "100"
+    },
+    "Fee": "10",
+    "Flags": 65536,
+    "Sequence": 8
+}
+        "#;
+
+        let tx: AMMWithdrawTransaction = serde_json::from_str(json).unwrap();
+        assert_eq!(tx.flags, AMMWithdrawFlags::LPToken);
+        assert_eq!(
+            tx.lp_token_in,
+            Some(Amount::issued(
+                "100",
+                "03930D02208264E2E40EC1B0C09E4DB96EE197B1",
+                "rE54zDvgnghAoPopCgvtiqWNq3dU5y836S"
+            ))
+        );
+    }
+}
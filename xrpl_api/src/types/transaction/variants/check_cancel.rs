@@ -0,0 +1,36 @@
+use crate::TransactionCommon;
+use enumflags2::BitFlags;
+use serde::{Deserialize, Serialize};
+use xrpl_types::CheckCancelFlags;
+
+/// A `CheckCancel` transaction <https://xrpl.org/checkcancel.html>
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CheckCancelTransaction {
+    #[serde(flatten)]
+    pub common: TransactionCommon,
+    #[serde(default)]
+    pub flags: BitFlags<CheckCancelFlags>,
+    #[serde(rename = "CheckID")]
+    pub check_id: String,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::CheckCancelTransaction;
+
+    #[test]
+    fn test_check_cancel_deserialize() {
+        let json = r#"
+{
+    "Account": "rUn84CUYbNjRoTQ6mSW7BVJPSVJNLb1QLo",
+    "TransactionType": "CheckCancel",
+    "CheckID": "49647F0D748DC3FE26BDACBC57F251AADEFFF391403EC9BF87C97F67E9977FB0",
+    "Fee": "12",
+    "Sequence": 4
+}
+        "#;
+
+        let _: CheckCancelTransaction = serde_json::from_str(json).unwrap();
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{Amount, TransactionCommon};
+use enumflags2::BitFlags;
+use serde::{Deserialize, Serialize};
+use xrpl_types::CheckCashFlags;
+
+/// A `CheckCash` transaction <https://xrpl.org/checkcash.html>. Exactly one of `amount`
+/// and `deliver_min` is set.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CheckCashTransaction {
+    #[serde(flatten)]
+    pub common: TransactionCommon,
+    #[serde(default)]
+    pub flags: BitFlags<CheckCashFlags>,
+    #[serde(rename = "CheckID")]
+    pub check_id: String,
+    /// Cash the check for exactly this amount
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<Amount>,
+    /// Cash the check for as much as possible, but at least this amount
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deliver_min: Option<Amount>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Amount, CheckCashTransaction};
+
+    #[test]
+    fn test_check_cash_deserialize() {
+        let json = r#"
+{
+    "Account": "rfkE1aSy9G8Upk4JssnwBxhEv5p4mn2KTy",
+    "TransactionType": "CheckCash",
+    "Amount": "100000000",
+    "CheckID": "838766BA2B995C00744175F69A1B11E32C3DBC40E64801A4056FCBD657F57334",
+    "Fee": "12",
+    "Sequence": 3
+}
+        "#;
+
+        let tx: CheckCashTransaction = serde_json::from_str(json).unwrap();
+        assert_eq!(tx.amount, Some(Amount::drops(100000000)));
+        assert_eq!(tx.deliver_min, None);
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{Amount, TransactionCommon};
+use enumflags2::BitFlags;
+use serde::{Deserialize, Serialize};
+use xrpl_types::CheckCreateFlags;
+
+/// A `CheckCreate` transaction <https://xrpl.org/checkcreate.html>
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CheckCreateTransaction {
+    #[serde(flatten)]
+    pub common: TransactionCommon,
+    #[serde(default)]
+    pub flags: BitFlags<CheckCreateFlags>,
+    pub destination: String,
+    pub send_max: Amount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_tag: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<u32>,
+    #[serde(rename = "InvoiceID", skip_serializing_if = "Option::is_none")]
+    pub invoice_id: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::CheckCreateTransaction;
+
+    #[test]
+    fn test_check_create_deserialize() {
+        let json = r#"
+{
+    "TransactionType": "CheckCreate",
+    "Account": "rUn84CUYbNjRoTQ6mSW7BVJPSVJNLb1QLo",
+    "Destination": "rfkE1aSy9G8Upk4JssnwBxhEv5p4mn2KTy",
+    "SendMax": "100000000",
+    "Expiration": 570113521,
+    "InvoiceID": "6F1DFD1D0FE8A32E40E1F2C05CF1C15545BAB56B617F9C6C2D63A6B704BEF59B",
+    "DestinationTag": 1,
+    "Fee": "12",
+    "Sequence": 2
+}
+        "#;
+
+        let tx: CheckCreateTransaction = serde_json::from_str(json).unwrap();
+        assert_eq!(tx.expiration, Some(570113521));
+        assert!(tx.invoice_id.is_some());
+    }
+}
@@ -0,0 +1,37 @@
+use crate::TransactionCommon;
+use enumflags2::BitFlags;
+use serde::{Deserialize, Serialize};
+use xrpl_types::EscrowCancelFlags;
+
+/// An `EscrowCancel` transaction <https://xrpl.org/escrowcancel.html>
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct EscrowCancelTransaction {
+    #[serde(flatten)]
+    pub common: TransactionCommon,
+    #[serde(default)]
+    pub flags: BitFlags<EscrowCancelFlags>,
+    pub owner: String,
+    pub offer_sequence: u32,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::EscrowCancelTransaction;
+
+    #[test]
+    fn test_escrow_cancel_deserialize() {
+        let json = r#"
+{
+    "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+    "TransactionType": "EscrowCancel",
+    "Owner": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+    "OfferSequence": 7,
+    "Fee": "12",
+    "Sequence": 9
+}
+        "#;
+
+        let _: EscrowCancelTransaction = serde_json::from_str(json).unwrap();
+    }
+}
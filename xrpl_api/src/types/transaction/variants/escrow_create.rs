@@ -0,0 +1,54 @@
+use crate::{Amount, TransactionCommon};
+use enumflags2::BitFlags;
+use serde::{Deserialize, Serialize};
+use xrpl_types::EscrowCreateFlags;
+
+/// An `EscrowCreate` transaction <https://xrpl.org/escrowcreate.html>
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct EscrowCreateTransaction {
+    #[serde(flatten)]
+    pub common: TransactionCommon,
+    #[serde(default)]
+    pub flags: BitFlags<EscrowCreateFlags>,
+    pub amount: Amount,
+    pub destination: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_tag: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_after: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finish_after: Option<u32>,
+    /// Hex encoded crypto-condition
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::EscrowCreateTransaction;
+
+    #[test]
+    fn test_escrow_create_deserialize() {
+        let json = r#"
+{
+    "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+    "TransactionType": "EscrowCreate",
+    "Amount": "10000",
+    "Destination": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW",
+    "CancelAfter": 533257958,
+    "FinishAfter": 533171558,
+    "Condition": "A0258020E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855810100",
+    "DestinationTag": 23480,
+    "SourceTag": 11747,
+    "Fee": "12",
+    "Sequence": 3
+}
+        "#;
+
+        let tx: EscrowCreateTransaction = serde_json::from_str(json).unwrap();
+        assert_eq!(tx.cancel_after, Some(533257958));
+        assert_eq!(tx.finish_after, Some(533171558));
+        assert_eq!(tx.destination_tag, Some(23480));
+    }
+}
@@ -0,0 +1,47 @@
+use crate::TransactionCommon;
+use enumflags2::BitFlags;
+use serde::{Deserialize, Serialize};
+use xrpl_types::EscrowFinishFlags;
+
+/// An `EscrowFinish` transaction <https://xrpl.org/escrowfinish.html>
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct EscrowFinishTransaction {
+    #[serde(flatten)]
+    pub common: TransactionCommon,
+    #[serde(default)]
+    pub flags: BitFlags<EscrowFinishFlags>,
+    pub owner: String,
+    pub offer_sequence: u32,
+    /// Hex encoded crypto-condition
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
+    /// Hex encoded crypto-condition fulfillment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fulfillment: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::EscrowFinishTransaction;
+
+    #[test]
+    fn test_escrow_finish_deserialize() {
+        let json = r#"
+{
+    "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+    "TransactionType": "EscrowFinish",
+    "Owner": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+    "OfferSequence": 7,
+    "Condition": "A0258020E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855810100",
+    "Fulfillment": "A0028000",
+    "Fee": "330",
+    "Sequence": 8
+}
+        "#;
+
+        let tx: EscrowFinishTransaction = serde_json::from_str(json).unwrap();
+        assert_eq!(tx.offer_sequence, 7);
+        assert_eq!(tx.fulfillment.as_deref(), Some("A0028000"));
+    }
+}
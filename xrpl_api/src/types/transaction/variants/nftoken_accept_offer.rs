@@ -0,0 +1,46 @@
+use crate::{Amount, TransactionCommon};
+use enumflags2::BitFlags;
+use serde::{Deserialize, Serialize};
+use xrpl_types::NFTokenAcceptOfferFlags;
+
+/// An `NFTokenAcceptOffer` transaction <https://xrpl.org/nftokenacceptoffer.html>. Both offers
+/// are set in brokered mode.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct NFTokenAcceptOfferTransaction {
+    #[serde(flatten)]
+    pub common: TransactionCommon,
+    #[serde(default)]
+    pub flags: BitFlags<NFTokenAcceptOfferFlags>,
+    #[serde(rename = "NFTokenSellOffer", skip_serializing_if = "Option::is_none")]
+    pub nftoken_sell_offer: Option<String>,
+    #[serde(rename = "NFTokenBuyOffer", skip_serializing_if = "Option::is_none")]
+    pub nftoken_buy_offer: Option<String>,
+    #[serde(rename = "NFTokenBrokerFee", skip_serializing_if = "Option::is_none")]
+    pub nftoken_broker_fee: Option<Amount>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Amount, NFTokenAcceptOfferTransaction};
+
+    #[test]
+    fn test_nftoken_accept_offer_deserialize() {
+        let json = r#"
+{
+    "TransactionType": "NFTokenAcceptOffer",
+    "Account": "r9spUPhPBfB6kQeF6vPhwmtFwRhBh2JUCG",
+    "Fee": "12",
+    "NFTokenSellOffer": "68CD1F6F906494EA08C9CB5CAFA64DFA90D4E834B7151899B73231DE5A0C3B77",
+    "NFTokenBuyOffer": "D2CB1F6F906494EA08C9CB5CAFA64DFA90D4E834B7151899B73231DE5A0C3B77",
+    "NFTokenBrokerFee": "100000",
+    "Sequence": 68549302
+}
+        "#;
+
+        let tx: NFTokenAcceptOfferTransaction = serde_json::from_str(json).unwrap();
+        assert!(tx.nftoken_sell_offer.is_some());
+        assert!(tx.nftoken_buy_offer.is_some());
+        assert_eq!(tx.nftoken_broker_fee, Some(Amount::drops(100000)));
+    }
+}
@@ -0,0 +1,43 @@
+use crate::TransactionCommon;
+use enumflags2::BitFlags;
+use serde::{Deserialize, Serialize};
+use xrpl_types::NFTokenBurnFlags;
+
+/// An `NFTokenBurn` transaction <https://xrpl.org/nftokenburn.html>
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct NFTokenBurnTransaction {
+    #[serde(flatten)]
+    pub common: TransactionCommon,
+    #[serde(default)]
+    pub flags: BitFlags<NFTokenBurnFlags>,
+    #[serde(rename = "NFTokenID")]
+    pub nftoken_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::NFTokenBurnTransaction;
+
+    #[test]
+    fn test_nftoken_burn_deserialize() {
+        let json = r#"
+{
+    "TransactionType": "NFTokenBurn",
+    "Account": "rEaHypMTfjgtQUV2R4rmmWAbBvXn8gHrwX",
+    "Owner": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+    "Fee": "10",
+    "Sequence": 13,
+    "NFTokenID": "000B013A95F14B0044F78A264E41713C64B5F89242540EE208C3098E00000D65"
+}
+        "#;
+
+        let tx: NFTokenBurnTransaction = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            tx.owner.as_deref(),
+            Some("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B")
+        );
+    }
+}
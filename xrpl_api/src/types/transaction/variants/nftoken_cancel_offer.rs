@@ -0,0 +1,39 @@
+use crate::TransactionCommon;
+use enumflags2::BitFlags;
+use serde::{Deserialize, Serialize};
+use xrpl_types::NFTokenCancelOfferFlags;
+
+/// An `NFTokenCancelOffer` transaction <https://xrpl.org/nftokencanceloffer.html>
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct NFTokenCancelOfferTransaction {
+    #[serde(flatten)]
+    pub common: TransactionCommon,
+    #[serde(default)]
+    pub flags: BitFlags<NFTokenCancelOfferFlags>,
+    #[serde(rename = "NFTokenOffers")]
+    pub nftoken_offers: Vec<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::NFTokenCancelOfferTransaction;
+
+    #[test]
+    fn test_nftoken_cancel_offer_deserialize() {
+        let json = r#"
+{
+    "TransactionType": "NFTokenCancelOffer",
+    "Account": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+    "NFTokenOffers": [
+        "9C92E061381C1EF37A8CDE0E8FC35188BFC30B1883825042A64309AC09F4C36D"
+    ],
+    "Fee": "10",
+    "Sequence": 9
+}
+        "#;
+
+        let tx: NFTokenCancelOfferTransaction = serde_json::from_str(json).unwrap();
+        assert_eq!(tx.nftoken_offers.len(), 1);
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{Amount, TransactionCommon};
+use enumflags2::BitFlags;
+use serde::{Deserialize, Serialize};
+use xrpl_types::NFTokenCreateOfferFlags;
+
+/// An `NFTokenCreateOffer` transaction <https://xrpl.org/nftokencreateoffer.html>
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct NFTokenCreateOfferTransaction {
+    #[serde(flatten)]
+    pub common: TransactionCommon,
+    #[serde(default)]
+    pub flags: BitFlags<NFTokenCreateOfferFlags>,
+    #[serde(rename = "NFTokenID")]
+    pub nftoken_id: String,
+    pub amount: Amount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::NFTokenCreateOfferTransaction;
+    use xrpl_types::NFTokenCreateOfferFlags;
+
+    #[test]
+    fn test_nftoken_create_offer_deserialize() {
+        let json = r#"
+{
+    "TransactionType": "NFTokenCreateOffer",
+    "Account": "rs8jBmmfpwgmrSPgwMsh7CvKRmRt1JTVSX",
+    "NFTokenID": "000100001E962F495F07A990F4ED55ACCFEEF365DBAA76B6A048C0A200000007",
+    "Amount": "1000000",
+    "Flags": 1,
+    "Fee": "10",
+    "Sequence": 8
+}
+        "#;
+
+        let tx: NFTokenCreateOfferTransaction = serde_json::from_str(json).unwrap();
+        assert_eq!(tx.flags, NFTokenCreateOfferFlags::SellNFToken);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::TransactionCommon;
+use enumflags2::BitFlags;
+use serde::{Deserialize, Serialize};
+use xrpl_types::NFTokenMintFlags;
+
+/// An `NFTokenMint` transaction <https://xrpl.org/nftokenmint.html>
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct NFTokenMintTransaction {
+    #[serde(flatten)]
+    pub common: TransactionCommon,
+    #[serde(default)]
+    pub flags: BitFlags<NFTokenMintFlags>,
+    #[serde(rename = "NFTokenTaxon")]
+    pub nftoken_taxon: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_fee: Option<u16>,
+    /// Hex encoded URI of the token data
+    #[serde(rename = "URI", skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::NFTokenMintTransaction;
+    use xrpl_types::NFTokenMintFlags;
+
+    #[test]
+    fn test_nftoken_mint_deserialize() {
+        let json = r#"
+{
+    "TransactionType": "NFTokenMint",
+    "Account": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+    "TransferFee": 314,
+    "NFTokenTaxon": 0,
+    "Flags": 8,
+    "Fee": "10",
+    "Sequence": 12,
+    "URI": "697066733A2F2F62616679626569676479727A74357366703775646D37687537367568377932366E6634646675796C71616266336F636C67747179353566627A6469",
+    "Memos": [
+        {
+            "Memo": {
+                "MemoType": "687474703A2F2F6578616D706C652E636F6D2F6D656D6F2F67656E65726963",
+                "MemoData": "72656E74"
+            }
+        }
+    ]
+}
+        "#;
+
+        let tx: NFTokenMintTransaction = serde_json::from_str(json).unwrap();
+        assert_eq!(tx.flags, NFTokenMintFlags::Transferable);
+        assert_eq!(tx.transfer_fee, Some(314));
+        assert_eq!(tx.nftoken_taxon, 0);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Amount, TransactionCommon};
+use crate::{Amount, PathStep, TransactionCommon};
 use enumflags2::BitFlags;
 use serde::{Deserialize, Serialize};
 use xrpl_types::PaymentFlags;
@@ -21,6 +21,8 @@ pub struct PaymentTransaction {
     pub send_max: Option<Amount>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deliver_min: Option<Amount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paths: Option<Vec<Vec<PathStep>>>,
 }
 
 #[cfg(test)]
@@ -0,0 +1,53 @@
+use crate::TransactionCommon;
+use enumflags2::BitFlags;
+use serde::{Deserialize, Serialize};
+use xrpl_types::PaymentChannelClaimFlags;
+
+/// A `PaymentChannelClaim` transaction <https://xrpl.org/paymentchannelclaim.html>
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PaymentChannelClaimTransaction {
+    #[serde(flatten)]
+    pub common: TransactionCommon,
+    #[serde(default)]
+    pub flags: BitFlags<PaymentChannelClaimFlags>,
+    pub channel: String,
+    /// Total XRP, in drops, delivered by the channel after this claim
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance: Option<String>,
+    /// XRP, in drops, authorized by the claim signature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::PaymentChannelClaimTransaction;
+    use xrpl_types::PaymentChannelClaimFlags;
+
+    #[test]
+    fn test_payment_channel_claim_deserialize() {
+        let json = r#"
+{
+    "Account": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW",
+    "TransactionType": "PaymentChannelClaim",
+    "Channel": "C1AE6DDDEEC05CF2978C0BAD6FE302948E9533691DC749DCDD3B9E5992CA6198",
+    "Balance": "1000000",
+    "Amount": "1000000",
+    "Signature": "30440220718D264EF05CAED7C781FF6DE298DCAC68D002562C9BF3A07C1E721B420C0DAB02203A5A4779EF4D2CCC7BC3EF886676D803A9981B928D3B8ACA483B80ECA3CD7B9B",
+    "PublicKey": "32D2471DB72B27E3310F355BB33E339BF26F8392D5A93D3BC0FC3B566612DA0F0A",
+    "Flags": 131072,
+    "Fee": "12",
+    "Sequence": 2
+}
+        "#;
+
+        let tx: PaymentChannelClaimTransaction = serde_json::from_str(json).unwrap();
+        assert_eq!(tx.flags, PaymentChannelClaimFlags::Close);
+        assert_eq!(tx.balance.as_deref(), Some("1000000"));
+    }
+}
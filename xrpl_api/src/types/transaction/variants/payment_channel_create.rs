@@ -0,0 +1,52 @@
+use crate::TransactionCommon;
+use enumflags2::BitFlags;
+use serde::{Deserialize, Serialize};
+use xrpl_types::PaymentChannelCreateFlags;
+
+/// A `PaymentChannelCreate` transaction <https://xrpl.org/paymentchannelcreate.html>
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PaymentChannelCreateTransaction {
+    #[serde(flatten)]
+    pub common: TransactionCommon,
+    #[serde(default)]
+    pub flags: BitFlags<PaymentChannelCreateFlags>,
+    /// XRP, in drops, to set aside in the channel
+    pub amount: String,
+    pub destination: String,
+    pub settle_delay: u32,
+    /// Public key of the key pair that signs claims against the channel, in hex
+    pub public_key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_after: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_tag: Option<u32>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::PaymentChannelCreateTransaction;
+
+    #[test]
+    fn test_payment_channel_create_deserialize() {
+        let json = r#"
+{
+    "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+    "TransactionType": "PaymentChannelCreate",
+    "Amount": "10000",
+    "Destination": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW",
+    "SettleDelay": 86400,
+    "PublicKey": "32D2471DB72B27E3310F355BB33E339BF26F8392D5A93D3BC0FC3B566612DA0F0A",
+    "CancelAfter": 533171558,
+    "DestinationTag": 23480,
+    "SourceTag": 11747,
+    "Fee": "12",
+    "Sequence": 4
+}
+        "#;
+
+        let tx: PaymentChannelCreateTransaction = serde_json::from_str(json).unwrap();
+        assert_eq!(tx.settle_delay, 86400);
+        assert_eq!(tx.cancel_after, Some(533171558));
+    }
+}
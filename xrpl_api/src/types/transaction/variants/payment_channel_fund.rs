@@ -0,0 +1,41 @@
+use crate::TransactionCommon;
+use enumflags2::BitFlags;
+use serde::{Deserialize, Serialize};
+use xrpl_types::PaymentChannelFundFlags;
+
+/// A `PaymentChannelFund` transaction <https://xrpl.org/paymentchannelfund.html>
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PaymentChannelFundTransaction {
+    #[serde(flatten)]
+    pub common: TransactionCommon,
+    #[serde(default)]
+    pub flags: BitFlags<PaymentChannelFundFlags>,
+    pub channel: String,
+    /// XRP, in drops, to add to the channel
+    pub amount: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<u32>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::PaymentChannelFundTransaction;
+
+    #[test]
+    fn test_payment_channel_fund_deserialize() {
+        let json = r#"
+{
+    "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+    "TransactionType": "PaymentChannelFund",
+    "Channel": "C1AE6DDDEEC05CF2978C0BAD6FE302948E9533691DC749DCDD3B9E5992CA6198",
+    "Amount": "200000",
+    "Expiration": 543171558,
+    "Fee": "12",
+    "Sequence": 5
+}
+        "#;
+
+        let _: PaymentChannelFundTransaction = serde_json::from_str(json).unwrap();
+    }
+}
@@ -0,0 +1,16 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use xrpl_binary_codec::json::{decode, decode_strict, encode};
+
+// Decoding must not panic, and a decoded object must encode to bytes that decode to the same
+// object. Objects accepted in strict mode must encode to the same bytes.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(json) = decode(data) {
+        let encoded = encode(&json).expect("decoded object must encode");
+        assert_eq!(decode(&encoded).expect("encoded object must decode"), json);
+    }
+    if let Ok(json) = decode_strict(data) {
+        assert_eq!(encode(&json).expect("decoded object must encode"), data);
+    }
+});
@@ -0,0 +1,17 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use xrpl_binary_codec::deserialize::deserialize;
+use xrpl_binary_codec::serialize::serialize;
+use xrpl_types::TransactionVariant;
+
+// Deserializing must not panic, and a deserialized transaction must serialize to bytes that
+// deserialize to the same transaction
+fuzz_target!(|data: &[u8]| {
+    if let Ok(tx) = deserialize::<TransactionVariant>(data) {
+        let serialized = serialize(&tx).expect("deserialized transaction must serialize");
+        let deserialized: TransactionVariant =
+            deserialize(&serialized).expect("serialized transaction must deserialize");
+        assert_eq!(serialize(&deserialized).unwrap(), serialized);
+    }
+});
@@ -0,0 +1,83 @@
+//! Automated market maker (AMM) helpers <https://xrpl.org/automated-market-makers.html>
+
+use crate::hash::sha512_half;
+use xrpl_types::{Currency, CurrencyCode};
+
+/// First byte of the currency code of LP tokens
+const LP_TOKEN_CURRENCY_PREFIX: u8 = 0x03;
+
+/// Currency code of the LP tokens issued by the AMM for the given assets, in any order.
+/// The code is `0x03` followed by the first 19 bytes of the SHA-512Half of the two asset
+/// currency codes, in ascending order.
+/// See <https://xrpl.org/automated-market-makers.html#lp-token-currency-codes>
+pub fn lp_token_currency_code(asset: Currency, asset2: Currency) -> CurrencyCode {
+    let code = currency_code_bytes(asset);
+    let code2 = currency_code_bytes(asset2);
+    let (min, max) = if code <= code2 {
+        (code, code2)
+    } else {
+        (code2, code)
+    };
+    let mut data = [0u8; 40];
+    data[..20].copy_from_slice(&min);
+    data[20..].copy_from_slice(&max);
+    let hash = sha512_half(&data);
+    let mut bytes = [0u8; 20];
+    bytes[0] = LP_TOKEN_CURRENCY_PREFIX;
+    bytes[1..].copy_from_slice(&hash.0[..19]);
+    CurrencyCode::non_standard(bytes).expect("non-zero first byte")
+}
+
+fn currency_code_bytes(currency: Currency) -> [u8; 20] {
+    match currency {
+        Currency::Xrp => [0; 20],
+        Currency::Issued(issued) => issued.currency_code().to_bytes(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use xrpl_types::AccountId;
+
+    fn issued(code: &str, issuer: &str) -> Currency {
+        Currency::issued(
+            code.parse().unwrap(),
+            AccountId::from_address(issuer).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_lp_token_currency_code() {
+        let usd = issued("USD", "rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq");
+        let code = lp_token_currency_code(Currency::xrp(), usd);
+        assert_eq!(code.to_string(), "03930D02208264E2E40EC1B0C09E4DB96EE197B1");
+        assert_eq!(lp_token_currency_code(usd, Currency::xrp()), code);
+    }
+
+    /// LP token of the XRP/TST pool of the `amm_info` example, see <https://xrpl.org/amm_info.html>
+    #[test]
+    fn test_lp_token_currency_code_amm_info() {
+        let tst = issued("TST", "rP9jPyP5kyvFRb6ZiRghAGw5u8SGAmU4bd");
+        assert_eq!(
+            lp_token_currency_code(Currency::xrp(), tst).to_string(),
+            "039C99CD9AB0B70B32ECDA51EAAE471625608EA2"
+        );
+    }
+
+    #[test]
+    fn test_lp_token_currency_code_ignores_issuer() {
+        let usd = issued("USD", "rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq");
+        let eur = issued("EUR", "rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq");
+        let other_eur = issued("EUR", "rPEPPER7kfTD9w2To4CQk6UCfuHM9c6GDY");
+        assert_eq!(
+            lp_token_currency_code(eur, usd).to_string(),
+            "03FE31F736943F050684BDDE2A78B1D2AE331DF5"
+        );
+        assert_eq!(
+            lp_token_currency_code(usd, other_eur),
+            lp_token_currency_code(eur, usd)
+        );
+    }
+}
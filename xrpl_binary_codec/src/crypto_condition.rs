@@ -0,0 +1,281 @@
+//! PREIMAGE-SHA-256 crypto-conditions, as used by conditional escrows, see
+//! <https://xrpl.org/escrow.html#conditional-escrow> and
+//! <https://datatracker.ietf.org/doc/html/draft-thomas-crypto-conditions-04>
+
+use crate::alloc::{format, vec::Vec};
+use crate::BinaryCodecError;
+use core::fmt;
+use sha2::{Digest, Sha256};
+use xrpl_types::Blob;
+
+/// Maximum preimage length accepted by the XRPL
+pub const MAX_PREIMAGE_LENGTH: usize = 128;
+
+/// Length of the random preimage created by [`PreimageSha256::generate`]
+pub const GENERATED_PREIMAGE_LENGTH: usize = 32;
+
+/// A PREIMAGE-SHA-256 fulfillment. The condition is the SHA-256 fingerprint of the preimage,
+/// the fulfillment reveals the preimage.
+#[derive(Clone, Eq, PartialEq)]
+pub struct PreimageSha256 {
+    preimage: Vec<u8>,
+}
+
+/// Shows the condition only, the secret preimage is redacted
+impl fmt::Debug for PreimageSha256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PreimageSha256")
+            .field("condition", &self.condition())
+            .finish_non_exhaustive()
+    }
+}
+
+impl PreimageSha256 {
+    /// Creates a fulfillment from the given secret preimage
+    pub fn new(preimage: &[u8]) -> Result<Self, BinaryCodecError> {
+        if preimage.len() > MAX_PREIMAGE_LENGTH {
+            return Err(BinaryCodecError::InvalidLength(format!(
+                "preimage of {} bytes exceeds {} bytes",
+                preimage.len(),
+                MAX_PREIMAGE_LENGTH
+            )));
+        }
+        Ok(Self {
+            preimage: preimage.to_vec(),
+        })
+    }
+
+    /// Creates a fulfillment from a new random preimage
+    #[cfg(feature = "std")]
+    pub fn generate() -> Result<Self, BinaryCodecError> {
+        let mut preimage = [0u8; GENERATED_PREIMAGE_LENGTH];
+        getrandom::getrandom(&mut preimage).map_err(|err| {
+            BinaryCodecError::InvalidField(std::format!(
+                "failed to generate random preimage: {}",
+                err
+            ))
+        })?;
+        Self::new(&preimage)
+    }
+
+    /// Parses a serialized fulfillment, as found in the `Fulfillment` field of `EscrowFinish`
+    pub fn from_fulfillment(fulfillment: &[u8]) -> Result<Self, BinaryCodecError> {
+        let content = read_tlv(fulfillment, PREIMAGE_SHA256_TYPE_TAG)?;
+        let preimage = read_tlv(content, PREIMAGE_TAG)?;
+        Self::new(preimage)
+    }
+
+    pub fn preimage(&self) -> &[u8] {
+        &self.preimage
+    }
+
+    /// SHA-256 digest of the preimage
+    pub fn fingerprint(&self) -> [u8; 32] {
+        Sha256::digest(&self.preimage).into()
+    }
+
+    /// Serialized condition, for the `Condition` field of `EscrowCreate` and `EscrowFinish`
+    pub fn condition(&self) -> Blob {
+        let mut content = Vec::new();
+        write_tlv(&mut content, FINGERPRINT_TAG, &self.fingerprint());
+        write_tlv(&mut content, COST_TAG, &encode_cost(self.preimage.len()));
+        let mut condition = Vec::new();
+        write_tlv(&mut condition, PREIMAGE_SHA256_TYPE_TAG, &content);
+        Blob(condition)
+    }
+
+    /// Serialized fulfillment, for the `Fulfillment` field of `EscrowFinish`
+    pub fn fulfillment(&self) -> Blob {
+        let mut content = Vec::new();
+        write_tlv(&mut content, PREIMAGE_TAG, &self.preimage);
+        let mut fulfillment = Vec::new();
+        write_tlv(&mut fulfillment, PREIMAGE_SHA256_TYPE_TAG, &content);
+        Blob(fulfillment)
+    }
+
+    /// Returns true if this fulfillment satisfies the given serialized condition
+    pub fn fulfills(&self, condition: &[u8]) -> bool {
+        self.condition().0 == condition
+    }
+}
+
+/// Context specific, constructed tag 0 (PREIMAGE-SHA-256 type)
+const PREIMAGE_SHA256_TYPE_TAG: u8 = 0xA0;
+/// Context specific, primitive tag 0 (condition fingerprint or fulfillment preimage)
+const FINGERPRINT_TAG: u8 = 0x80;
+const PREIMAGE_TAG: u8 = 0x80;
+/// Context specific, primitive tag 1 (condition cost)
+const COST_TAG: u8 = 0x81;
+
+/// The cost of a PREIMAGE-SHA-256 condition is the preimage length, encoded as a minimal
+/// big-endian unsigned integer
+fn encode_cost(cost: usize) -> Vec<u8> {
+    let bytes = (cost as u64).to_be_bytes();
+    let start = bytes
+        .iter()
+        .position(|&b| b != 0)
+        .unwrap_or(bytes.len() - 1);
+    let mut encoded = Vec::new();
+    // Prefix with a zero byte if the high bit is set, so that the integer is not negative
+    if bytes[start] & 0x80 != 0 {
+        encoded.push(0);
+    }
+    encoded.extend_from_slice(&bytes[start..]);
+    encoded
+}
+
+/// Writes a DER tag-length-value
+fn write_tlv(out: &mut Vec<u8>, tag: u8, value: &[u8]) {
+    out.push(tag);
+    let len = value.len();
+    if len < 0x80 {
+        out.push(len as u8);
+    } else {
+        let bytes = (len as u64).to_be_bytes();
+        let start = bytes.iter().position(|&b| b != 0).unwrap_or(0);
+        out.push(0x80 | (bytes.len() - start) as u8);
+        out.extend_from_slice(&bytes[start..]);
+    }
+    out.extend_from_slice(value);
+}
+
+/// Reads a DER tag-length-value with the given tag that must span all of `data`,
+/// returning the value
+fn read_tlv(data: &[u8], tag: u8) -> Result<&[u8], BinaryCodecError> {
+    let insufficient = || BinaryCodecError::InsufficientBytes("crypto-condition".into());
+    let (&actual_tag, rest) = data.split_first().ok_or_else(insufficient)?;
+    if actual_tag != tag {
+        return Err(BinaryCodecError::InvalidField(format!(
+            "unexpected crypto-condition tag {:#04x}, expected {:#04x}",
+            actual_tag, tag
+        )));
+    }
+    let (&first, mut rest) = rest.split_first().ok_or_else(insufficient)?;
+    let len = if first < 0x80 {
+        first as usize
+    } else {
+        let count = (first & 0x7F) as usize;
+        if count == 0 || count > 2 || rest.len() < count {
+            return Err(BinaryCodecError::InvalidLength(
+                "invalid crypto-condition length".into(),
+            ));
+        }
+        let len = rest[..count]
+            .iter()
+            .fold(0usize, |len, &b| (len << 8) | b as usize);
+        rest = &rest[count..];
+        if len < 0x80 || (count == 2 && len < 0x100) {
+            return Err(BinaryCodecError::InvalidLength(
+                "crypto-condition length is not minimally encoded".into(),
+            ));
+        }
+        len
+    };
+    if rest.len() != len {
+        return Err(BinaryCodecError::InvalidLength(format!(
+            "crypto-condition length {} does not match {} remaining bytes",
+            len,
+            rest.len()
+        )));
+    }
+    Ok(rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+
+    #[test]
+    fn test_empty_preimage() {
+        let fulfillment = PreimageSha256::new(&[]).unwrap();
+        assert_eq!(
+            fulfillment.condition().to_hex(),
+            "A0258020E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855810100"
+        );
+        assert_eq!(fulfillment.fulfillment().to_hex(), "A0028000");
+    }
+
+    #[test]
+    fn test_debug_redacts_preimage() {
+        let fulfillment = PreimageSha256::new(b"secret").unwrap();
+        let debug = format!("{:?}", fulfillment);
+        assert_eq!(
+            debug,
+            format!(
+                "PreimageSha256 {{ condition: {}, .. }}",
+                fulfillment.condition().to_hex()
+            )
+        );
+        assert!(!debug.contains(&hex::encode_upper(b"secret")));
+    }
+
+    #[test]
+    fn test_preimage() {
+        let fulfillment = PreimageSha256::new(&[0u8; 32]).unwrap();
+        assert_eq!(
+            fulfillment.condition().to_hex(),
+            "A025802066687AADF862BD776C8FC18B8E9F8E20089714856EE233B3902A591D0D5F2925810120"
+        );
+        assert_eq!(
+            fulfillment.fulfillment().to_hex(),
+            "A02280200000000000000000000000000000000000000000000000000000000000000000"
+        );
+    }
+
+    #[test]
+    fn test_long_preimage() {
+        let fulfillment = PreimageSha256::new(&[7u8; MAX_PREIMAGE_LENGTH]).unwrap();
+        let condition = fulfillment.condition().to_hex();
+        assert!(condition.starts_with("A0268020"));
+        assert!(condition.ends_with("81020080"));
+        let serialized = fulfillment.fulfillment();
+        assert_eq!(&serialized.0[..6], &[0xA0, 0x81, 0x83, 0x80, 0x81, 0x80]);
+        assert_eq!(
+            PreimageSha256::from_fulfillment(&serialized.0).unwrap(),
+            fulfillment
+        );
+
+        assert_matches!(
+            PreimageSha256::new(&[7u8; MAX_PREIMAGE_LENGTH + 1]),
+            Err(BinaryCodecError::InvalidLength(_))
+        );
+    }
+
+    #[test]
+    fn test_from_fulfillment() {
+        let fulfillment = PreimageSha256::new(b"secret").unwrap();
+        let parsed = PreimageSha256::from_fulfillment(&fulfillment.fulfillment().0).unwrap();
+        assert_eq!(parsed.preimage(), b"secret");
+        assert!(parsed.fulfills(&fulfillment.condition().0));
+        assert!(!PreimageSha256::new(b"other")
+            .unwrap()
+            .fulfills(&fulfillment.condition().0));
+
+        assert_matches!(
+            PreimageSha256::from_fulfillment(&hex::decode("A1028000").unwrap()),
+            Err(BinaryCodecError::InvalidField(_))
+        );
+        assert_matches!(
+            PreimageSha256::from_fulfillment(&hex::decode("A003800000").unwrap()),
+            Err(BinaryCodecError::InvalidLength(_))
+        );
+        assert_matches!(
+            PreimageSha256::from_fulfillment(&hex::decode("A00280").unwrap()),
+            Err(BinaryCodecError::InvalidLength(_))
+        );
+        assert_matches!(
+            PreimageSha256::from_fulfillment(&hex::decode("A0818180").unwrap()),
+            Err(BinaryCodecError::InvalidLength(_))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_generate() {
+        let fulfillment = PreimageSha256::generate().unwrap();
+        assert_eq!(fulfillment.preimage().len(), GENERATED_PREIMAGE_LENGTH);
+        assert_ne!(PreimageSha256::generate().unwrap(), fulfillment);
+        assert!(fulfillment.fulfills(&fulfillment.condition().0));
+    }
+}
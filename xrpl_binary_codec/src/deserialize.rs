@@ -0,0 +1,1098 @@
+use crate::deserializer::ObjectDeserializer;
+use crate::serializer::field_info::field_info_lookup;
+use crate::BinaryCodecError;
+use xrpl_types::deserialize::Deserialize;
+
+/// Deserializes the given object from the canonical binary format <https://xrpl.org/serialization.html>.
+/// Fields in the serialized object that are not part of the deserialized object are rejected.
+///
+/// A transaction blob of any supported transaction type is deserialized with
+/// [`xrpl_types::TransactionVariant`].
+pub fn deserialize<T: Deserialize>(bytes: &[u8]) -> Result<T, BinaryCodecError> {
+    let mut deserializer = ObjectDeserializer::new(bytes, field_info_lookup())?;
+    let object = T::deserialize(&mut deserializer)?;
+    deserializer.end()?;
+    Ok(object)
+}
+
+/// Deserializes the given object like [`deserialize`], rejecting encodings that rippled considers
+/// non-canonical, see [`crate::deserializer::Deserializer::strict`]. Use it for input that is
+/// signed or hashed, to protect against malleated encodings.
+pub fn deserialize_strict<T: Deserialize>(bytes: &[u8]) -> Result<T, BinaryCodecError> {
+    let mut deserializer = ObjectDeserializer::new_strict(bytes, field_info_lookup())?;
+    let object = T::deserialize(&mut deserializer)?;
+    deserializer.end()?;
+    Ok(object)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::amm::lp_token_currency_code;
+    use crate::crypto_condition::PreimageSha256;
+    use crate::keys::KeyPair;
+    use crate::serialize::serialize;
+    use crate::sign;
+    use alloc::vec::Vec;
+    use ascii::AsciiChar;
+    use assert_matches::assert_matches;
+    use proptest::prelude::*;
+    use proptest::sample::Index;
+    use xrpl_types::deserialize::{required, Deserializer};
+    use xrpl_types::serialize::{Serialize, Serializer};
+    use xrpl_types::{
+        AMMBidTransaction, AMMCreateFlags, AMMCreateTransaction, AMMDeleteTransaction,
+        AMMDepositFlags, AMMDepositTransaction, AMMVoteTransaction, AMMWithdrawFlags,
+        AMMWithdrawTransaction, AccountId, Amount, Blob, CheckCancelTransaction, CheckCashAmount,
+        CheckCashTransaction, CheckCreateTransaction, Currency, CurrencyCode, DropsAmount, Error,
+        EscrowCancelTransaction, EscrowCreateTransaction, EscrowFinishTransaction, Hash256,
+        IssuedValue, Memo, NFTokenAcceptOfferTransaction, NFTokenCancelOfferTransaction,
+        NFTokenCreateOfferFlags, NFTokenCreateOfferTransaction, NFTokenFlags, NFTokenId,
+        NFTokenMintFlags, NFTokenMintTransaction, Number, OfferCancelTransaction, OfferCreateFlags,
+        OfferCreateTransaction, Path, PathStep, PaymentChannelClaimFlags,
+        PaymentChannelClaimTransaction, PaymentChannelCreateTransaction, PaymentFlags,
+        PaymentTransaction, Signer, TransactionCommon, TransactionType, TransactionVariant,
+        TrustSetTransaction, XChainBridge,
+    };
+
+    /// Signed payment from `sign::tests::test_sign_transaction`
+    const SIGNED_PAYMENT: &str = "120000228000000024011769EB201B01176A3F6140000000014FB18068400000000000000C7321037D37332B158AC75D7BA8E7EF1F3F4C7C0FA7B4BD8818B9C03545D3AED40BB3A974463044022059E8475EF21F380A0A8FF70FF976F53DFB2EEAADD98860F642BF4004A008BEF7022014279499218DD1460B753135AEAED5A63935ACE5975869C3204886B1F346569E811471CFCE39CE9B97E7E519AF8B282DDBE140A278748314F667B0CA50CC7709A220B0561B85E53A48461FA8";
+
+    /// Signed payment of issued currency from <https://xrpl.org/sign.html>
+    const SIGNED_ISSUED_PAYMENT: &str = "1200002280000000240000000361D4838D7EA4C6800000000000000000000000000055534400000000004B4E9C06F24296074F7BC48F92A97916C6DC5EA968400000000000000A732103AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB74473045022100D184EB4AE5956FF600E7536EE459345C7BBCF097A84CC61A93B9AF7197EDB98702201CEA8009B7BEEBAA2AACC0359B41C427C1C5B550A4CA4B80CF2174AF2D6D5DCE81144B4E9C06F24296074F7BC48F92A97916C6DC5EA983143E9D4A2B8AA0780F682D136F7A56D6724EF53754";
+
+    #[test]
+    fn test_deserialize_payment() {
+        let blob = hex::decode(SIGNED_PAYMENT).unwrap();
+        let tx: PaymentTransaction = deserialize(&blob).unwrap();
+
+        assert_eq!(
+            tx.common.account.to_address(),
+            "rB48JG388ovDA9fmPJbqgnSK3tnndSxgAe"
+        );
+        assert_eq!(
+            tx.destination.to_address(),
+            "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe"
+        );
+        assert_eq!(tx.amount, Amount::drops(22_000_000).unwrap());
+        assert_eq!(tx.flags.bits(), 0x80000000);
+        assert_eq!(tx.common.fee, Some(DropsAmount::from_drops(12).unwrap()));
+        assert_eq!(tx.common.sequence, Some(18311659));
+        assert_eq!(tx.common.last_ledger_sequence, Some(18311743));
+        assert!(tx.common.txn_signature.is_some());
+
+        assert_eq!(serialize(&tx).unwrap(), blob);
+    }
+
+    /// Cross-currency payment, also in `fixtures/json-decode.json`
+    const PAYMENT_WITH_PATHS: &str = "12000022800200002400000007201B0086955361D4838D7EA4C6800000000000000000000000000055534400000000003E9D4A2B8AA0780F682D136F7A56D6724EF5375468400000000000000C69D485543DF729C00000000000000000000000000045555200000000004B4E9C06F24296074F7BC48F92A97916C6DC5EA9732103AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB81144B4E9C06F24296074F7BC48F92A97916C6DC5EA983143E9D4A2B8AA0780F682D136F7A56D6724EF5375401121000000000000000000000000000000000000000003000000000000000000000000055534400000000003E9D4A2B8AA0780F682D136F7A56D6724EF53754FF01B5F762798A53D543A014CAF8B297CFF8F2F937E83000000000000000000000000055534400000000003E9D4A2B8AA0780F682D136F7A56D6724EF5375400";
+
+    #[test]
+    fn test_deserialize_payment_with_paths() {
+        let account = AccountId::from_address("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn").unwrap();
+        let destination = AccountId::from_address("ra5nK24KXen9AHvsdFTKHSANinZseWnPcX").unwrap();
+        let ripple_through = AccountId::from_address("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh").unwrap();
+        let usd = CurrencyCode::standard([AsciiChar::U, AsciiChar::S, AsciiChar::D]).unwrap();
+        let eur = CurrencyCode::standard([AsciiChar::E, AsciiChar::U, AsciiChar::R]).unwrap();
+        let mut tx = PaymentTransaction::new(
+            account,
+            Amount::issued(
+                IssuedValue::from_mantissa_exponent(1, 0).unwrap(),
+                usd,
+                destination,
+            )
+            .unwrap(),
+            destination,
+        )
+        .send_max(
+            Amount::issued(
+                IssuedValue::from_mantissa_exponent(15, -1).unwrap(),
+                eur,
+                account,
+            )
+            .unwrap(),
+        )
+        .paths(vec![
+            Path(vec![
+                PathStep::currency(CurrencyCode::xrp(), None),
+                PathStep::currency(usd, Some(destination)),
+            ]),
+            Path(vec![
+                PathStep::account(ripple_through),
+                PathStep::currency(usd, Some(destination)),
+            ]),
+        ]);
+        tx.flags = PaymentFlags::FullyCanonicalSig | PaymentFlags::PartialPayment;
+        tx.common.fee = Some(DropsAmount::from_drops(12).unwrap());
+        tx.common.sequence = Some(7);
+        tx.common.last_ledger_sequence = Some(8820051);
+        tx.common.signing_pub_key = Some(Blob(
+            hex::decode("03AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB")
+                .unwrap(),
+        ));
+
+        let blob = serialize(&tx).unwrap();
+        assert_eq!(hex::encode_upper(&blob), PAYMENT_WITH_PATHS);
+        let deserialized: PaymentTransaction = deserialize(&blob).unwrap();
+        assert_eq!(deserialized.paths, tx.paths);
+    }
+
+    #[test]
+    fn test_deserialize_transaction_variant() {
+        let blob = hex::decode(SIGNED_ISSUED_PAYMENT).unwrap();
+        let tx: TransactionVariant = deserialize(&blob).unwrap();
+
+        assert_eq!(tx.transaction_type(), TransactionType::Payment);
+        assert_matches!(&tx, TransactionVariant::Payment(payment) => {
+            assert_eq!(
+                payment.amount,
+                Amount::issued(
+                    IssuedValue::from_mantissa_exponent(1, 0).unwrap(),
+                    CurrencyCode::standard([AsciiChar::U, AsciiChar::S, AsciiChar::D]).unwrap(),
+                    AccountId::from_address("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn").unwrap(),
+                )
+                .unwrap()
+            );
+        });
+
+        assert_eq!(serialize(&tx).unwrap(), blob);
+    }
+
+    #[test]
+    fn test_deserialize_offer_create_roundtrip() {
+        let account = AccountId::from_address("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn").unwrap();
+        let mut tx = OfferCreateTransaction::new(
+            account,
+            Amount::drops(1_000_000).unwrap(),
+            Amount::issued(
+                IssuedValue::from_mantissa_exponent(-12345, -2).unwrap(),
+                CurrencyCode::non_standard([0x02; 20]).unwrap(),
+                account,
+            )
+            .unwrap(),
+        );
+        tx.flags = OfferCreateFlags::Sell | OfferCreateFlags::ImmediateOrCancel;
+        tx.expiration = Some(595640108);
+        tx.common.fee = Some(DropsAmount::from_drops(10).unwrap());
+        tx.common.memos = vec![Memo {
+            memo_type: Blob(b"text/plain".to_vec()),
+            memo_data: Blob(b"hello".to_vec()),
+            memo_format: None,
+        }];
+        tx.common.signers = vec![Signer {
+            account,
+            txn_signature: Blob(vec![0x30; 70]),
+            signing_pub_key: Blob(vec![0x02; 33]),
+        }];
+        let blob = serialize(&tx).unwrap();
+
+        let deserialized: OfferCreateTransaction = deserialize(&blob).unwrap();
+        assert_eq!(deserialized.flags, tx.flags);
+        assert_eq!(deserialized.taker_gets, tx.taker_gets);
+        assert_eq!(deserialized.taker_pays, tx.taker_pays);
+        assert_eq!(
+            deserialized.common.memos[0].memo_data,
+            Blob(b"hello".to_vec())
+        );
+        assert_eq!(deserialized.common.signers[0].account, account);
+        assert_eq!(serialize(&deserialized).unwrap(), blob);
+    }
+
+    #[test]
+    fn test_deserialize_escrow_roundtrip() {
+        let account = AccountId::from_address("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn").unwrap();
+        let owner = AccountId::from_address("rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW").unwrap();
+        let fulfillment = PreimageSha256::new(b"secret").unwrap();
+
+        let create = EscrowCreateTransaction::new(account, Amount::drops(10_000).unwrap(), owner)
+            .destination_tag(23480)
+            .cancel_after(533257958)
+            .finish_after(533171558)
+            .condition(fulfillment.condition());
+        let blob = serialize(&create).unwrap();
+        let tx: TransactionVariant = deserialize(&blob).unwrap();
+        assert_matches!(&tx, TransactionVariant::EscrowCreate(deserialized) => {
+            assert_eq!(deserialized.destination, owner);
+            assert_eq!(deserialized.cancel_after, Some(533257958));
+            assert_eq!(deserialized.finish_after, Some(533171558));
+            assert_eq!(deserialized.condition, Some(fulfillment.condition()));
+        });
+        assert_eq!(serialize(&tx).unwrap(), blob);
+
+        let finish = EscrowFinishTransaction::new(account, owner, 7)
+            .fulfill(fulfillment.condition(), fulfillment.fulfillment());
+        let blob = serialize(&finish).unwrap();
+        let deserialized: EscrowFinishTransaction = deserialize(&blob).unwrap();
+        assert_eq!(deserialized.owner, owner);
+        assert_eq!(deserialized.offer_sequence, 7);
+        let fulfilled =
+            PreimageSha256::from_fulfillment(&deserialized.fulfillment.unwrap().0).unwrap();
+        assert!(fulfilled.fulfills(&deserialized.condition.unwrap().0));
+
+        let cancel = EscrowCancelTransaction::new(account, owner, 7);
+        let blob = serialize(&cancel).unwrap();
+        let deserialized: EscrowCancelTransaction = deserialize(&blob).unwrap();
+        assert_eq!(deserialized.offer_sequence, 7);
+        assert_eq!(serialize(&deserialized).unwrap(), blob);
+    }
+
+    /// `EscrowCreate` example of <https://xrpl.org/escrowcreate.html>, plus the `Flags` field that
+    /// the typed transaction always writes. The expected encoding is assembled field by field from
+    /// the field codes of `definitions.json`, in canonical field order.
+    const ESCROW_CREATE: &str = concat!(
+        "120001",             // TransactionType EscrowCreate
+        "2200000000",         // Flags 0
+        "2300002DE3",         // SourceTag 11747
+        "2400000003",         // Sequence 3
+        "2E00005BB8",         // DestinationTag 23480
+        "20241FC8DEE6",       // CancelAfter 533257958
+        "20251FC78D66",       // FinishAfter 533171558
+        "614000000000002710", // Amount 10000 drops
+        "68400000000000000C", // Fee 12 drops
+        "701127",             // Condition, 39 bytes
+        "A0258020E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855810100",
+        "81144B4E9C06F24296074F7BC48F92A97916C6DC5EA9", // Account
+        "8314204288D2E47F8EF6C99BCC457966320D12409711", // Destination
+    );
+
+    /// `EscrowFinish` example of <https://xrpl.org/escrowfinish.html>, encoded like
+    /// [`ESCROW_CREATE`].
+    const ESCROW_FINISH: &str = concat!(
+        "120002",             // TransactionType EscrowFinish
+        "2200000000",         // Flags 0
+        "2400000008",         // Sequence 8
+        "201900000007",       // OfferSequence 7
+        "68400000000000014A", // Fee 330 drops
+        "701004A0028000",     // Fulfillment, 4 bytes
+        "701127",             // Condition, 39 bytes
+        "A0258020E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855810100",
+        "81144B4E9C06F24296074F7BC48F92A97916C6DC5EA9", // Account
+        "82144B4E9C06F24296074F7BC48F92A97916C6DC5EA9", // Owner
+    );
+
+    #[test]
+    fn test_deserialize_escrow_known_answer() {
+        let account = AccountId::from_address("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn").unwrap();
+        let destination = AccountId::from_address("rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW").unwrap();
+        let fulfillment = PreimageSha256::new(b"").unwrap();
+
+        let mut create =
+            EscrowCreateTransaction::new(account, Amount::drops(10_000).unwrap(), destination)
+                .destination_tag(23480)
+                .cancel_after(533257958)
+                .finish_after(533171558)
+                .condition(fulfillment.condition());
+        create.common.source_tag = Some(11747);
+        create.common.fee = Some(DropsAmount::from_drops(12).unwrap());
+        create.common.sequence = Some(3);
+        assert_eq!(
+            hex::encode_upper(serialize(&create).unwrap()),
+            ESCROW_CREATE
+        );
+        let deserialized: EscrowCreateTransaction =
+            deserialize(&hex::decode(ESCROW_CREATE).unwrap()).unwrap();
+        assert_eq!(deserialized.common.source_tag, Some(11747));
+        assert_eq!(deserialized.amount, Amount::drops(10_000).unwrap());
+        assert_eq!(deserialized.destination, destination);
+        assert_eq!(deserialized.destination_tag, Some(23480));
+        assert_eq!(deserialized.cancel_after, Some(533257958));
+        assert_eq!(deserialized.finish_after, Some(533171558));
+        assert_eq!(deserialized.condition, Some(fulfillment.condition()));
+
+        let mut finish = EscrowFinishTransaction::new(account, account, 7)
+            .fulfill(fulfillment.condition(), fulfillment.fulfillment());
+        finish.common.fee = Some(DropsAmount::from_drops(330).unwrap());
+        finish.common.sequence = Some(8);
+        assert_eq!(
+            hex::encode_upper(serialize(&finish).unwrap()),
+            ESCROW_FINISH
+        );
+        let deserialized: EscrowFinishTransaction =
+            deserialize(&hex::decode(ESCROW_FINISH).unwrap()).unwrap();
+        assert_eq!(deserialized.owner, account);
+        assert_eq!(deserialized.offer_sequence, 7);
+        assert_eq!(deserialized.condition, Some(fulfillment.condition()));
+        assert_eq!(deserialized.fulfillment, Some(fulfillment.fulfillment()));
+    }
+
+    #[test]
+    fn test_deserialize_payment_channel_roundtrip() {
+        let key_pair = KeyPair::from_seed("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r").unwrap();
+        let destination = AccountId::from_address("rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW").unwrap();
+        let amount = DropsAmount::from_drops(1_000_000).unwrap();
+
+        let create = PaymentChannelCreateTransaction::new(
+            key_pair.account_id(),
+            amount,
+            destination,
+            86400,
+            key_pair.public_key(),
+        )
+        .cancel_after(533171558);
+        let blob = serialize(&create).unwrap();
+        let tx: TransactionVariant = deserialize(&blob).unwrap();
+        assert_matches!(&tx, TransactionVariant::PaymentChannelCreate(deserialized) => {
+            assert_eq!(deserialized.amount, amount);
+            assert_eq!(deserialized.settle_delay, 86400);
+            assert_eq!(deserialized.public_key, key_pair.public_key());
+        });
+        assert_eq!(serialize(&tx).unwrap(), blob);
+
+        let channel = Hash256([0x5D; 32]);
+        let signature = sign::sign_payment_channel_claim(&channel, amount, &key_pair);
+        let mut claim = PaymentChannelClaimTransaction::new(destination, channel)
+            .balance(amount)
+            .claim(amount, signature, key_pair.public_key());
+        claim.flags = PaymentChannelClaimFlags::Close.into();
+        let blob = serialize(&claim).unwrap();
+        let deserialized: PaymentChannelClaimTransaction = deserialize(&blob).unwrap();
+        assert_eq!(deserialized.flags, PaymentChannelClaimFlags::Close);
+        sign::verify_payment_channel_claim(
+            &deserialized.channel,
+            deserialized.amount.unwrap(),
+            &deserialized.public_key.unwrap().0,
+            &deserialized.signature.unwrap().0,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_deserialize_payment_channel_issued_amount() {
+        struct IssuedChannelCreate(TransactionCommon);
+
+        impl Serialize for IssuedChannelCreate {
+            fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+                s.serialize_uint16(
+                    "TransactionType",
+                    TransactionType::PaymentChannelCreate as u16,
+                )?;
+                self.0.serialize(s)?;
+                let amount = Amount::issued(
+                    IssuedValue::from_mantissa_exponent(1, 0).unwrap(),
+                    CurrencyCode::standard([AsciiChar::U, AsciiChar::S, AsciiChar::D]).unwrap(),
+                    self.0.account,
+                )
+                .unwrap();
+                s.serialize_amount("Amount", amount)?;
+                s.serialize_account_id("Destination", self.0.account)?;
+                s.serialize_uint32("SettleDelay", 60)?;
+                s.serialize_blob("PublicKey", &Blob(vec![0xED; 33]))
+            }
+        }
+
+        let account = AccountId::from_address("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn").unwrap();
+        let blob = serialize(&IssuedChannelCreate(TransactionCommon::new(account))).unwrap();
+        let result = deserialize::<PaymentChannelCreateTransaction>(&blob);
+        assert_matches!(result, Err(BinaryCodecError::InvalidField(message)) => {
+            assert!(message.contains("Amount must be an XRP amount"), "message: {message}");
+        });
+    }
+
+    #[test]
+    fn test_deserialize_check_roundtrip() {
+        let account = AccountId::from_address("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn").unwrap();
+        let destination = AccountId::from_address("rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW").unwrap();
+        let send_max = Amount::issued(
+            IssuedValue::from_mantissa_exponent(100, 0).unwrap(),
+            CurrencyCode::standard([AsciiChar::U, AsciiChar::S, AsciiChar::D]).unwrap(),
+            account,
+        )
+        .unwrap();
+        let check_id = Hash256([0x83; 32]);
+
+        let create = CheckCreateTransaction::new(account, destination, send_max)
+            .destination_tag(1)
+            .expiration(570113521)
+            .invoice_id(Hash256([0x6F; 32]));
+        let blob = serialize(&create).unwrap();
+        let tx: TransactionVariant = deserialize(&blob).unwrap();
+        assert_matches!(&tx, TransactionVariant::CheckCreate(deserialized) => {
+            assert_eq!(deserialized.send_max, send_max);
+            assert_eq!(deserialized.invoice_id, Some(Hash256([0x6F; 32])));
+        });
+        assert_eq!(serialize(&tx).unwrap(), blob);
+
+        for amount in [
+            CheckCashAmount::Amount(send_max),
+            CheckCashAmount::DeliverMin(send_max),
+        ] {
+            let cash = CheckCashTransaction::new(destination, check_id, amount);
+            let blob = serialize(&cash).unwrap();
+            let deserialized: CheckCashTransaction = deserialize(&blob).unwrap();
+            assert_eq!(deserialized.check_id, check_id);
+            assert_eq!(deserialized.amount, amount);
+        }
+
+        let cancel = CheckCancelTransaction::new(account, check_id);
+        let blob = serialize(&cancel).unwrap();
+        let deserialized: CheckCancelTransaction = deserialize(&blob).unwrap();
+        assert_eq!(deserialized.check_id, check_id);
+    }
+
+    /// `CheckCreate` example of <https://xrpl.org/checkcreate.html>, encoded like
+    /// [`ESCROW_CREATE`].
+    const CHECK_CREATE: &str = concat!(
+        "120010",     // TransactionType CheckCreate
+        "2200000000", // Flags 0
+        "2400000002", // Sequence 2
+        "2A21FB3DF1", // Expiration 570113521
+        "2E00000001", // DestinationTag 1
+        "5011",       // InvoiceID
+        "6F1DFD1D0FE8A32E40E1F2C05CF1C15545BAB56B617F9C6C2D63A6B704BEF59B",
+        "68400000000000000C",                           // Fee 12 drops
+        "694000000005F5E100",                           // SendMax 100000000 drops
+        "81147990EC5D1D8DF69E070A968D4B186986FDF06ED0", // Account
+        "831449FF0C73CA6AF9733DA805F76CA2C37776B7C46B", // Destination
+    );
+
+    /// `CheckCash` example of <https://xrpl.org/checkcash.html>, encoded like
+    /// [`ESCROW_CREATE`].
+    const CHECK_CASH: &str = concat!(
+        "120011",     // TransactionType CheckCash
+        "2200000000", // Flags 0
+        "2400000003", // Sequence 3
+        "5018",       // CheckID
+        "838766BA2B995C00744175F69A1B11E32C3DBC40E64801A4056FCBD657F57334",
+        "614000000005F5E100",                           // Amount 100000000 drops
+        "68400000000000000C",                           // Fee 12 drops
+        "811449FF0C73CA6AF9733DA805F76CA2C37776B7C46B", // Account
+    );
+
+    #[test]
+    fn test_deserialize_check_known_answer() {
+        let account = AccountId::from_address("rUn84CUYbNjRoTQ6mSW7BVJPSVJNLb1QLo").unwrap();
+        let destination = AccountId::from_address("rfkE1aSy9G8Upk4JssnwBxhEv5p4mn2KTy").unwrap();
+        let send_max = Amount::drops(100_000_000).unwrap();
+        let invoice_id =
+            Hash256::from_hex("6F1DFD1D0FE8A32E40E1F2C05CF1C15545BAB56B617F9C6C2D63A6B704BEF59B")
+                .unwrap();
+
+        let mut create = CheckCreateTransaction::new(account, destination, send_max)
+            .destination_tag(1)
+            .expiration(570113521)
+            .invoice_id(invoice_id);
+        create.common.fee = Some(DropsAmount::from_drops(12).unwrap());
+        create.common.sequence = Some(2);
+        assert_eq!(hex::encode_upper(serialize(&create).unwrap()), CHECK_CREATE);
+        let deserialized: CheckCreateTransaction =
+            deserialize(&hex::decode(CHECK_CREATE).unwrap()).unwrap();
+        assert_eq!(deserialized.common.account, account);
+        assert_eq!(deserialized.destination, destination);
+        assert_eq!(deserialized.send_max, send_max);
+        assert_eq!(deserialized.destination_tag, Some(1));
+        assert_eq!(deserialized.expiration, Some(570113521));
+        assert_eq!(deserialized.invoice_id, Some(invoice_id));
+
+        // The cashed check was created by the account's transaction with sequence 4
+        let check_id = crate::keylet::check(account, 4);
+        let mut cash =
+            CheckCashTransaction::new(destination, check_id, CheckCashAmount::Amount(send_max));
+        cash.common.fee = Some(DropsAmount::from_drops(12).unwrap());
+        cash.common.sequence = Some(3);
+        assert_eq!(hex::encode_upper(serialize(&cash).unwrap()), CHECK_CASH);
+        let deserialized: CheckCashTransaction =
+            deserialize(&hex::decode(CHECK_CASH).unwrap()).unwrap();
+        assert_eq!(deserialized.common.account, destination);
+        assert_eq!(deserialized.check_id, check_id);
+        assert_eq!(deserialized.amount, CheckCashAmount::Amount(send_max));
+    }
+
+    #[test]
+    fn test_deserialize_check_cash_amount() {
+        struct CheckCashBoth(CheckCashTransaction);
+
+        impl Serialize for CheckCashBoth {
+            fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+                self.0.serialize(s)?;
+                s.serialize_amount("DeliverMin", Amount::drops(1).unwrap())
+            }
+        }
+
+        let account = AccountId::from_address("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn").unwrap();
+        let tx = CheckCashTransaction::new(
+            account,
+            Hash256([0x83; 32]),
+            CheckCashAmount::Amount(Amount::drops(1).unwrap()),
+        );
+        let blob = serialize(&CheckCashBoth(tx)).unwrap();
+        let result = deserialize::<CheckCashTransaction>(&blob);
+        assert_matches!(result, Err(BinaryCodecError::InvalidField(message)) => {
+            assert!(message.contains("exactly one of Amount and DeliverMin, found both"), "message: {message}");
+        });
+    }
+
+    #[test]
+    fn test_deserialize_nftoken_roundtrip() {
+        let issuer = AccountId::from_address("rNCFjv8Ek5oDrNiMJ3pw6eLLFtMjZLJnf2").unwrap();
+        let buyer = AccountId::from_address("rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW").unwrap();
+        let broker = AccountId::from_address("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn").unwrap();
+
+        let mut mint = NFTokenMintTransaction::new(issuer, 146999694)
+            .transfer_fee(3140)
+            .unwrap()
+            .uri(Blob(
+                b"ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf4dfuylqabf3oclgtqy55fbzdi".to_vec(),
+            ));
+        mint.flags =
+            NFTokenMintFlags::Burnable | NFTokenMintFlags::OnlyXRP | NFTokenMintFlags::Transferable;
+        let blob = serialize(&mint).unwrap();
+        let tx: TransactionVariant = deserialize(&blob).unwrap();
+        assert_matches!(&tx, TransactionVariant::NFTokenMint(deserialized) => {
+            assert_eq!(deserialized.flags, mint.flags);
+            assert_eq!(deserialized.transfer_fee, Some(3140));
+            assert_eq!(deserialized.uri, mint.uri);
+        });
+        assert_eq!(serialize(&tx).unwrap(), blob);
+
+        // The id of the token minted above, when the issuer had minted 3429 tokens before
+        let nftoken_id = NFTokenId {
+            raw_flags: 0x000B,
+            transfer_fee: 3140,
+            issuer,
+            taxon: 146999694,
+            sequence: 3429,
+        };
+        assert_eq!(
+            nftoken_id.flags(),
+            NFTokenFlags::Burnable | NFTokenFlags::OnlyXRP | NFTokenFlags::Transferable
+        );
+        let nftoken_id = nftoken_id.to_hash();
+
+        let sell = NFTokenCreateOfferTransaction::new_sell_offer(
+            issuer,
+            nftoken_id,
+            Amount::drops(1_000_000).unwrap(),
+        );
+        let buy = NFTokenCreateOfferTransaction::new_buy_offer(
+            buyer,
+            nftoken_id,
+            Amount::drops(1_100_000).unwrap(),
+            issuer,
+        )
+        .expiration(570113521);
+        for offer in [sell, buy] {
+            let blob = serialize(&offer).unwrap();
+            let deserialized: NFTokenCreateOfferTransaction = deserialize(&blob).unwrap();
+            assert_eq!(deserialized.nftoken_id, nftoken_id);
+            assert_eq!(deserialized.owner, offer.owner);
+            assert_eq!(
+                deserialized
+                    .flags
+                    .contains(NFTokenCreateOfferFlags::SellNFToken),
+                offer.owner.is_none()
+            );
+        }
+
+        let accept = NFTokenAcceptOfferTransaction::new_brokered(
+            broker,
+            Hash256([0x01; 32]),
+            Hash256([0x02; 32]),
+        )
+        .broker_fee(Amount::drops(100_000).unwrap());
+        let blob = serialize(&accept).unwrap();
+        let deserialized: NFTokenAcceptOfferTransaction = deserialize(&blob).unwrap();
+        assert_eq!(deserialized.nftoken_sell_offer, Some(Hash256([0x01; 32])));
+        assert_eq!(deserialized.nftoken_buy_offer, Some(Hash256([0x02; 32])));
+        assert_eq!(
+            deserialized.nftoken_broker_fee,
+            Some(Amount::drops(100_000).unwrap())
+        );
+
+        let cancel = NFTokenCancelOfferTransaction::new(
+            issuer,
+            vec![Hash256([0x01; 32]), Hash256([0x02; 32])],
+        );
+        let blob = serialize(&cancel).unwrap();
+        let deserialized: NFTokenCancelOfferTransaction = deserialize(&blob).unwrap();
+        assert_eq!(deserialized.nftoken_offers, cancel.nftoken_offers);
+    }
+
+    /// `NFTokenMint` example of <https://xrpl.org/nftokenmint.html>, encoded like
+    /// [`ESCROW_CREATE`].
+    const NFTOKEN_MINT: &str = concat!(
+        "120019",             // TransactionType NFTokenMint
+        "14013A",             // TransferFee 314
+        "2200000008",         // Flags tfTransferable
+        "240000000C",         // Sequence 12
+        "202A00000000",       // NFTokenTaxon 0
+        "68400000000000000A", // Fee 10 drops
+        "7542",               // URI, 66 bytes
+        "697066733A2F2F62616679626569676479727A74357366703775646D37687537367568377932366E6634646675796C71616266336F636C67747179353566627A6469",
+        "81140A20B3C85F482532A9578DBB3950B85CA06594D1", // Account
+        "F9",                                           // Memos
+        "EA",                                           // Memo
+        "7C1F",                                         // MemoType, 31 bytes
+        "687474703A2F2F6578616D706C652E636F6D2F6D656D6F2F67656E65726963",
+        "7D0472656E74", // MemoData, 4 bytes
+        "E1",           // end of Memo
+        "F1",           // end of Memos
+    );
+
+    /// `NFTokenCreateOffer` example of <https://xrpl.org/nftokencreateoffer.html>, encoded like
+    /// [`ESCROW_CREATE`].
+    const NFTOKEN_CREATE_OFFER: &str = concat!(
+        "12001B",     // TransactionType NFTokenCreateOffer
+        "2200000001", // Flags tfSellNFToken
+        "2400000008", // Sequence 8
+        "5A",         // NFTokenID
+        "000100001E962F495F07A990F4ED55ACCFEEF365DBAA76B6A048C0A200000007",
+        "6140000000000F4240",                           // Amount 1000000 drops
+        "68400000000000000A",                           // Fee 10 drops
+        "81141E962F495F07A990F4ED55ACCFEEF365DBAA76B6", // Account
+    );
+
+    #[test]
+    fn test_deserialize_nftoken_known_answer() {
+        let account = AccountId::from_address("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B").unwrap();
+        let uri = Blob::from_hex("697066733A2F2F62616679626569676479727A74357366703775646D37687537367568377932366E6634646675796C71616266336F636C67747179353566627A6469").unwrap();
+        let memo = Memo {
+            memo_type: Blob::from_hex(
+                "687474703A2F2F6578616D706C652E636F6D2F6D656D6F2F67656E65726963",
+            )
+            .unwrap(),
+            memo_data: Blob::from_hex("72656E74").unwrap(),
+            memo_format: None,
+        };
+
+        let mut mint = NFTokenMintTransaction::new(account, 0)
+            .transfer_fee(314)
+            .unwrap()
+            .uri(uri.clone());
+        mint.common.fee = Some(DropsAmount::from_drops(10).unwrap());
+        mint.common.sequence = Some(12);
+        mint.common.memos = vec![memo.clone()];
+        assert_eq!(hex::encode_upper(serialize(&mint).unwrap()), NFTOKEN_MINT);
+        let deserialized: NFTokenMintTransaction =
+            deserialize(&hex::decode(NFTOKEN_MINT).unwrap()).unwrap();
+        assert_eq!(deserialized.flags, NFTokenMintFlags::Transferable);
+        assert_eq!(deserialized.transfer_fee, Some(314));
+        assert_eq!(deserialized.nftoken_taxon, 0);
+        assert_eq!(deserialized.uri, Some(uri));
+        assert_eq!(deserialized.common.memos, vec![memo]);
+
+        // The offered token was minted by the offering account with taxon 0 and sequence 7
+        let account = AccountId::from_address("rs8jBmmfpwgmrSPgwMsh7CvKRmRt1JTVSX").unwrap();
+        let nftoken_id =
+            Hash256::from_hex("000100001E962F495F07A990F4ED55ACCFEEF365DBAA76B6A048C0A200000007")
+                .unwrap();
+        let id = NFTokenId::from_hash(nftoken_id);
+        assert_eq!(id.flags(), NFTokenFlags::Burnable);
+        assert_eq!(id.issuer, account);
+        assert_eq!(id.taxon, 0);
+        assert_eq!(id.sequence, 7);
+
+        let mut offer = NFTokenCreateOfferTransaction::new_sell_offer(
+            account,
+            nftoken_id,
+            Amount::drops(1_000_000).unwrap(),
+        );
+        offer.common.fee = Some(DropsAmount::from_drops(10).unwrap());
+        offer.common.sequence = Some(8);
+        assert_eq!(
+            hex::encode_upper(serialize(&offer).unwrap()),
+            NFTOKEN_CREATE_OFFER
+        );
+        let deserialized: NFTokenCreateOfferTransaction =
+            deserialize(&hex::decode(NFTOKEN_CREATE_OFFER).unwrap()).unwrap();
+        assert_eq!(deserialized.flags, NFTokenCreateOfferFlags::SellNFToken);
+        assert_eq!(deserialized.nftoken_id, nftoken_id);
+        assert_eq!(deserialized.amount, Amount::drops(1_000_000).unwrap());
+    }
+
+    #[test]
+    fn test_deserialize_nftoken_mint_transfer_fee() {
+        let issuer = AccountId::from_address("rNCFjv8Ek5oDrNiMJ3pw6eLLFtMjZLJnf2").unwrap();
+        let mint = NFTokenMintTransaction::new(issuer, 0);
+        assert_matches!(mint.clone().transfer_fee(50001), Err(Error::InvalidData(_)));
+        let mint = mint.transfer_fee(50000).unwrap();
+        assert_eq!(mint.flags, NFTokenMintFlags::Transferable);
+
+        let mut too_high = mint.clone();
+        too_high.transfer_fee = Some(50001);
+        let result = deserialize::<NFTokenMintTransaction>(&serialize(&too_high).unwrap());
+        assert_matches!(result, Err(BinaryCodecError::InvalidField(message)) => {
+            assert!(message.contains("Transfer fee 50001 exceeds the maximum of 50000"), "message: {message}");
+        });
+
+        let mut not_transferable = mint;
+        not_transferable.flags = Default::default();
+        let result = deserialize::<NFTokenMintTransaction>(&serialize(&not_transferable).unwrap());
+        assert_matches!(result, Err(BinaryCodecError::InvalidField(message)) => {
+            assert!(message.contains("TransferFee requires the Transferable flag"), "message: {message}");
+        });
+    }
+
+    #[test]
+    fn test_deserialize_amm_roundtrip() {
+        let account = AccountId::from_address("rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW").unwrap();
+        let issuer = AccountId::from_address("rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq").unwrap();
+        let usd_code: CurrencyCode = "USD".parse().unwrap();
+        let usd = Currency::issued(usd_code, issuer).unwrap();
+        let usd_amount = |mantissa| {
+            Amount::issued(
+                IssuedValue::from_mantissa_exponent(mantissa, 0).unwrap(),
+                usd_code,
+                issuer,
+            )
+            .unwrap()
+        };
+        let lp_token_amount = |mantissa| {
+            Amount::issued(
+                IssuedValue::from_mantissa_exponent(mantissa, 0).unwrap(),
+                lp_token_currency_code(Currency::xrp(), usd),
+                issuer,
+            )
+            .unwrap()
+        };
+
+        let create = AMMCreateTransaction::new(
+            account,
+            Amount::drops(25_000_000).unwrap(),
+            usd_amount(250),
+            500,
+        );
+        let blob = serialize(&create).unwrap();
+        let tx: TransactionVariant = deserialize(&blob).unwrap();
+        assert_matches!(&tx, TransactionVariant::AMMCreate(deserialized) => {
+            assert_eq!(deserialized.amount, create.amount);
+            assert_eq!(deserialized.amount2, create.amount2);
+            assert_eq!(deserialized.trading_fee, 500);
+        });
+        assert_eq!(serialize(&tx).unwrap(), blob);
+
+        let deposits = [
+            AMMDepositTransaction::new_lp_token(account, Currency::xrp(), usd, lp_token_amount(10)),
+            AMMDepositTransaction::new_single_asset(account, Currency::xrp(), usd, usd_amount(5)),
+            AMMDepositTransaction::new_two_asset(
+                account,
+                Currency::xrp(),
+                usd,
+                Amount::drops(1_000_000).unwrap(),
+                usd_amount(10),
+            ),
+            AMMDepositTransaction::new_two_asset_if_empty(
+                account,
+                Currency::xrp(),
+                usd,
+                Amount::drops(1_000_000).unwrap(),
+                usd_amount(10),
+            )
+            .trading_fee(100),
+        ];
+        for deposit in deposits {
+            let blob = serialize(&deposit).unwrap();
+            let deserialized: AMMDepositTransaction = deserialize(&blob).unwrap();
+            assert_eq!(deserialized.flags, deposit.flags);
+            assert_eq!(deserialized.asset, Currency::xrp());
+            assert_eq!(deserialized.asset2, usd);
+            assert_eq!(deserialized.amount, deposit.amount);
+            assert_eq!(deserialized.amount2, deposit.amount2);
+            assert_eq!(deserialized.lp_token_out, deposit.lp_token_out);
+            assert_eq!(deserialized.trading_fee, deposit.trading_fee);
+        }
+
+        let withdraw = AMMWithdrawTransaction::new_withdraw_all(account, Currency::xrp(), usd);
+        let blob = serialize(&withdraw).unwrap();
+        let deserialized: AMMWithdrawTransaction = deserialize(&blob).unwrap();
+        assert_eq!(deserialized.flags, AMMWithdrawFlags::WithdrawAll);
+        assert_eq!(deserialized.amount, None);
+
+        let withdraw = AMMWithdrawTransaction::new_one_asset_lp_token(
+            account,
+            Currency::xrp(),
+            usd,
+            usd_amount(5),
+            lp_token_amount(3),
+        );
+        let blob = serialize(&withdraw).unwrap();
+        let deserialized: AMMWithdrawTransaction = deserialize(&blob).unwrap();
+        assert_eq!(deserialized.flags, AMMWithdrawFlags::OneAssetLPToken);
+        assert_eq!(deserialized.lp_token_in, Some(lp_token_amount(3)));
+
+        let bid = AMMBidTransaction::new(account, Currency::xrp(), usd)
+            .bid_max(lp_token_amount(100))
+            .auth_accounts([issuer]);
+        let blob = serialize(&bid).unwrap();
+        let deserialized: AMMBidTransaction = deserialize(&blob).unwrap();
+        assert_eq!(deserialized.bid_min, None);
+        assert_eq!(deserialized.bid_max, Some(lp_token_amount(100)));
+        assert_eq!(deserialized.auth_accounts, bid.auth_accounts);
+
+        let vote = AMMVoteTransaction::new(account, Currency::xrp(), usd, 600);
+        let blob = serialize(&vote).unwrap();
+        let tx: TransactionVariant = deserialize(&blob).unwrap();
+        assert_matches!(&tx, TransactionVariant::AMMVote(deserialized) => {
+            assert_eq!(deserialized.trading_fee, 600);
+        });
+
+        let delete = AMMDeleteTransaction::new(account, Currency::xrp(), usd);
+        let blob = serialize(&delete).unwrap();
+        let tx: TransactionVariant = deserialize(&blob).unwrap();
+        assert_matches!(&tx, TransactionVariant::AMMDelete(deserialized) => {
+            assert_eq!(deserialized.asset2, usd);
+        });
+    }
+
+    /// `AMMCreate` example of <https://xrpl.org/ammcreate.html>, encoded like [`ESCROW_CREATE`]
+    const AMM_CREATE: &str = concat!(
+        "120023",             // TransactionType AMMCreate
+        "1501F4",             // TradingFee 500
+        "2280000000",         // Flags tfFullyCanonicalSig
+        "2400000006",         // Sequence 6
+        "61D4C8E1BC9BF04000", // Amount 25 TST
+        "0000000000000000000000005453540000000000",
+        "F2F97C4301C80D60F86653A319AA7F302C70B83B",
+        "6840000000001E8480",                           // Fee 2000000 drops
+        "6B400000000EE6B280",                           // Amount2 250000000 drops
+        "8114BFCF819B0562067A282537A0D0EA7C29A4E0493F", // Account
+    );
+
+    /// `AMMDeposit` example of <https://xrpl.org/ammdeposit.html>, encoded like
+    /// [`ESCROW_CREATE`].
+    const AMM_DEPOSIT: &str = concat!(
+        "120024",             // TransactionType AMMDeposit
+        "2200100000",         // Flags tfTwoAsset
+        "2400000007",         // Sequence 7
+        "61D488E1BC9BF04000", // Amount 2.5 TST
+        "0000000000000000000000005453540000000000",
+        "F2F97C4301C80D60F86653A319AA7F302C70B83B",
+        "68400000000000000A",                           // Fee 10 drops
+        "6B4000000001C9C380",                           // Amount2 30000000 drops
+        "8114BFCF819B0562067A282537A0D0EA7C29A4E0493F", // Account
+        "0318",                                         // Asset XRP
+        "0000000000000000000000000000000000000000",
+        "0418", // Asset2 TST
+        "0000000000000000000000005453540000000000",
+        "F2F97C4301C80D60F86653A319AA7F302C70B83B",
+    );
+
+    #[test]
+    fn test_deserialize_amm_known_answer() {
+        let account = AccountId::from_address("rJVUeRqDFNs2xqA7ncVE6ZoAhPUoaJJSQm").unwrap();
+        let issuer = AccountId::from_address("rP9jPyP5kyvFRb6ZiRghAGw5u8SGAmU4bd").unwrap();
+        let tst_code = CurrencyCode::standard([AsciiChar::T, AsciiChar::S, AsciiChar::T]).unwrap();
+        let tst_amount = |mantissa, exponent| {
+            Amount::issued(
+                IssuedValue::from_mantissa_exponent(mantissa, exponent).unwrap(),
+                tst_code,
+                issuer,
+            )
+            .unwrap()
+        };
+
+        let mut create = AMMCreateTransaction::new(
+            account,
+            tst_amount(25, 0),
+            Amount::drops(250_000_000).unwrap(),
+            500,
+        );
+        create.flags = AMMCreateFlags::FullyCanonicalSig.into();
+        create.common.fee = Some(DropsAmount::from_drops(2_000_000).unwrap());
+        create.common.sequence = Some(6);
+        assert_eq!(hex::encode_upper(serialize(&create).unwrap()), AMM_CREATE);
+        let deserialized: AMMCreateTransaction =
+            deserialize(&hex::decode(AMM_CREATE).unwrap()).unwrap();
+        assert_eq!(deserialized.flags, AMMCreateFlags::FullyCanonicalSig);
+        assert_eq!(deserialized.amount, tst_amount(25, 0));
+        assert_eq!(deserialized.amount2, Amount::drops(250_000_000).unwrap());
+        assert_eq!(deserialized.trading_fee, 500);
+
+        let tst = Currency::issued(tst_code, issuer).unwrap();
+        let mut deposit = AMMDepositTransaction::new_two_asset(
+            account,
+            Currency::xrp(),
+            tst,
+            tst_amount(25, -1),
+            Amount::drops(30_000_000).unwrap(),
+        );
+        deposit.common.fee = Some(DropsAmount::from_drops(10).unwrap());
+        deposit.common.sequence = Some(7);
+        assert_eq!(hex::encode_upper(serialize(&deposit).unwrap()), AMM_DEPOSIT);
+        let deserialized: AMMDepositTransaction =
+            deserialize(&hex::decode(AMM_DEPOSIT).unwrap()).unwrap();
+        assert_eq!(deserialized.flags, AMMDepositFlags::TwoAsset);
+        assert_eq!(deserialized.asset, Currency::xrp());
+        assert_eq!(deserialized.asset2, tst);
+        assert_eq!(deserialized.amount, Some(tst_amount(25, -1)));
+        assert_eq!(
+            deserialized.amount2,
+            Some(Amount::drops(30_000_000).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_deserialize_wrong_transaction_type() {
+        let blob = hex::decode(SIGNED_PAYMENT).unwrap();
+        let result = deserialize::<OfferCancelTransaction>(&blob);
+        assert_matches!(result, Err(BinaryCodecError::InvalidField(message)) => {
+            assert!(message.contains("Expected transaction type OfferCancel, found Payment"), "message: {message}");
+        });
+    }
+
+    #[test]
+    fn test_deserialize_trust_set_drops_limit() {
+        struct DropsTrustSet;
+
+        impl Serialize for DropsTrustSet {
+            fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+                s.serialize_uint16("TransactionType", TransactionType::TrustSet as u16)?;
+                s.serialize_account_id("Account", AccountId::default())?;
+                s.serialize_amount("LimitAmount", Amount::drops(100).unwrap())?;
+                Ok(())
+            }
+        }
+
+        let blob = serialize(&DropsTrustSet).unwrap();
+        let result = deserialize::<TrustSetTransaction>(&blob);
+        assert_matches!(result, Err(BinaryCodecError::InvalidField(message)) => {
+            assert!(message.contains("LimitAmount must be an issued amount"), "message: {message}");
+        });
+    }
+
+    /// Fields not modelled by the transaction type must not be silently dropped
+    #[test]
+    fn test_deserialize_unknown_field() {
+        struct PaymentWithExpiration(PaymentTransaction);
+
+        impl Serialize for PaymentWithExpiration {
+            fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+                self.0.serialize(s)?;
+                s.serialize_uint32("Expiration", 1)?;
+                Ok(())
+            }
+        }
+
+        let blob = hex::decode(SIGNED_PAYMENT).unwrap();
+        let tx: PaymentTransaction = deserialize(&blob).unwrap();
+        let blob = serialize(&PaymentWithExpiration(tx)).unwrap();
+        let result = deserialize::<PaymentTransaction>(&blob);
+        assert_matches!(result, Err(BinaryCodecError::InvalidField(message)) => {
+            assert!(message.contains("Field Expiration is not part of the deserialized object"), "message: {message}");
+        });
+    }
+
+    #[test]
+    fn test_deserialize_missing_required_field() {
+        let blob = hex::decode(SIGNED_PAYMENT).unwrap();
+        let tx: PaymentTransaction = deserialize(&blob).unwrap();
+        let blob: Vec<u8> = serialize(&tx.common).unwrap();
+        let result = deserialize::<TransactionVariant>(&blob);
+        assert_matches!(result, Err(BinaryCodecError::InvalidField(message)) => {
+            assert!(message.contains("Required field TransactionType is missing"), "message: {message}");
+        });
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct CompositeFields {
+        memo: Memo,
+        paths: Vec<Path>,
+        amendments: Vec<Hash256>,
+        asset: Currency,
+        asset2: Currency,
+        bridge: XChainBridge,
+        assets_total: Number,
+    }
+
+    impl Serialize for CompositeFields {
+        fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+            s.serialize_object("Memo", &self.memo)?;
+            s.serialize_path_set("Paths", &self.paths)?;
+            s.serialize_vector256("Amendments", &self.amendments)?;
+            s.serialize_issue("Asset", self.asset)?;
+            s.serialize_issue("Asset2", self.asset2)?;
+            s.serialize_xchain_bridge("XChainBridge", &self.bridge)?;
+            s.serialize_number("AssetsTotal", self.assets_total)?;
+            Ok(())
+        }
+    }
+
+    impl Deserialize for CompositeFields {
+        fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+            Ok(Self {
+                memo: required("Memo", d.deserialize_object("Memo")?)?,
+                paths: required("Paths", d.deserialize_path_set("Paths")?)?,
+                amendments: required("Amendments", d.deserialize_vector256("Amendments")?)?,
+                asset: required("Asset", d.deserialize_issue("Asset")?)?,
+                asset2: required("Asset2", d.deserialize_issue("Asset2")?)?,
+                bridge: required("XChainBridge", d.deserialize_xchain_bridge("XChainBridge")?)?,
+                assets_total: required("AssetsTotal", d.deserialize_number("AssetsTotal")?)?,
+            })
+        }
+    }
+
+    #[test]
+    fn test_deserialize_composite_fields_roundtrip() {
+        let account = AccountId::from_address("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh").unwrap();
+        let issuer = AccountId::from_address("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn").unwrap();
+        let usd = CurrencyCode::standard([AsciiChar::U, AsciiChar::S, AsciiChar::D]).unwrap();
+        let object = CompositeFields {
+            memo: Memo {
+                memo_type: Blob(vec![1, 2]),
+                memo_data: Blob(vec![3]),
+                memo_format: None,
+            },
+            paths: vec![
+                Path(vec![
+                    PathStep::account(account),
+                    PathStep::currency(usd, Some(issuer)),
+                ]),
+                Path(vec![PathStep::currency(CurrencyCode::xrp(), None)]),
+            ],
+            amendments: vec![Hash256([1; 32]), Hash256([2; 32])],
+            asset: Currency::xrp(),
+            asset2: Currency::issued(usd, issuer).unwrap(),
+            bridge: XChainBridge {
+                locking_chain_door: account,
+                locking_chain_issue: Currency::xrp(),
+                issuing_chain_door: issuer,
+                issuing_chain_issue: Currency::issued(usd, issuer).unwrap(),
+            },
+            assets_total: "-12.5".parse().unwrap(),
+        };
+
+        let blob = serialize(&object).unwrap();
+        let deserialized: CompositeFields = deserialize(&blob).unwrap();
+        assert_eq!(deserialized, object);
+        assert_eq!(serialize(&deserialized).unwrap(), blob);
+    }
+
+    #[test]
+    fn test_deserialize_strict() {
+        let blob = hex::decode(SIGNED_PAYMENT).unwrap();
+        let tx: PaymentTransaction = deserialize_strict(&blob).unwrap();
+        assert_eq!(serialize(&tx).unwrap(), blob);
+
+        // Flags before TransactionType
+        let mut blob = hex::decode(SIGNED_PAYMENT).unwrap();
+        blob[..8].rotate_left(3);
+        assert!(deserialize::<PaymentTransaction>(&blob).is_ok());
+        assert_matches!(
+            deserialize_strict::<PaymentTransaction>(&blob),
+            Err(BinaryCodecError::NonCanonical { offset: 5, message }) => {
+                assert_eq!(message, "Field TransactionType is not in canonical order");
+            }
+        );
+    }
+
+    proptest! {
+        /// Deserializing a malformed transaction must not panic, and a deserialized transaction
+        /// must serialize to bytes that deserialize to the same transaction
+        #[test]
+        fn test_deserialize_mutated_transactions(
+            fixture in prop::sample::select(&[SIGNED_PAYMENT, SIGNED_ISSUED_PAYMENT, PAYMENT_WITH_PATHS][..]),
+            mutations in prop::collection::vec(any::<(Index, u8)>(), 1..4),
+            truncate in any::<Index>(),
+        ) {
+            let mut blob = hex::decode(fixture).unwrap();
+            for (index, byte) in mutations {
+                *index.get_mut(&mut blob) = byte;
+            }
+            blob.truncate(truncate.index(blob.len() + 1));
+            if let Ok(tx) = deserialize::<TransactionVariant>(&blob) {
+                let serialized = serialize(&tx).unwrap();
+                let deserialized: TransactionVariant = deserialize(&serialized).unwrap();
+                prop_assert_eq!(serialize(&deserialized).unwrap(), serialized);
+            }
+        }
+    }
+}
@@ -1,11 +1,16 @@
-use crate::alloc::{string::String, vec, vec::Vec};
+use crate::alloc::{format, string::String, vec, vec::Vec};
 use crate::error::BinaryCodecError;
 use crate::serializer::{
+    definitions::{definitions, Definitions},
     field_id::{FieldId, TypeCode},
     field_info::FieldInfo,
+    path_set,
 };
+use core::str::FromStr;
+use xrpl_types::deserialize::Deserialize;
 use xrpl_types::{
-    AccountId, Amount, Blob, Hash128, Hash160, Hash256, UInt16, UInt32, UInt8, Uint64,
+    AccountId, Amount, Blob, Currency, CurrencyCode, DropsAmount, Hash128, Hash160, Hash256,
+    IssuedValue, Number, Path, PathStep, UInt16, UInt32, UInt8, Uint64, XChainBridge,
 };
 
 use bytes::{Buf, Bytes};
@@ -14,32 +19,82 @@ use hashbrown::HashMap;
 #[cfg(feature = "std")]
 use std::collections::HashMap;
 
+#[cfg(feature = "json")]
+use crate::alloc::{collections::BTreeMap, string::ToString};
 #[cfg(feature = "json")]
 use serde_json::Value;
 
 mod constants {
-    pub const OBJECT_NAME: &str = "STObject";
     pub const OBJECT_END_MARKER_NAME: &str = "ObjectEndMarker";
     pub const OBJECT_END_MARKER_BYTE: &[u8] = &[0xE1];
 
     pub const ARRAY_END_MARKER: &[u8] = &[0xf1];
     pub const ARRAY_END_MARKER_NAME: &str = "ArrayEndMarker";
     pub const OBJECT_END_MARKER_ARRAY: &[u8] = &[0xE1];
+
+    /// Maximum nesting depth of objects and arrays, like rippled
+    pub const MAX_NESTING_DEPTH: usize = 10;
+
+    /// Maximum length that can be encoded with a length prefix <https://xrpl.org/serialization.html#length-prefixing>
+    pub const MAX_VARIABLE_LENGTH: usize = 918744;
+
+    /// Standard currency code "XRP", which is not a valid issued currency
+    pub const XRP_STANDARD_CURRENCY_CODE: [u8; 20] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, b'X', b'R', b'P', 0, 0, 0, 0, 0,
+    ];
 }
 
 #[derive(Debug, Clone)]
 pub struct FieldInstance {
-    info: FieldInfo,
-    name: String,
+    pub info: FieldInfo,
+    pub name: String,
 }
 
-#[derive(Debug, Clone, Default)]
-pub struct Deserializer {
+#[derive(Debug, Clone)]
+pub struct Deserializer<'d> {
     bytes: Bytes,
     field_ordinal_lookup: HashMap<u32, FieldInstance>,
+    /// Definitions of the names of enum values, like transaction types
+    definitions: &'d Definitions,
+    /// Number of objects and arrays being read
+    depth: usize,
+    /// Length of the bytes being read, used to report offsets
+    len: usize,
+    /// Reject non-canonical encodings, see [`Deserializer::strict`]
+    strict: bool,
+}
+
+impl Default for Deserializer<'_> {
+    fn default() -> Self {
+        Self {
+            bytes: Bytes::new(),
+            field_ordinal_lookup: HashMap::new(),
+            definitions: definitions(),
+            depth: 0,
+            len: 0,
+            strict: false,
+        }
+    }
+}
+
+impl<'d> Deserializer<'d> {
+    /// Deserializer using the given definitions, e.g. loaded with [`Definitions::from_json`]
+    pub fn with_definitions(bytes: Vec<u8>, definitions: &'d Definitions) -> Self {
+        Self {
+            definitions,
+            ..Deserializer::new(bytes, definitions.field_info_map())
+        }
+    }
+
+    /// Definitions used to name enum values, like transaction types
+    pub fn definitions(&self) -> &'d Definitions {
+        self.definitions
+    }
 }
 
-impl Deserializer {
+impl Deserializer<'_> {
+    /// Deserializer using the given fields. The built-in definitions are used for other
+    /// definitions, see [`Deserializer::with_definitions`].
     pub fn new(bytes: Vec<u8>, field_info_map: &HashMap<String, FieldInfo>) -> Self {
         let mut field_ordinal_lookup = HashMap::new();
         for (name, info) in field_info_map {
@@ -51,12 +106,37 @@ impl Deserializer {
             field_ordinal_lookup.insert(ordinal, field);
         }
         Self {
+            len: bytes.len(),
             bytes: Bytes::from(bytes),
             field_ordinal_lookup,
+            definitions: definitions(),
+            depth: 0,
+            strict: false,
+        }
+    }
+
+    /// Sets whether to reject encodings that rippled considers non-canonical: fields out of
+    /// canonical order or duplicated, lengths beyond the maximum length prefix, issued values and
+    /// numbers that are not normalized, drops amounts above the total XRP supply, empty paths and
+    /// "XRP" as an issued currency code. Such input is rejected with
+    /// [`BinaryCodecError::NonCanonical`]. Objects decoded in strict mode encode to the same bytes.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Offset of the next byte to read
+    fn position(&self) -> usize {
+        self.len - self.bytes.remaining()
+    }
+
+    fn non_canonical(offset: usize, message: &str) -> BinaryCodecError {
+        BinaryCodecError::NonCanonical {
+            offset,
+            message: message.into(),
         }
     }
 
-    #[allow(dead_code)]
     fn read(&mut self, n: usize) -> Result<Vec<u8>, BinaryCodecError> {
         if self.bytes.remaining() < n {
             return Err(BinaryCodecError::InsufficientBytes("read".into()));
@@ -73,7 +153,21 @@ impl Deserializer {
         }
     }
 
+    /// Reads a length prefix. Each length has a single encoding, but the three byte prefix can
+    /// encode lengths beyond the maximum.
     fn read_variable_length(&mut self) -> Result<usize, BinaryCodecError> {
+        let offset = self.position();
+        let len = self.read_length_prefix()?;
+        if self.strict && len > constants::MAX_VARIABLE_LENGTH {
+            return Err(Self::non_canonical(
+                offset,
+                "Length exceeds the maximum length prefix",
+            ));
+        }
+        Ok(len)
+    }
+
+    fn read_length_prefix(&mut self) -> Result<usize, BinaryCodecError> {
         let b1 = self.read_u8()? as usize;
         if b1 <= 192 {
             Ok(b1)
@@ -114,7 +208,7 @@ impl Deserializer {
         Ok((type_code << 16) | nth)
     }
 
-    fn read_field(&mut self) -> Result<FieldInstance, BinaryCodecError> {
+    pub(crate) fn read_field(&mut self) -> Result<FieldInstance, BinaryCodecError> {
         let ordinal = self.read_field_ordinal()?;
         self.field_ordinal_lookup
             .get(&ordinal)
@@ -122,7 +216,83 @@ impl Deserializer {
             .ok_or(BinaryCodecError::FieldNotFound("Field not found".into()))
     }
 
-    fn read_field_value(&mut self, info: &FieldInfo) -> Result<Vec<u8>, BinaryCodecError> {
+    /// Reads a field of an object, `None` at the object end marker
+    fn read_object_field(&mut self) -> Result<Option<FieldInstance>, BinaryCodecError> {
+        let field = self.read_field()?;
+        match field.name.as_str() {
+            constants::OBJECT_END_MARKER_NAME => Ok(None),
+            constants::ARRAY_END_MARKER_NAME => Err(BinaryCodecError::InvalidField(
+                "Array end marker in object".into(),
+            )),
+            _ => Ok(Some(field)),
+        }
+    }
+
+    /// Reads the field of an array element, `None` at the array end marker. Array elements are
+    /// objects wrapped in a field, e.g. `Memo` in `Memos`.
+    fn read_array_field(&mut self) -> Result<Option<FieldInstance>, BinaryCodecError> {
+        let field = self.read_field()?;
+        if field.name == constants::ARRAY_END_MARKER_NAME {
+            return Ok(None);
+        }
+        if field.info.field_type != TypeCode::Object
+            || field.name == constants::OBJECT_END_MARKER_NAME
+        {
+            return Err(BinaryCodecError::InvalidField(format!(
+                "Array element {} is not an object",
+                field.name
+            )));
+        }
+        Ok(Some(field))
+    }
+
+    /// In strict mode, checks that the given field read at the given offset follows the previous
+    /// field of the object in canonical order, see <https://xrpl.org/serialization.html#canonical-field-order>
+    fn check_field_order(
+        &self,
+        previous_ordinal: &mut Option<u32>,
+        field: &FieldInstance,
+        offset: usize,
+    ) -> Result<(), BinaryCodecError> {
+        let ordinal = field.info.ordinal();
+        if self.strict {
+            match *previous_ordinal {
+                Some(previous) if previous == ordinal => {
+                    return Err(Self::non_canonical(
+                        offset,
+                        &format!("Duplicate field {}", field.name),
+                    ));
+                }
+                Some(previous) if previous > ordinal => {
+                    return Err(Self::non_canonical(
+                        offset,
+                        &format!("Field {} is not in canonical order", field.name),
+                    ));
+                }
+                _ => {}
+            }
+        }
+        *previous_ordinal = Some(ordinal);
+        Ok(())
+    }
+
+    /// Tracks the nesting of objects and arrays, deeply nested input must not overflow the stack
+    fn enter_nested(&mut self) -> Result<(), BinaryCodecError> {
+        if self.depth >= constants::MAX_NESTING_DEPTH {
+            return Err(BinaryCodecError::OutOfRange(
+                "Maximum nesting depth exceeded".into(),
+            ));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    /// Reads the value of a field with the given info. For variable length encoded fields the
+    /// length prefix is not part of the returned bytes.
+    pub(crate) fn read_field_value(
+        &mut self,
+        info: &FieldInfo,
+    ) -> Result<Vec<u8>, BinaryCodecError> {
         let size_hint: Option<usize> = if info.is_vl_encoded {
             Some(self.read_variable_length()?)
         } else {
@@ -130,23 +300,162 @@ impl Deserializer {
         };
         let bytes = match info.field_type {
             TypeCode::Hash256 => self.deserialize_hash256()?.0.to_vec(),
-            TypeCode::AccountId => self.deserialize_account_id()?.0.to_vec(),
+            TypeCode::AccountId => {
+                if size_hint.is_some_and(|len| len != 20) {
+                    return Err(BinaryCodecError::InvalidLength(
+                        "Account id must be 20 bytes".into(),
+                    ));
+                }
+                self.deserialize_account_id()?.0.to_vec()
+            }
             TypeCode::Blob => {
                 let hint =
                     size_hint.ok_or(BinaryCodecError::FieldNotFound("missing hint".into()))?;
                 self.deserialize_blob(hint)?.0.to_vec()
             }
-            TypeCode::Object => self.deserialize_object()?,
-            TypeCode::Array => self.deserialize_array()?,
-            _ => vec![], // TODO: default other types to Blob for now
+            TypeCode::Object => {
+                self.enter_nested()?;
+                let bytes = self.deserialize_object();
+                self.depth -= 1;
+                bytes?
+            }
+            TypeCode::Array => {
+                self.enter_nested()?;
+                let bytes = self.deserialize_array();
+                self.depth -= 1;
+                bytes?
+            }
+            TypeCode::Hash128 => self.deserialize_hash128()?.0.to_vec(),
+            TypeCode::Hash160 => self.deserialize_hash160()?.0.to_vec(),
+            TypeCode::UInt8 => self.deserialize_uint8()?.to_be_bytes().to_vec(),
+            TypeCode::UInt16 => self.deserialize_uint16()?.to_be_bytes().to_vec(),
+            TypeCode::UInt32 => self.deserialize_uint32()?.to_be_bytes().to_vec(),
+            TypeCode::UInt64 => self.deserialize_uint64()?.to_be_bytes().to_vec(),
+            TypeCode::Amount => {
+                // The "not XRP" bit distinguishes 48 byte issued amounts from 8 byte drops amounts,
+                // see <https://xrpl.org/serialization.html#amount-fields>
+                let first_byte = *self.bytes.first().ok_or_else(|| {
+                    BinaryCodecError::InsufficientBytes("read_field_value".into())
+                })?;
+                let len = if first_byte & 0x80 != 0 { 48 } else { 8 };
+                let offset = self.position();
+                let bytes = self.read(len)?;
+                if self.strict {
+                    check_canonical_amount(&bytes, offset)?;
+                }
+                bytes
+            }
+            TypeCode::PathSet => self.read_path_set()?,
+            TypeCode::Vector256 => {
+                let hint =
+                    size_hint.ok_or(BinaryCodecError::FieldNotFound("missing hint".into()))?;
+                if hint % 32 != 0 {
+                    return Err(BinaryCodecError::InvalidLength(format!(
+                        "Vector256 length {} is not a multiple of 32",
+                        hint
+                    )));
+                }
+                self.read(hint)?
+            }
+            TypeCode::Issue => self.read_issue()?,
+            TypeCode::XChainBridge => {
+                // Account ids are length prefixed, see <https://xrpl.org/serialization.html#xchainbridge-fields>
+                let mut bytes = Vec::new();
+                for _ in 0..2 {
+                    if self.read_variable_length()? != 20 {
+                        return Err(BinaryCodecError::InvalidLength(
+                            "XChainBridge door account id must be 20 bytes".into(),
+                        ));
+                    }
+                    bytes.push(20);
+                    bytes.extend(self.read(20)?);
+                    bytes.extend(self.read_issue()?);
+                }
+                bytes
+            }
+            TypeCode::Number => {
+                let offset = self.position();
+                let bytes = self.read(12)?;
+                if self.strict {
+                    check_canonical_number(&bytes, offset)?;
+                }
+                bytes
+            }
         };
         Ok(bytes)
     }
 
+    /// Reads an issue, the issuer is only present for issued currencies, see
+    /// <https://xrpl.org/serialization.html#issue-fields>
+    fn read_issue(&mut self) -> Result<Vec<u8>, BinaryCodecError> {
+        let offset = self.position();
+        let mut bytes = self.read(20)?;
+        if self.strict && bytes == constants::XRP_STANDARD_CURRENCY_CODE {
+            return Err(Self::non_canonical(offset, "XRP as issued currency code"));
+        }
+        if bytes != [0u8; 20] {
+            bytes.extend(self.read(20)?);
+        }
+        Ok(bytes)
+    }
+
+    /// Reads a path set including the end byte, see <https://xrpl.org/serialization.html#pathset-fields>
+    fn read_path_set(&mut self) -> Result<Vec<u8>, BinaryCodecError> {
+        let mut bytes = Vec::new();
+        let mut path_len = 0;
+        loop {
+            let offset = self.position();
+            let step_type = self.read_u8()?;
+            bytes.push(step_type);
+            if let path_set::END_BYTE | path_set::PATH_SEPARATOR_BYTE = step_type {
+                if self.strict && path_len == 0 {
+                    return Err(Self::non_canonical(offset, "Empty path"));
+                }
+                if step_type == path_set::END_BYTE {
+                    return Ok(bytes);
+                }
+                path_len = 0;
+                continue;
+            }
+            path_len += 1;
+            if step_type & !path_set::STEP_TYPE_MASK != 0 {
+                return Err(BinaryCodecError::InvalidField(format!(
+                    "Invalid path step type: {:#04x}",
+                    step_type
+                )));
+            }
+            if self.strict && step_type & path_set::CURRENCY_STEP_BIT != 0 {
+                let currency_offset = if step_type & path_set::ACCOUNT_STEP_BIT != 0 {
+                    20
+                } else {
+                    0
+                };
+                let currency = self
+                    .bytes
+                    .get(currency_offset..currency_offset + 20)
+                    .unwrap_or_default();
+                if currency == constants::XRP_STANDARD_CURRENCY_CODE {
+                    return Err(Self::non_canonical(
+                        self.position() + currency_offset,
+                        "XRP as issued currency code",
+                    ));
+                }
+            }
+            let len = 20 * (step_type & path_set::STEP_TYPE_MASK).count_ones() as usize;
+            bytes.extend(self.read(len)?);
+        }
+    }
+
     pub fn end(&mut self) -> bool {
         self.bytes.remaining() == 0
     }
 
+    /// Number of bytes not yet read
+    pub fn remaining(&self) -> usize {
+        self.bytes.remaining()
+    }
+
+    /// Converts the given value of a field of the given type to JSON, in the format used by rippled
     #[cfg(feature = "json")]
     pub fn to_json(
         &mut self,
@@ -154,51 +463,412 @@ impl Deserializer {
         data: &[u8],
     ) -> Result<Value, BinaryCodecError> {
         match type_code {
-            TypeCode::Hash256 => Ok(Value::String(hex::encode_upper(data))),
+            TypeCode::Hash128 | TypeCode::Hash160 | TypeCode::Hash256 | TypeCode::Blob => {
+                Ok(Value::String(hex::encode_upper(data)))
+            }
             TypeCode::AccountId => {
                 let account_bytes: [u8; 20] =
                     data.try_into().map_err(|_| BinaryCodecError::Overflow)?;
                 Ok(Value::String(AccountId(account_bytes).to_address()))
             }
-            TypeCode::Blob => Ok(Value::String(hex::encode_upper(data))),
+            TypeCode::UInt8 => Ok(Value::from(value_deserializer(data).deserialize_uint8()?)),
+            TypeCode::UInt16 => Ok(Value::from(value_deserializer(data).deserialize_uint16()?)),
+            TypeCode::UInt32 => Ok(Value::from(value_deserializer(data).deserialize_uint32()?)),
+            // rippled formats 64 bit integers as hex without leading zeros
+            TypeCode::UInt64 => Ok(Value::String(format!(
+                "{:x}",
+                value_deserializer(data).deserialize_uint64()?
+            ))),
+            TypeCode::Amount => Ok(amount_to_json(
+                value_deserializer(data).deserialize_amount()?,
+            )),
             TypeCode::Object => {
-                let mut accumulator: HashMap<String, Value> = HashMap::new();
-                self.bytes = Bytes::from(data.to_vec());
-                while self.bytes.remaining() > 0 {
-                    let field: FieldInstance = self.read_field()?;
-                    if field.name == constants::OBJECT_END_MARKER_NAME {
-                        break;
-                    }
-                    let data_read = self.read_field_value(&field.info)?;
-                    let json_value = self.to_json(&field.info.field_type, &data_read)?;
-                    accumulator.insert(field.name, json_value);
-                }
-                Ok(Value::Object(accumulator.into_iter().collect()))
+                let bytes = core::mem::replace(&mut self.bytes, Bytes::from(data.to_vec()));
+                let len = core::mem::replace(&mut self.len, data.len());
+                let result = self.object_to_json();
+                self.bytes = bytes;
+                self.len = len;
+                result
             }
             TypeCode::Array => {
-                let mut result = Vec::new();
-                self.bytes = Bytes::from(data.to_vec());
-                while self.bytes.remaining() > 0 {
-                    let field = self.read_field()?;
-                    if field.name == constants::ARRAY_END_MARKER_NAME {
-                        break;
-                    }
-                    let data_read = self.read_field_value(&field.info)?;
-                    let json_value = self.to_json(&field.info.field_type, &data_read)?;
+                let bytes = core::mem::replace(&mut self.bytes, Bytes::from(data.to_vec()));
+                let len = core::mem::replace(&mut self.len, data.len());
+                let result = self.array_to_json();
+                self.bytes = bytes;
+                self.len = len;
+                result
+            }
+            TypeCode::PathSet => Ok(path_set_to_json(
+                &value_deserializer(data).deserialize_path_set()?,
+            )),
+            TypeCode::Vector256 => Ok(Value::Array(
+                data.chunks(32)
+                    .map(|hash| Value::String(hex::encode_upper(hash)))
+                    .collect(),
+            )),
+            TypeCode::Issue => Ok(issue_to_json(value_deserializer(data).deserialize_issue()?)),
+            TypeCode::XChainBridge => {
+                let bridge = value_deserializer(data).deserialize_xchain_bridge()?;
+                let mut json = serde_json::Map::new();
+                json.insert(
+                    "IssuingChainDoor".into(),
+                    Value::String(bridge.issuing_chain_door.to_address()),
+                );
+                json.insert(
+                    "IssuingChainIssue".into(),
+                    issue_to_json(bridge.issuing_chain_issue),
+                );
+                json.insert(
+                    "LockingChainDoor".into(),
+                    Value::String(bridge.locking_chain_door.to_address()),
+                );
+                json.insert(
+                    "LockingChainIssue".into(),
+                    issue_to_json(bridge.locking_chain_issue),
+                );
+                Ok(Value::Object(json))
+            }
+            TypeCode::Number => Ok(Value::String(
+                value_deserializer(data).deserialize_number()?.to_string(),
+            )),
+        }
+    }
 
-                    let obj: serde_json::Map<String, Value> =
-                        vec![(field.name.clone(), json_value)].into_iter().collect();
-                    result.push(Value::Object(obj));
-                }
-                Ok(Value::Array(result))
+    /// Reads the fields of an object until the end of the bytes or the object end marker.
+    /// Fields are ordered by name, like in the JSON returned by rippled.
+    #[cfg(feature = "json")]
+    fn object_to_json(&mut self) -> Result<Value, BinaryCodecError> {
+        let mut accumulator: BTreeMap<String, Value> = BTreeMap::new();
+        let mut previous_ordinal = None;
+        while self.bytes.remaining() > 0 {
+            let offset = self.position();
+            let field = self.read_object_field()?.ok_or_else(|| {
+                BinaryCodecError::InvalidField("Unexpected object end marker".into())
+            })?;
+            self.check_field_order(&mut previous_ordinal, &field, offset)?;
+            let data_read = self.read_field_value(&field.info)?;
+            let json_value = self.field_to_json(&field, &data_read)?;
+            accumulator.insert(field.name, json_value);
+        }
+        Ok(Value::Object(accumulator.into_iter().collect()))
+    }
+
+    #[cfg(feature = "json")]
+    fn array_to_json(&mut self) -> Result<Value, BinaryCodecError> {
+        let mut result = Vec::new();
+        while self.bytes.remaining() > 0 {
+            let Some(field) = self.read_array_field()? else {
+                break;
+            };
+            let data_read = self.read_field_value(&field.info)?;
+            let json_value = self.field_to_json(&field, &data_read)?;
+
+            let obj: serde_json::Map<String, Value> =
+                vec![(field.name.clone(), json_value)].into_iter().collect();
+            result.push(Value::Object(obj));
+        }
+        Ok(Value::Array(result))
+    }
+
+    /// Converts the value of the given field to JSON. Enum fields are converted to their names.
+    #[cfg(feature = "json")]
+    fn field_to_json(
+        &mut self,
+        field: &FieldInstance,
+        data: &[u8],
+    ) -> Result<Value, BinaryCodecError> {
+        let name = match field.name.as_str() {
+            "TransactionType" => {
+                let code = value_deserializer(data).deserialize_uint16()?;
+                self.definitions.transaction_type_name(code)
             }
-            _ => Ok(Value::String(hex::encode_upper(data))), // TODO: default other types to Blob for now
+            "LedgerEntryType" => {
+                let code = value_deserializer(data).deserialize_uint16()?;
+                self.definitions.ledger_entry_type_name(code)
+            }
+            "TransactionResult" => {
+                let code = value_deserializer(data).deserialize_uint8()?;
+                self.definitions.transaction_result_name(code)
+            }
+            _ => None,
+        };
+        match name {
+            Some(name) => Ok(Value::String(name.to_string())),
+            None => self.to_json(&field.info.field_type, data),
+        }
+    }
+}
+
+/// A field read from a serialized object
+#[derive(Debug, Clone)]
+struct DeserializedField {
+    name: String,
+    info: FieldInfo,
+    /// Value of the field, without length prefix
+    value: Vec<u8>,
+    /// If the field has been deserialized by [`Deserialize`] implementations
+    read: bool,
+}
+
+/// Deserializes the fields of a serialized object into `xrpl_types` objects, see
+/// [`xrpl_types::deserialize::Deserializer`]. Call [`ObjectDeserializer::end`] after deserializing
+/// to make sure all fields in the object are part of the deserialized object.
+#[derive(Debug, Clone)]
+pub struct ObjectDeserializer<'a> {
+    field_info_map: &'a HashMap<String, FieldInfo>,
+    fields: Vec<DeserializedField>,
+}
+
+impl<'a> ObjectDeserializer<'a> {
+    /// Reads all fields of the given serialized object
+    pub fn new(
+        bytes: &[u8],
+        field_info_map: &'a HashMap<String, FieldInfo>,
+    ) -> Result<Self, BinaryCodecError> {
+        Self::read(
+            Deserializer::new(bytes.to_vec(), field_info_map),
+            field_info_map,
+        )
+    }
+
+    /// Reads all fields of the given serialized object, rejecting non-canonical encodings, see
+    /// [`Deserializer::strict`]
+    pub fn new_strict(
+        bytes: &[u8],
+        field_info_map: &'a HashMap<String, FieldInfo>,
+    ) -> Result<Self, BinaryCodecError> {
+        Self::read(
+            Deserializer::new(bytes.to_vec(), field_info_map).strict(true),
+            field_info_map,
+        )
+    }
+
+    fn read(
+        mut deserializer: Deserializer<'_>,
+        field_info_map: &'a HashMap<String, FieldInfo>,
+    ) -> Result<Self, BinaryCodecError> {
+        let mut fields = Vec::new();
+        let mut previous_ordinal = None;
+        while !deserializer.end() {
+            let offset = deserializer.position();
+            let field = deserializer.read_object_field()?.ok_or_else(|| {
+                BinaryCodecError::InvalidField("Unexpected object end marker".into())
+            })?;
+            deserializer.check_field_order(&mut previous_ordinal, &field, offset)?;
+            let value = deserializer.read_field_value(&field.info)?;
+            fields.push(DeserializedField {
+                name: field.name,
+                info: field.info,
+                value,
+                read: false,
+            });
         }
+        Ok(Self {
+            field_info_map,
+            fields,
+        })
+    }
+
+    /// Checks that all fields in the object have been deserialized
+    pub fn end(self) -> Result<(), BinaryCodecError> {
+        match self.fields.iter().find(|field| !field.read) {
+            Some(field) => Err(BinaryCodecError::InvalidField(format!(
+                "Field {} is not part of the deserialized object",
+                field.name
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Value of the field with given name, checking that the field has the given type
+    fn field_value(
+        &mut self,
+        field_name: &str,
+        field_type: TypeCode,
+    ) -> Result<Option<&[u8]>, BinaryCodecError> {
+        let Some(field) = self
+            .fields
+            .iter_mut()
+            .find(|field| field.name == field_name)
+        else {
+            return Ok(None);
+        };
+        if field.info.field_type != field_type {
+            return Err(BinaryCodecError::InvalidField(format!(
+                "Field with name {} must have type {}",
+                field_name, field.info.field_type
+            )));
+        }
+        field.read = true;
+        Ok(Some(&field.value))
+    }
+
+    /// Deserializes the value of the field with given name and type with the given closure
+    fn deserialize_field<T>(
+        &mut self,
+        field_name: &str,
+        field_type: TypeCode,
+        deserialize_value_closure: impl FnOnce(&mut Deserializer) -> Result<T, BinaryCodecError>,
+    ) -> Result<Option<T>, BinaryCodecError> {
+        let Some(value) = self.field_value(field_name, field_type)? else {
+            return Ok(None);
+        };
+        let mut deserializer = value_deserializer(value);
+        let value = deserialize_value_closure(&mut deserializer)?;
+        if !deserializer.end() {
+            return Err(BinaryCodecError::InvalidLength(format!(
+                "Trailing bytes in field {}",
+                field_name
+            )));
+        }
+        Ok(Some(value))
+    }
+}
+
+impl xrpl_types::deserialize::Deserializer for ObjectDeserializer<'_> {
+    type Error = BinaryCodecError;
+
+    fn deserialize_account_id(
+        &mut self,
+        field_name: &str,
+    ) -> Result<Option<AccountId>, BinaryCodecError> {
+        self.deserialize_field(field_name, TypeCode::AccountId, |de| {
+            de.deserialize_account_id()
+        })
+    }
+
+    fn deserialize_amount(&mut self, field_name: &str) -> Result<Option<Amount>, BinaryCodecError> {
+        self.deserialize_field(field_name, TypeCode::Amount, |de| de.deserialize_amount())
+    }
+
+    fn deserialize_blob(&mut self, field_name: &str) -> Result<Option<Blob>, BinaryCodecError> {
+        Ok(self
+            .field_value(field_name, TypeCode::Blob)?
+            .map(|value| Blob(value.to_vec())))
+    }
+
+    fn deserialize_hash128(
+        &mut self,
+        field_name: &str,
+    ) -> Result<Option<Hash128>, BinaryCodecError> {
+        self.deserialize_field(field_name, TypeCode::Hash128, |de| de.deserialize_hash128())
+    }
+
+    fn deserialize_hash160(
+        &mut self,
+        field_name: &str,
+    ) -> Result<Option<Hash160>, BinaryCodecError> {
+        self.deserialize_field(field_name, TypeCode::Hash160, |de| de.deserialize_hash160())
+    }
+
+    fn deserialize_hash256(
+        &mut self,
+        field_name: &str,
+    ) -> Result<Option<Hash256>, BinaryCodecError> {
+        self.deserialize_field(field_name, TypeCode::Hash256, |de| de.deserialize_hash256())
+    }
+
+    fn deserialize_uint8(&mut self, field_name: &str) -> Result<Option<UInt8>, BinaryCodecError> {
+        self.deserialize_field(field_name, TypeCode::UInt8, |de| de.deserialize_uint8())
+    }
+
+    fn deserialize_uint16(&mut self, field_name: &str) -> Result<Option<UInt16>, BinaryCodecError> {
+        self.deserialize_field(field_name, TypeCode::UInt16, |de| de.deserialize_uint16())
+    }
+
+    fn deserialize_uint32(&mut self, field_name: &str) -> Result<Option<UInt32>, BinaryCodecError> {
+        self.deserialize_field(field_name, TypeCode::UInt32, |de| de.deserialize_uint32())
+    }
+
+    fn deserialize_uint64(&mut self, field_name: &str) -> Result<Option<Uint64>, BinaryCodecError> {
+        self.deserialize_field(field_name, TypeCode::UInt64, |de| de.deserialize_uint64())
+    }
+
+    fn deserialize_array<T: Deserialize>(
+        &mut self,
+        field_name: &str,
+        object_field_name: &str,
+    ) -> Result<Vec<T>, BinaryCodecError> {
+        let field_info_map = self.field_info_map;
+        let Some(value) = self.field_value(field_name, TypeCode::Array)? else {
+            return Ok(Vec::new());
+        };
+        let mut deserializer = Deserializer::new(value.to_vec(), field_info_map);
+        let mut objects = Vec::new();
+        while !deserializer.end() {
+            let field = deserializer.read_field()?;
+            if field.name == constants::ARRAY_END_MARKER_NAME {
+                break;
+            }
+            if field.name != object_field_name {
+                return Err(BinaryCodecError::InvalidField(format!(
+                    "Array {} must contain {} objects, found {}",
+                    field_name, object_field_name, field.name
+                )));
+            }
+            let value = deserializer.read_field_value(&field.info)?;
+            let mut object_deserializer = ObjectDeserializer::new(&value, field_info_map)?;
+            objects.push(T::deserialize(&mut object_deserializer)?);
+            object_deserializer.end()?;
+        }
+        Ok(objects)
+    }
+
+    fn deserialize_object<T: Deserialize>(
+        &mut self,
+        field_name: &str,
+    ) -> Result<Option<T>, BinaryCodecError> {
+        let field_info_map = self.field_info_map;
+        let Some(value) = self.field_value(field_name, TypeCode::Object)? else {
+            return Ok(None);
+        };
+        let mut object_deserializer = ObjectDeserializer::new(value, field_info_map)?;
+        let object = T::deserialize(&mut object_deserializer)?;
+        object_deserializer.end()?;
+        Ok(Some(object))
+    }
+
+    fn deserialize_path_set(
+        &mut self,
+        field_name: &str,
+    ) -> Result<Option<Vec<Path>>, BinaryCodecError> {
+        self.deserialize_field(field_name, TypeCode::PathSet, |de| {
+            de.deserialize_path_set()
+        })
+    }
+
+    fn deserialize_vector256(
+        &mut self,
+        field_name: &str,
+    ) -> Result<Option<Vec<Hash256>>, BinaryCodecError> {
+        self.deserialize_field(field_name, TypeCode::Vector256, |de| {
+            de.deserialize_vector256()
+        })
+    }
+
+    fn deserialize_issue(
+        &mut self,
+        field_name: &str,
+    ) -> Result<Option<Currency>, BinaryCodecError> {
+        self.deserialize_field(field_name, TypeCode::Issue, |de| de.deserialize_issue())
+    }
+
+    fn deserialize_xchain_bridge(
+        &mut self,
+        field_name: &str,
+    ) -> Result<Option<XChainBridge>, BinaryCodecError> {
+        self.deserialize_field(field_name, TypeCode::XChainBridge, |de| {
+            de.deserialize_xchain_bridge()
+        })
+    }
+
+    fn deserialize_number(&mut self, field_name: &str) -> Result<Option<Number>, BinaryCodecError> {
+        self.deserialize_field(field_name, TypeCode::Number, |de| de.deserialize_number())
     }
 }
 
 #[allow(dead_code)]
-impl Deserializer {
+impl Deserializer<'_> {
     fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), BinaryCodecError> {
         if self.bytes.remaining() < buf.len() {
             return Err(BinaryCodecError::InsufficientBytes("read_exact".into()));
@@ -213,8 +883,145 @@ impl Deserializer {
         Ok(AccountId(bytes))
     }
 
+    /// <https://xrpl.org/serialization.html#amount-fields>
     fn deserialize_amount(&mut self) -> Result<Amount, BinaryCodecError> {
-        unimplemented!()
+        const ISSUED_MASK: u64 = 0x8000000000000000;
+        const POSITIVE_MASK: u64 = 0x4000000000000000;
+        const MANTISSA_MASK: u64 = 0x003FFFFFFFFFFFFF;
+
+        let value = self.deserialize_uint64()?;
+        if value & ISSUED_MASK == 0 {
+            if value & POSITIVE_MASK == 0 {
+                return Err(BinaryCodecError::OutOfRange("Negative drops amount".into()));
+            }
+            return Ok(Amount::Drops(DropsAmount::from_drops(
+                value & !POSITIVE_MASK,
+            )?));
+        }
+
+        let issued_value = if value & !ISSUED_MASK == 0 {
+            IssuedValue::zero()
+        } else {
+            let mantissa = (value & MANTISSA_MASK) as i64;
+            let exponent = i8::try_from(((value >> 54) & 0xFF) as i16 - 97).map_err(|_| {
+                BinaryCodecError::OutOfRange(format!("Issued value exponent: {:#x}", value))
+            })?;
+            let mantissa = if value & POSITIVE_MASK == 0 {
+                -mantissa
+            } else {
+                mantissa
+            };
+            IssuedValue::from_mantissa_exponent(mantissa, exponent)?
+        };
+        let currency = self.deserialize_currency_code()?;
+        let issuer = self.deserialize_account_id()?;
+        Ok(Amount::issued(issued_value, currency, issuer)?)
+    }
+
+    /// <https://xrpl.org/serialization.html#currency-codes>
+    fn deserialize_currency_code(&mut self) -> Result<CurrencyCode, BinaryCodecError> {
+        let mut bytes = [0u8; 20];
+        self.read_exact(&mut bytes)?;
+        if bytes == [0u8; 20] {
+            return Ok(CurrencyCode::Xrp);
+        }
+        if bytes[0] != 0x00 {
+            return Ok(CurrencyCode::non_standard(bytes)?);
+        }
+        if bytes[..12] != [0u8; 12] || bytes[15..] != [0u8; 5] {
+            return Err(BinaryCodecError::InvalidField(format!(
+                "Invalid currency code: {}",
+                hex::encode_upper(bytes)
+            )));
+        }
+        let code = core::str::from_utf8(&bytes[12..15]).map_err(|_| {
+            BinaryCodecError::InvalidField(format!(
+                "Invalid standard currency code: {}",
+                hex::encode_upper(bytes)
+            ))
+        })?;
+        Ok(CurrencyCode::from_str(code)?)
+    }
+
+    /// <https://xrpl.org/serialization.html#issue-fields>
+    fn deserialize_issue(&mut self) -> Result<Currency, BinaryCodecError> {
+        let currency_code = self.deserialize_currency_code()?;
+        if currency_code.is_xrp() {
+            return Ok(Currency::Xrp);
+        }
+        Ok(Currency::issued(
+            currency_code,
+            self.deserialize_account_id()?,
+        )?)
+    }
+
+    /// Reads a path set including the end byte <https://xrpl.org/serialization.html#pathset-fields>
+    fn deserialize_path_set(&mut self) -> Result<Vec<Path>, BinaryCodecError> {
+        let mut path_set = Vec::new();
+        let mut path = Vec::new();
+        loop {
+            let step_type = self.read_u8()?;
+            match step_type {
+                path_set::END_BYTE | path_set::PATH_SEPARATOR_BYTE => {
+                    path_set.push(Path(core::mem::take(&mut path)));
+                    if step_type == path_set::END_BYTE {
+                        return Ok(path_set);
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+            let account = (step_type & path_set::ACCOUNT_STEP_BIT != 0)
+                .then(|| self.deserialize_account_id())
+                .transpose()?;
+            let currency = (step_type & path_set::CURRENCY_STEP_BIT != 0)
+                .then(|| self.deserialize_currency_code())
+                .transpose()?;
+            let issuer = (step_type & path_set::ISSUER_STEP_BIT != 0)
+                .then(|| self.deserialize_account_id())
+                .transpose()?;
+            path.push(PathStep::new(account, currency, issuer)?);
+        }
+    }
+
+    /// Reads the remaining bytes as 256 bit hashes <https://xrpl.org/serialization.html#vector256-fields>
+    fn deserialize_vector256(&mut self) -> Result<Vec<Hash256>, BinaryCodecError> {
+        let mut hashes = Vec::with_capacity(self.remaining() / 32);
+        while !self.end() {
+            hashes.push(self.deserialize_hash256()?);
+        }
+        Ok(hashes)
+    }
+
+    /// <https://xrpl.org/serialization.html#xchainbridge-fields>
+    fn deserialize_xchain_bridge(&mut self) -> Result<XChainBridge, BinaryCodecError> {
+        let locking_chain_door = self.deserialize_door_account_id()?;
+        let locking_chain_issue = self.deserialize_issue()?;
+        let issuing_chain_door = self.deserialize_door_account_id()?;
+        let issuing_chain_issue = self.deserialize_issue()?;
+        Ok(XChainBridge {
+            locking_chain_door,
+            locking_chain_issue,
+            issuing_chain_door,
+            issuing_chain_issue,
+        })
+    }
+
+    /// Length prefixed account id of a bridge door
+    fn deserialize_door_account_id(&mut self) -> Result<AccountId, BinaryCodecError> {
+        if self.read_variable_length()? != 20 {
+            return Err(BinaryCodecError::InvalidLength(
+                "XChainBridge door account id must be 20 bytes".into(),
+            ));
+        }
+        self.deserialize_account_id()
+    }
+
+    /// Signed 64 bit mantissa followed by signed 32 bit exponent
+    fn deserialize_number(&mut self) -> Result<Number, BinaryCodecError> {
+        let mantissa = self.deserialize_uint64()? as i64;
+        let exponent = self.deserialize_uint32()? as i32;
+        Ok(Number::from_mantissa_exponent(mantissa, exponent)?)
     }
 
     fn deserialize_blob(&mut self, len: usize) -> Result<Blob, BinaryCodecError> {
@@ -265,13 +1072,18 @@ impl Deserializer {
         Ok(Uint64::from_be_bytes(bytes))
     }
 
+    /// Reads an array including the end marker
     fn deserialize_array(&mut self) -> Result<Vec<u8>, BinaryCodecError> {
         let mut bytes = Vec::new();
-        while !self.end() {
-            let field = self.read_field()?;
-            if field.name == constants::ARRAY_END_MARKER_NAME {
-                break;
+        loop {
+            if self.end() {
+                return Err(BinaryCodecError::InsufficientBytes(
+                    "Missing array end marker".into(),
+                ));
             }
+            let Some(field) = self.read_array_field()? else {
+                break;
+            };
             let header: Vec<u8> = FieldId::from(field.info.clone()).into();
             bytes.extend_from_slice(&header);
             let data = self.read_field_value(&field.info)?;
@@ -282,13 +1094,25 @@ impl Deserializer {
         Ok(bytes)
     }
 
+    /// Reads the fields of an object until the end marker, which is not part of the returned
+    /// bytes. Only nested objects must have an end marker.
     fn deserialize_object(&mut self) -> Result<Vec<u8>, BinaryCodecError> {
         let mut sink: Vec<Vec<u8>> = Vec::new();
-        while !self.end() {
-            let field = self.read_field()?;
-            if field.name == constants::OBJECT_END_MARKER_NAME {
+        let mut previous_ordinal = None;
+        loop {
+            if self.end() {
+                if self.depth > 0 {
+                    return Err(BinaryCodecError::InsufficientBytes(
+                        "Missing object end marker".into(),
+                    ));
+                }
                 break;
             }
+            let offset = self.position();
+            let Some(field) = self.read_object_field()? else {
+                break;
+            };
+            self.check_field_order(&mut previous_ordinal, &field, offset)?;
             let data = self.read_field_value(&field.info)?;
             sink.push(FieldId::from(field.info.clone()).into()); // push header
             if field.info.is_vl_encoded {
@@ -296,7 +1120,7 @@ impl Deserializer {
                 sink.push(vl);
             }
             sink.push(data);
-            if field.name == constants::OBJECT_NAME {
+            if field.info.field_type == TypeCode::Object {
                 sink.push(constants::OBJECT_END_MARKER_BYTE.to_vec());
             }
         }
@@ -305,7 +1129,149 @@ impl Deserializer {
     }
 }
 
-fn encode_variable_length(length: usize) -> Result<Vec<u8>, BinaryCodecError> {
+/// Checks that the given serialized amount read at the given offset is canonical, see
+/// <https://xrpl.org/serialization.html#amount-fields>
+fn check_canonical_amount(bytes: &[u8], offset: usize) -> Result<(), BinaryCodecError> {
+    const ISSUED_MASK: u64 = 0x8000000000000000;
+    const POSITIVE_MASK: u64 = 0x4000000000000000;
+    const MANTISSA_MASK: u64 = 0x003FFFFFFFFFFFFF;
+    const MANTISSA_MIN: u64 = 1000000000000000;
+    const MANTISSA_MAX: u64 = 9999999999999999;
+    const EXPONENT_MIN: i16 = -96;
+    const EXPONENT_MAX: i16 = 80;
+    const DROPS_MAX: u64 = 100_000_000_000_000_000;
+
+    let value = value_deserializer(bytes).deserialize_uint64()?;
+    if value & ISSUED_MASK == 0 {
+        if value & !POSITIVE_MASK > DROPS_MAX {
+            return Err(Deserializer::non_canonical(
+                offset,
+                "Drops amount exceeds the total XRP supply",
+            ));
+        }
+        return Ok(());
+    }
+    // Zero has a single encoding, without sign
+    if value != ISSUED_MASK {
+        let mantissa = value & MANTISSA_MASK;
+        let exponent = ((value >> 54) & 0xFF) as i16 - 97;
+        if !(MANTISSA_MIN..=MANTISSA_MAX).contains(&mantissa)
+            || !(EXPONENT_MIN..=EXPONENT_MAX).contains(&exponent)
+        {
+            return Err(Deserializer::non_canonical(
+                offset,
+                "Issued value is not normalized",
+            ));
+        }
+    }
+    let currency = &bytes[8..28];
+    if currency == [0u8; 20] || currency == constants::XRP_STANDARD_CURRENCY_CODE {
+        return Err(Deserializer::non_canonical(
+            offset + 8,
+            "XRP as issued currency code",
+        ));
+    }
+    Ok(())
+}
+
+/// Checks that the given serialized number read at the given offset is normalized like rippled
+/// does, with zero represented by the lowest exponent
+fn check_canonical_number(bytes: &[u8], offset: usize) -> Result<(), BinaryCodecError> {
+    let mut deserializer = value_deserializer(bytes);
+    let mantissa = deserializer.deserialize_uint64()? as i64;
+    let exponent = deserializer.deserialize_uint32()? as i32;
+    let number = Number::from_mantissa_exponent(mantissa, exponent)?;
+    if number.mantissa() != mantissa || number.exponent() != exponent {
+        return Err(Deserializer::non_canonical(
+            offset,
+            "Number is not normalized",
+        ));
+    }
+    Ok(())
+}
+
+/// Deserializer of the value of a single field
+fn value_deserializer(data: &[u8]) -> Deserializer<'static> {
+    Deserializer {
+        bytes: Bytes::from(data.to_vec()),
+        len: data.len(),
+        ..Deserializer::default()
+    }
+}
+
+#[cfg(feature = "json")]
+fn amount_to_json(amount: Amount) -> Value {
+    match amount {
+        Amount::Drops(drops) => Value::String(drops.drops().to_string()),
+        Amount::Issued(issued) => {
+            let mut amount = serde_json::Map::new();
+            amount.insert(
+                "currency".into(),
+                Value::String(issued.currency().to_string()),
+            );
+            amount.insert("issuer".into(), Value::String(issued.issuer().to_address()));
+            amount.insert("value".into(), Value::String(issued.value().to_string()));
+            Value::Object(amount)
+        }
+    }
+}
+
+/// Converts path set to JSON, including the `type` and `type_hex` of each step like rippled
+#[cfg(feature = "json")]
+fn path_set_to_json(path_set: &[Path]) -> Value {
+    let mut paths = Vec::with_capacity(path_set.len());
+    for path in path_set {
+        let mut steps = Vec::with_capacity(path.0.len());
+        for step in &path.0 {
+            let mut json = serde_json::Map::new();
+            let mut step_type = 0;
+            if let Some(account) = step.account_id() {
+                step_type |= path_set::ACCOUNT_STEP_BIT;
+                json.insert("account".into(), Value::String(account.to_address()));
+            }
+            if let Some(currency) = step.currency_code() {
+                step_type |= path_set::CURRENCY_STEP_BIT;
+                json.insert("currency".into(), Value::String(currency.to_string()));
+            }
+            if let Some(issuer) = step.issuer() {
+                step_type |= path_set::ISSUER_STEP_BIT;
+                json.insert("issuer".into(), Value::String(issuer.to_address()));
+            }
+            json.insert("type".into(), Value::from(step_type));
+            json.insert(
+                "type_hex".into(),
+                Value::String(format!("{:016X}", step_type)),
+            );
+            steps.push(Value::Object(json));
+        }
+        paths.push(Value::Array(steps));
+    }
+    Value::Array(paths)
+}
+
+/// Converts issue to JSON, with `currency` and for issued currencies `issuer`
+#[cfg(feature = "json")]
+fn issue_to_json(issue: Currency) -> Value {
+    let mut json = serde_json::Map::new();
+    match issue {
+        Currency::Xrp => {
+            json.insert(
+                "currency".into(),
+                Value::String(CurrencyCode::Xrp.to_string()),
+            );
+        }
+        Currency::Issued(issued) => {
+            json.insert(
+                "currency".into(),
+                Value::String(issued.currency_code().to_string()),
+            );
+            json.insert("issuer".into(), Value::String(issued.issuer().to_address()));
+        }
+    }
+    Value::Object(json)
+}
+
+pub(crate) fn encode_variable_length(length: usize) -> Result<Vec<u8>, BinaryCodecError> {
     let mut len_bytes = [0u8; 3];
     if length <= 192 {
         len_bytes[0] = length as u8;
@@ -329,7 +1295,9 @@ fn encode_variable_length(length: usize) -> Result<Vec<u8>, BinaryCodecError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::alloc::string::ToString;
     use crate::serializer::field_info::field_info_lookup;
+    use assert_matches::assert_matches;
 
     #[test]
     fn test_decode_account_txn_id() {
@@ -411,6 +1379,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decode_amount() {
+        // Drops amount followed by an issued amount of -1.5 USD
+        let encoded_amounts = "400000000000000A9485543DF729C0000000000000000000000000005553440000000000B5F762798A53D543A014CAF8B297CFF8F2F937E8";
+
+        let deserializer =
+            &mut Deserializer::new(hex::decode(encoded_amounts).unwrap(), field_info_lookup());
+        let amount = deserializer.deserialize_amount().unwrap();
+        assert_eq!(amount, Amount::drops(10).unwrap());
+        let amount = deserializer.deserialize_amount().unwrap();
+        assert_matches!(amount, Amount::Issued(issued) => {
+            assert_eq!(issued.value(), IssuedValue::from_mantissa_exponent(-15, -1).unwrap());
+            assert_eq!(issued.currency().to_string(), "USD");
+            assert_eq!(issued.issuer().to_address(), "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+        });
+        assert!(deserializer.end());
+    }
+
+    #[test]
+    fn test_decode_negative_drops_amount() {
+        let deserializer = &mut Deserializer::new(
+            hex::decode("000000000000000A").unwrap(),
+            field_info_lookup(),
+        );
+        let result = deserializer.deserialize_amount();
+        assert_matches!(result, Err(BinaryCodecError::OutOfRange(_)));
+    }
+
     #[test]
     fn test_decode_txn_signature() {
         // TxnSignature encoded
@@ -454,15 +1450,58 @@ mod tests {
 
     #[test]
     fn test_decode_memos_array() {
+        // The array end marker is only valid in arrays, like in rippled
         let encoded_tx_memos_arr = "7C1F687474703A2F2F6578616D706C652E636F6D2F6D656D6F2F67656E657269637D0472656E74F1E1F1F1E1F1";
 
         let deserializer = &mut Deserializer::new(
             hex::decode(encoded_tx_memos_arr).unwrap(),
             field_info_lookup(),
         );
-        let data = deserializer.deserialize_object().unwrap();
-        let hex_val = hex::encode_upper(data);
-        assert_eq!(encoded_tx_memos_arr, hex_val);
+        assert_matches!(
+            deserializer.deserialize_object(),
+            Err(BinaryCodecError::InvalidField(_))
+        );
+    }
+
+    #[test]
+    fn test_decode_misplaced_end_markers() {
+        for encoded in [
+            // Object end marker in array
+            "F9E1F1",
+            // Non-object in array
+            "F9240000000AF1",
+            // Missing array end marker
+            "F9EA7D0100E1",
+            // Missing object end marker
+            "EA7D0100",
+        ] {
+            let deserializer =
+                &mut Deserializer::new(hex::decode(encoded).unwrap(), field_info_lookup());
+            assert!(deserializer.deserialize_object().is_err(), "{}", encoded);
+        }
+    }
+
+    #[test]
+    fn test_decode_nesting_depth() {
+        // Memos array containing a Memo object containing a Memos array, and so on
+        let nested = |depth: usize| {
+            let mut bytes = Vec::new();
+            for _ in 0..depth {
+                bytes.extend([0xF9, 0xEA]);
+            }
+            for _ in 0..depth {
+                bytes.extend([0xE1, 0xF1]);
+            }
+            bytes
+        };
+        let deserializer = &mut Deserializer::new(nested(5), field_info_lookup());
+        assert!(deserializer.deserialize_object().is_ok());
+
+        let deserializer = &mut Deserializer::new(nested(100_000), field_info_lookup());
+        assert_matches!(
+            deserializer.deserialize_object(),
+            Err(BinaryCodecError::OutOfRange(_))
+        );
     }
 
     #[cfg(feature = "json")]
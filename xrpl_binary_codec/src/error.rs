@@ -12,6 +12,14 @@ pub enum BinaryCodecError {
     InvalidLength(String),
     FieldNotFound(String),
     InsufficientBytes(String),
+    InvalidKey(String),
+    InvalidSignature(String),
+    InvalidProof(String),
+    /// Non-canonical encoding rejected in strict mode, at the given offset of the serialized bytes
+    NonCanonical {
+        offset: usize,
+        message: String,
+    },
     Overflow,
 }
 
@@ -31,7 +39,22 @@ impl fmt::Display for BinaryCodecError {
             Self::InvalidLength(s) => write!(f, "Invalid length: {}", s),
             Self::FieldNotFound(s) => write!(f, "Field not found: {}", s),
             Self::InsufficientBytes(s) => write!(f, "Insufficient bytes to decode: {}", s),
+            Self::InvalidKey(s) => write!(f, "Invalid key: {}", s),
+            Self::InvalidSignature(s) => write!(f, "Invalid signature: {}", s),
+            Self::InvalidProof(s) => write!(f, "Invalid proof: {}", s),
+            Self::NonCanonical { offset, message } => {
+                write!(f, "Non-canonical encoding at byte {}: {}", offset, message)
+            }
             Self::Overflow => write!(f, "Bytes overflow"),
         }
     }
 }
+
+impl From<xrpl_types::Error> for BinaryCodecError {
+    fn from(err: xrpl_types::Error) -> Self {
+        match err {
+            xrpl_types::Error::InvalidData(s) => Self::InvalidField(s),
+            xrpl_types::Error::OutOfRange(s) => Self::OutOfRange(s),
+        }
+    }
+}
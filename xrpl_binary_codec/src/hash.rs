@@ -1,13 +1,33 @@
+use crate::alloc::vec::Vec;
+use crate::{serialize, BinaryCodecError};
 use sha2::Digest;
 use sha2::Sha512;
-use xrpl_types::Hash256;
+use xrpl_types::{Hash256, LedgerHeader, Transaction};
 
 /// Unsigned single signer transactions prefix <https://xrpl.org/basic-data-types.html#hash-prefixes>
 pub const HASH_PREFIX_UNSIGNED_TRANSACTION_SINGLE: [u8; 4] = [0x53, 0x54, 0x58, 0x00];
 
+/// Multi-signed transactions prefix <https://xrpl.org/basic-data-types.html#hash-prefixes>
+pub const HASH_PREFIX_UNSIGNED_TRANSACTION_MULTI: [u8; 4] = [0x53, 0x4D, 0x54, 0x00];
+
 /// Signed transactions prefix <https://xrpl.org/basic-data-types.html#hash-prefixes>
 pub const HASH_PREFIX_SIGNED_TRANSACTION: [u8; 4] = [0x54, 0x58, 0x4E, 0x00];
 
+/// Ledger header prefix <https://xrpl.org/basic-data-types.html#hash-prefixes>
+pub const HASH_PREFIX_LEDGER_MASTER: [u8; 4] = [0x4C, 0x57, 0x52, 0x00];
+
+/// SHAMap inner node prefix <https://xrpl.org/basic-data-types.html#hash-prefixes>
+pub const HASH_PREFIX_INNER_NODE: [u8; 4] = [0x4D, 0x49, 0x4E, 0x00];
+
+/// SHAMap transaction with metadata leaf node prefix <https://xrpl.org/basic-data-types.html#hash-prefixes>
+pub const HASH_PREFIX_TRANSACTION_NODE: [u8; 4] = [0x53, 0x4E, 0x44, 0x00];
+
+/// SHAMap account state leaf node prefix <https://xrpl.org/basic-data-types.html#hash-prefixes>
+pub const HASH_PREFIX_LEAF_NODE: [u8; 4] = [0x4D, 0x4C, 0x4E, 0x00];
+
+/// Payment channel claim prefix <https://xrpl.org/basic-data-types.html#hash-prefixes>
+pub const HASH_PREFIX_PAYMENT_CHANNEL_CLAIM: [u8; 4] = [0x43, 0x4C, 0x4D, 0x00];
+
 /// Calculate hash <https://xrpl.org/basic-data-types.html#hashes> of given data
 pub fn hash(prefix: [u8; 4], data: &[u8]) -> Hash256 {
     // INSIGHT: Sha512Trunc245 does not give same result as Sha512[0..32]
@@ -16,3 +36,99 @@ pub fn hash(prefix: [u8; 4], data: &[u8]) -> Hash256 {
     let hash: [u8; 64] = hasher.finalize().into();
     Hash256(hash[0..32].try_into().expect("length 64"))
 }
+
+/// Calculate SHA-512Half of given data, without a prefix
+pub fn sha512_half(data: &[u8]) -> Hash256 {
+    let hash: [u8; 64] = Sha512::digest(data).into();
+    Hash256(hash[0..32].try_into().expect("length 64"))
+}
+
+/// Calculate the hash (transaction id) of given signed transaction <https://xrpl.org/basic-data-types.html#hashes>
+pub fn transaction_hash<T: Transaction>(transaction: &T) -> Result<Hash256, BinaryCodecError> {
+    let blob = serialize::serialize(transaction)?;
+    Ok(transaction_blob_hash(&blob))
+}
+
+/// Calculate the hash (transaction id) of given serialized signed transaction <https://xrpl.org/basic-data-types.html#hashes>
+pub fn transaction_blob_hash(blob: &[u8]) -> Hash256 {
+    hash(HASH_PREFIX_SIGNED_TRANSACTION, blob)
+}
+
+/// Calculate the ledger hash of given ledger header <https://xrpl.org/ledger-header.html#ledger-index-or-ledger-hash>
+pub fn ledger_hash(header: &LedgerHeader) -> Hash256 {
+    let mut data = Vec::with_capacity(118);
+    data.extend_from_slice(&header.ledger_index.to_be_bytes());
+    data.extend_from_slice(&header.total_coins.to_be_bytes());
+    data.extend_from_slice(&header.parent_hash.0);
+    data.extend_from_slice(&header.transaction_hash.0);
+    data.extend_from_slice(&header.account_hash.0);
+    data.extend_from_slice(&header.parent_close_time.to_be_bytes());
+    data.extend_from_slice(&header.close_time.to_be_bytes());
+    data.push(header.close_time_resolution);
+    data.push(header.close_flags);
+    hash(HASH_PREFIX_LEDGER_MASTER, &data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deserialize;
+    use xrpl_types::{OfferCreateTransaction, TrustSetTransaction};
+
+    #[test]
+    fn test_transaction_blob_hash() {
+        // See <https://xrpl.org/sign.html>
+        let blob = hex::decode("1200002280000000240000000361D4838D7EA4C6800000000000000000000000000055534400000000004B4E9C06F24296074F7BC48F92A97916C6DC5EA968400000000000000A732103AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB74473045022100D184EB4AE5956FF600E7536EE459345C7BBCF097A84CC61A93B9AF7197EDB98702201CEA8009B7BEEBAA2AACC0359B41C427C1C5B550A4CA4B80CF2174AF2D6D5DCE81144B4E9C06F24296074F7BC48F92A97916C6DC5EA983143E9D4A2B8AA0780F682D136F7A56D6724EF53754").unwrap();
+        assert_eq!(
+            transaction_blob_hash(&blob).to_hex(),
+            "82230B9D489370504B39BC2CE46216176CAC9E752E5C1774A8CBEC9FBB819208"
+        );
+    }
+
+    #[test]
+    fn test_ledger_hash() {
+        let header = LedgerHeader {
+            ledger_index: 15202439,
+            total_coins: 99998831688050493,
+            parent_hash: Hash256::from_hex(
+                "12724A65B030C15A1573AA28B1BBB5DF3DA4589AA3623675A31CAE69B23B1C4E",
+            )
+            .unwrap(),
+            transaction_hash: Hash256::from_hex(
+                "325EACC5271322539EEEC2D6A5292471EF1B3E72AE7180533EFC3B8F0AD435C8",
+            )
+            .unwrap(),
+            account_hash: Hash256::from_hex(
+                "D9ABF622DA26EEEE48203085D4BC23B0F77DC6F8724AC33D975DA3CA492D2E44",
+            )
+            .unwrap(),
+            parent_close_time: 492656460,
+            close_time: 492656470,
+            close_time_resolution: 10,
+            close_flags: 0,
+        };
+        assert_eq!(
+            ledger_hash(&header).to_hex(),
+            "F4D865D83EB88C1A1911B9E90641919A1314F36E1B099F8E95FE3B7C77BE3349"
+        );
+    }
+
+    #[test]
+    fn test_transaction_hash() {
+        // Multi-signed `TrustSet` of <https://xrpl.org/submit_multisigned.html>
+        let blob = hex::decode("1200142200040000240000000263D5038D7EA4C680000000000000000000000000005553440000000000B5F762798A53D543A014CAF8B297CFF8F2F937E868400000000000753073008114A3780F5CB5A44D366520FC44055E8ED44D9A2270F3E010732102B3EC4E5DD96029A647CFA20DA07FE1F85296505552CCAC114087E66B46BD77DF744730450221009C195DBBF7967E223D8626CA19CF02073667F2B22E206727BFE848FF42BEAC8A022048C323B0BED19A988BDBEFA974B6DE8AA9DCAE250AA82BBD1221787032A864E58114204288D2E47F8EF6C99BCC457966320D12409711E1E0107321028FFB276505F9AC3F57E8D5242B386A597EF6C40A7999F37F1948636FD484E25B744630440220680BBD745004E9CFB6B13A137F505FB92298AD309071D16C7B982825188FD1AE022004200B1F7E4A6A84BB0E4FC09E1E3BA2B66EBD32F0E6D121A34BA3B04AD99BC181147908A7F0EDD48EA896C3580A399F0EE78611C8E3E1F1").unwrap();
+        let tx: TrustSetTransaction = deserialize::deserialize(&blob).unwrap();
+        assert_eq!(
+            transaction_hash(&tx).unwrap().to_hex(),
+            "BD636194C48FD7A100DE4C972336534C8E710FD008C0F3CF7BC5BF34DAF3C3E6"
+        );
+
+        // Mainnet `OfferCreate` of <https://xrpl.org/tx.html>
+        let blob = hex::decode("120007220000000024004CDE8E2019004CDE8C201B0363B1E064D587299C0A372E00000000000000000000000000434E590000000000CED6E99370D5C00EF4EBF72567DA99F5661BFB3A65400000037E11D60068400000000000000C732103B51A3EDF70E4098DA7FB053A01C5A6A0A163A30ED1445F14F87C7C3295FCB3BE74473045022100A5023A0E64923616FCDB6D664F569644C7C9D1895772F986CD6B981B515B02A00220530C973E9A8395BC6FE2484948D2751F6B030FC7FB8575D1BFB406368AD554D98114217C6F09CFB596F160D651906DFEF0569C7C91ED").unwrap();
+        let tx: OfferCreateTransaction = deserialize::deserialize(&blob).unwrap();
+        assert_eq!(
+            transaction_hash(&tx).unwrap().to_hex(),
+            "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9"
+        );
+    }
+}
@@ -0,0 +1,538 @@
+use crate::alloc::vec::Vec;
+use crate::deserializer::Deserializer;
+use crate::serializer::definitions::{definitions, Definitions};
+use crate::serializer::field_id::TypeCode;
+use crate::serializer::Serializer;
+use crate::BinaryCodecError;
+use serde_json::Value;
+
+/// Fields that are not part of the signed data of a transaction <https://xrpl.org/serialization.html#signing-field>
+const NON_SIGNING_FIELDS: &[&str] = &["TxnSignature", "Signers"];
+
+/// Decodes the given serialized object (e.g. a `tx_blob` or the `data` of a `ledger_data` entry)
+/// to JSON, in the format returned by rippled for `tx_json` and `node`
+pub fn decode(bytes: &[u8]) -> Result<Value, BinaryCodecError> {
+    decode_with_definitions(bytes, definitions())
+}
+
+/// Decodes the given serialized object to JSON like [`decode`], rejecting encodings that rippled
+/// considers non-canonical, see [`Deserializer::strict`]
+pub fn decode_strict(bytes: &[u8]) -> Result<Value, BinaryCodecError> {
+    let mut deserializer =
+        Deserializer::with_definitions(bytes.to_vec(), definitions()).strict(true);
+    deserializer.to_json(&TypeCode::Object, bytes)
+}
+
+/// Decodes the given serialized object to JSON using the given definitions, see [`decode`]
+pub fn decode_with_definitions(
+    bytes: &[u8],
+    definitions: &Definitions,
+) -> Result<Value, BinaryCodecError> {
+    let mut deserializer = Deserializer::with_definitions(bytes.to_vec(), definitions);
+    deserializer.to_json(&TypeCode::Object, bytes)
+}
+
+/// Encodes the given JSON object (e.g. a `tx_json` or the `node` of a ledger object) in the canonical
+/// binary format <https://xrpl.org/serialization.html>. The object has the format returned by
+/// rippled, see [`decode`], in API v1 or v2
+pub fn encode(json: &Value) -> Result<Vec<u8>, BinaryCodecError> {
+    encode_with_definitions(json, definitions())
+}
+
+/// Encodes the given JSON object using the given definitions, see [`encode`]
+pub fn encode_with_definitions(
+    json: &Value,
+    definitions: &Definitions,
+) -> Result<Vec<u8>, BinaryCodecError> {
+    let object = json
+        .as_object()
+        .ok_or_else(|| BinaryCodecError::InvalidField("Encoded JSON must be an object".into()))?;
+    let mut serializer = Serializer::with_definitions(definitions);
+    serializer.serialize_json_object(object)?;
+    serializer.into_bytes()
+}
+
+/// Encodes the given transaction JSON without the fields that are not signed, see [`encode`]. The
+/// hash prefix is not included.
+pub fn encode_for_signing(json: &Value) -> Result<Vec<u8>, BinaryCodecError> {
+    let mut json = json.clone();
+    if let Some(object) = json.as_object_mut() {
+        object.retain(|field_name, _| !NON_SIGNING_FIELDS.contains(&field_name.as_str()));
+    }
+    encode(&json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+    use proptest::prelude::*;
+    use proptest::sample::Index;
+
+    /// Serialized objects paired with the JSON rippled returns for them
+    const DECODE_FIXTURES: &str = include_str!("../fixtures/json-decode.json");
+
+    #[test]
+    fn test_decode_fixtures() {
+        let fixtures: Value = serde_json::from_str(DECODE_FIXTURES).unwrap();
+        for fixture in fixtures.as_array().unwrap() {
+            let description = fixture["description"].as_str().unwrap();
+            let bytes = hex::decode(fixture["binary"].as_str().unwrap()).unwrap();
+            let json = decode(&bytes).unwrap();
+            assert_eq!(json, fixture["json"], "{}", description);
+        }
+    }
+
+    /// Vectors from outside this crate in the format of `codec-fixtures.json` of xrpl.js, see
+    /// `fixtures/README.md` for their sources
+    const CODEC_FIXTURES: &str = include_str!("../fixtures/codec-fixtures.json");
+
+    /// Vectors produced by this codec, in the same format
+    const CODEC_REGRESSION: &str = include_str!("../fixtures/codec-regression.json");
+
+    #[test]
+    fn test_codec_fixtures_objects() {
+        for fixtures in [CODEC_FIXTURES, CODEC_REGRESSION] {
+            let fixtures: Value = serde_json::from_str(fixtures).unwrap();
+            for section in ["accountState", "transactions"] {
+                for fixture in fixtures[section].as_array().unwrap() {
+                    let binary = fixture["binary"].as_str().unwrap();
+                    let bytes = encode(&fixture["json"]).unwrap();
+                    assert_eq!(hex::encode_upper(&bytes), binary, "{}", fixture["json"]);
+                    let json = decode(&hex::decode(binary).unwrap()).unwrap();
+                    assert_eq!(json, fixture["json"], "{}", binary);
+                }
+            }
+        }
+    }
+
+    /// The single signed transactions of the fixtures must verify, the multi-signed one is
+    /// checked against its published hash in `hash::tests::test_transaction_hash`
+    #[test]
+    fn test_codec_fixtures_signed_transactions() {
+        let fixtures: Value = serde_json::from_str(CODEC_FIXTURES).unwrap();
+        let transactions = fixtures["transactions"].as_array().unwrap();
+        assert!(transactions.iter().all(|fixture| {
+            fixture["json"].get("TxnSignature").is_some()
+                || fixture["json"].get("Signers").is_some()
+        }));
+        let signed = transactions
+            .iter()
+            .filter(|fixture| fixture["json"].get("TxnSignature").is_some());
+        for fixture in signed {
+            let blob = hex::decode(fixture["binary"].as_str().unwrap()).unwrap();
+            crate::sign::verify_transaction_blob(&blob, None)
+                .unwrap_or_else(|err| panic!("{}: {}", fixture["json"], err));
+        }
+    }
+
+    #[test]
+    fn test_codec_fixtures_amounts() {
+        let amount_field_id = "61";
+        let fixtures = [CODEC_FIXTURES, CODEC_REGRESSION]
+            .map(|fixtures| serde_json::from_str::<Value>(fixtures).unwrap());
+        for fixture in fixtures
+            .iter()
+            .flat_map(|fixtures| fixtures["amounts"].as_array().unwrap())
+        {
+            let json = serde_json::json!({ "Amount": fixture["test_json"] });
+            match fixture["expected_hex"].as_str() {
+                Some(expected_hex) => {
+                    let bytes = encode(&json).unwrap();
+                    assert_eq!(
+                        hex::encode_upper(&bytes),
+                        format!("{}{}", amount_field_id, expected_hex)
+                    );
+                    assert_eq!(decode(&bytes).unwrap(), json);
+                }
+                None => assert!(encode(&json).is_err(), "{}", fixture["error"]),
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode_strict_fixtures() {
+        for bytes in fixture_blobs() {
+            assert_eq!(decode_strict(&bytes).unwrap(), decode(&bytes).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_decode_strict_non_canonical() {
+        let issuer = "4B4E9C06F24296074F7BC48F92A97916C6DC5EA9";
+        let usd = "0000000000000000000000005553440000000000";
+        let xrp = "0000000000000000000000005852500000000000";
+        let non_canonical = [
+            // Flags before TransactionType
+            (
+                "22800000001200 00".replace(' ', ""),
+                5,
+                "Field TransactionType is not in canonical order",
+            ),
+            ("120000120000".into(), 3, "Duplicate field TransactionType"),
+            // MemoData before MemoType in a memo
+            (
+                "F9EA7D01007C0100E1F1".into(),
+                5,
+                "Field MemoType is not in canonical order",
+            ),
+            (
+                format!("61D840000000000001{}{}", usd, issuer),
+                1,
+                "Issued value is not normalized",
+            ),
+            (
+                format!("61C000000000000000{}{}", usd, issuer),
+                1,
+                "Issued value is not normalized",
+            ),
+            (
+                format!("61D4838D7EA4C68000{}{}", xrp, issuer),
+                9,
+                "XRP as issued currency code",
+            ),
+            (
+                "61416345785D8A0001".into(),
+                1,
+                "Drops amount exceeds the total XRP supply",
+            ),
+            (
+                "73FEFFFF".into(),
+                1,
+                "Length exceeds the maximum length prefix",
+            ),
+            (
+                format!("0318{}{}", xrp, issuer),
+                2,
+                "XRP as issued currency code",
+            ),
+            ("011200".into(), 2, "Empty path"),
+            (
+                "91000000000000000000000000".into(),
+                1,
+                "Number is not normalized",
+            ),
+            (
+                format!("011230{}{}00", xrp, issuer),
+                3,
+                "XRP as issued currency code",
+            ),
+        ];
+        for (hex, expected_offset, expected_message) in non_canonical {
+            let bytes = hex::decode(&hex).unwrap();
+            match decode_strict(&bytes) {
+                Err(BinaryCodecError::NonCanonical { offset, message }) => {
+                    assert_eq!(
+                        (offset, message.as_str()),
+                        (expected_offset, expected_message),
+                        "{}",
+                        hex
+                    );
+                }
+                result => panic!("{}: {:?}", hex, result),
+            }
+        }
+    }
+
+    /// Numbers with more digits than the mantissa are rounded to nearest like rippled does, so
+    /// only the rounded encoding is canonical
+    #[test]
+    fn test_decode_strict_number_rounding() {
+        let json = serde_json::json!({"Number": "19999999999999999"});
+        let bytes = encode(&json).unwrap();
+        assert_eq!(hex::encode_upper(&bytes), "9100071AFD498D000000000001");
+        assert_eq!(
+            decode_strict(&bytes).unwrap(),
+            serde_json::json!({"Number": "2000000000000000e1"})
+        );
+
+        // 19999999999999999e0 and 9223372036854775807e0, normalized by rippled to
+        // 2000000000000000e1 and 9223372036854776e3
+        for hex in ["9100470DE4DF81FFFF00000000", "917FFFFFFFFFFFFFFF00000000"] {
+            let result = decode_strict(&hex::decode(hex).unwrap());
+            assert_matches!(result, Err(BinaryCodecError::NonCanonical { offset: 1, message }) => {
+                assert_eq!(message, "Number is not normalized");
+            });
+        }
+        let bytes = hex::decode("910020C49BA5E353F800000003").unwrap();
+        assert_eq!(
+            decode_strict(&bytes).unwrap(),
+            serde_json::json!({"Number": "9223372036854776e3"})
+        );
+    }
+
+    #[test]
+    fn test_decode_signed_transaction() {
+        // Signed issued currency payment from the rippled `sign` method documentation
+        let bytes = hex::decode("1200002280000000240000000361D4838D7EA4C6800000000000000000000000000055534400000000004B4E9C06F24296074F7BC48F92A97916C6DC5EA968400000000000000A732103AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB74473045022100D184EB4AE5956FF600E7536EE459345C7BBCF097A84CC61A93B9AF7197EDB98702201CEA8009B7BEEBAA2AACC0359B41C427C1C5B550A4CA4B80CF2174AF2D6D5DCE81144B4E9C06F24296074F7BC48F92A97916C6DC5EA983143E9D4A2B8AA0780F682D136F7A56D6724EF53754").unwrap();
+        let json = decode(&bytes).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "Amount": {
+                    "currency": "USD",
+                    "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                    "value": "1"
+                },
+                "Destination": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+                "Fee": "10",
+                "Flags": 2147483648u32,
+                "Sequence": 3,
+                "SigningPubKey": "03AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB",
+                "TransactionType": "Payment",
+                "TxnSignature": "3045022100D184EB4AE5956FF600E7536EE459345C7BBCF097A84CC61A93B9AF7197EDB98702201CEA8009B7BEEBAA2AACC0359B41C427C1C5B550A4CA4B80CF2174AF2D6D5DCE"
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_truncated_object() {
+        let bytes = hex::decode("120000228000000024000000").unwrap();
+        assert!(decode(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_encode_fixtures() {
+        let fixtures: Value = serde_json::from_str(DECODE_FIXTURES).unwrap();
+        for fixture in fixtures.as_array().unwrap() {
+            let description = fixture["description"].as_str().unwrap();
+            let bytes = encode(&fixture["json"]).unwrap();
+            assert_eq!(
+                hex::encode_upper(bytes),
+                fixture["binary"].as_str().unwrap(),
+                "{}",
+                description
+            );
+        }
+    }
+
+    /// Transaction with memos and signers, in the format returned by rippled including fields that
+    /// are not serialized
+    #[test]
+    fn test_encode_transaction_round_trip() {
+        let tx_json = serde_json::json!({
+            "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "Fee": "30",
+            "Flags": 0,
+            "Memos": [
+                {"Memo": {"MemoData": "72656E74", "MemoType": "687474703A2F2F6578616D706C652E636F6D2F6D656D6F2F67656E65726963"}},
+                {"Memo": {"MemoData": "00"}}
+            ],
+            "NFTokenTaxon": 0,
+            "Sequence": 3,
+            "Signers": [
+                {"Signer": {
+                    "Account": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+                    "SigningPubKey": "03AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB",
+                    "TxnSignature": "3045022100D184EB4AE5956FF600E7536EE459345C7BBCF097A84CC61A93B9AF7197EDB98702201CEA8009B7BEEBAA2AACC0359B41C427C1C5B550A4CA4B80CF2174AF2D6D5DCE"
+                }}
+            ],
+            "SigningPubKey": "",
+            "TransactionType": "NFTokenMint",
+            "URI": "697066733A2F2F62616679",
+            "hash": "E08D6E9754025BA2534A78707605E0601F03ACE063687A0CA1BDDACFCD1698C7",
+            "ledger_index": 56865245
+        });
+        let bytes = encode(&tx_json).unwrap();
+        let mut expected = tx_json.clone();
+        let expected_object = expected.as_object_mut().unwrap();
+        expected_object.remove("hash");
+        expected_object.remove("ledger_index");
+        assert_eq!(decode(&bytes).unwrap(), expected);
+    }
+
+    /// Payment of <https://xrpl.org/submit.html> in the API v2 `tx_json` format, where `Amount`
+    /// is named `DeliverMax`
+    #[test]
+    fn test_encode_deliver_max() {
+        let blob = "1200002280000000240000001E61D4838D7EA4C6800000000000000000000000000055534400000000004B4E9C06F24296074F7BC48F92A97916C6DC5EA968400000000000000B732103AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB7447304502210095D23D8AF107DF50651F266259CC7139D0CD0C64ABBA3A958156352A0D95A21E02207FCF9B77D7510380E49FF250C21B57169E14E9B4ACFD314CEDC79DDD0A38B8A681144B4E9C06F24296074F7BC48F92A97916C6DC5EA983143E9D4A2B8AA0780F682D136F7A56D6724EF53754";
+        let amount = serde_json::json!({
+            "currency": "USD",
+            "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "value": "1"
+        });
+        let tx_json_v2 = serde_json::json!({
+            "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "DeliverMax": amount,
+            "Destination": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+            "Fee": "11",
+            "Flags": 2147483648u32,
+            "Sequence": 30,
+            "SigningPubKey": "03AB40A0490F9B7ED8DF29D246BF2D6269820A0EE7742ACDD457BEA7C7D0931EDB",
+            "TransactionType": "Payment",
+            "TxnSignature": "304502210095D23D8AF107DF50651F266259CC7139D0CD0C64ABBA3A958156352A0D95A21E02207FCF9B77D7510380E49FF250C21B57169E14E9B4ACFD314CEDC79DDD0A38B8A6"
+        });
+        assert_eq!(hex::encode_upper(encode(&tx_json_v2).unwrap()), blob);
+
+        // API v1 returns both
+        let mut tx_json_v1 = tx_json_v2.clone();
+        tx_json_v1["Amount"] = amount;
+        assert_eq!(hex::encode_upper(encode(&tx_json_v1).unwrap()), blob);
+
+        tx_json_v1["DeliverMax"]["value"] = "2".into();
+        assert_matches!(encode(&tx_json_v1), Err(BinaryCodecError::InvalidField(message)) => {
+            assert!(message.contains("DeliverMax differs from Amount"), "message: {message}");
+        });
+    }
+
+    #[test]
+    fn test_encode_for_signing() {
+        let tx_json = serde_json::json!({
+            "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "Fee": "10",
+            "SigningPubKey": "",
+            "TransactionType": "AccountSet",
+            "TxnSignature": "00",
+            "Signers": [{"Signer": {"Account": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX"}}]
+        });
+        let bytes = encode_for_signing(&tx_json).unwrap();
+        let json = decode(&bytes).unwrap();
+        assert!(json.get("TxnSignature").is_none());
+        assert!(json.get("Signers").is_none());
+        assert_eq!(json["TransactionType"], "AccountSet");
+    }
+
+    #[test]
+    fn test_encode_invalid() {
+        let invalid = [
+            serde_json::json!([]),
+            serde_json::json!({"UnknownField": 1}),
+            serde_json::json!({"TransactionType": "UnknownTransaction"}),
+            serde_json::json!({"TransactionResult": "tesUNKNOWN"}),
+            serde_json::json!({"Sequence": -1}),
+            serde_json::json!({"Sequence": 4294967296u64}),
+            serde_json::json!({"Fee": "1.5"}),
+            serde_json::json!({"Amount": {"currency": "XRP", "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn", "value": "1"}}),
+            serde_json::json!({"Amount": {"currency": "USD", "value": "1"}}),
+            serde_json::json!({"Memos": [{"Memo": {}, "Signer": {}}]}),
+            serde_json::json!({"Memos": {"Memo": {}}}),
+            serde_json::json!({"Asset": {"currency": "XRP", "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"}}),
+            serde_json::json!({"Paths": [[{"type": 1}]]}),
+            serde_json::json!({"LedgerIndex": "00"}),
+        ];
+        for json in invalid {
+            assert!(encode(&json).is_err(), "{}", json);
+        }
+    }
+
+    /// Transaction type and field that are not built in, defined by loaded definitions
+    #[test]
+    fn test_encode_decode_with_definitions() {
+        let definitions_json: Value = serde_json::from_str(
+            r#"{
+                "TYPES": {"UInt16": 1, "UInt32": 2, "Amount": 6, "AccountID": 8},
+                "LEDGER_ENTRY_TYPES": {},
+                "FIELDS": [
+                    ["TransactionType", {"nth": 2, "isVLEncoded": false, "isSerialized": true, "isSigningField": true, "type": "UInt16"}],
+                    ["Fee", {"nth": 8, "isVLEncoded": false, "isSerialized": true, "isSigningField": true, "type": "Amount"}],
+                    ["Account", {"nth": 1, "isVLEncoded": true, "isSerialized": true, "isSigningField": true, "type": "AccountID"}],
+                    ["NewField", {"nth": 99, "isVLEncoded": false, "isSerialized": true, "isSigningField": true, "type": "UInt32"}]
+                ],
+                "TRANSACTION_RESULTS": {},
+                "TRANSACTION_TYPES": {"NewTransaction": 99}
+            }"#,
+        )
+        .unwrap();
+        let definitions = Definitions::from_json(&definitions_json).unwrap();
+
+        let tx_json = serde_json::json!({
+            "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "Fee": "10",
+            "NewField": 5,
+            "TransactionType": "NewTransaction"
+        });
+        assert!(encode(&tx_json).is_err());
+        let bytes = encode_with_definitions(&tx_json, &definitions).unwrap();
+        assert_eq!(
+            hex::encode_upper(&bytes),
+            "12006320630000000568400000000000000A81144B4E9C06F24296074F7BC48F92A97916C6DC5EA9"
+        );
+        assert!(decode(&bytes).is_err());
+        assert_eq!(
+            decode_with_definitions(&bytes, &definitions).unwrap(),
+            tx_json
+        );
+    }
+
+    #[test]
+    fn test_encode_decode_xchain_bridge_and_number() {
+        let json = serde_json::json!({
+            "AssetsTotal": "-12.5",
+            "XChainBridge": {
+                "IssuingChainDoor": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+                "IssuingChainIssue": {"currency": "XRP"},
+                "LockingChainDoor": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "LockingChainIssue": {"currency": "XRP"}
+            }
+        });
+        let bytes = encode(&json).unwrap();
+        assert_eq!(
+            hex::encode_upper(&bytes),
+            concat!(
+                "94FFFB8F21B207E000FFFFFFF2",
+                "011914",
+                "4B4E9C06F24296074F7BC48F92A97916C6DC5EA9",
+                "0000000000000000000000000000000000000000",
+                "14",
+                "B5F762798A53D543A014CAF8B297CFF8F2F937E8",
+                "0000000000000000000000000000000000000000",
+            )
+        );
+        assert_eq!(decode(&bytes).unwrap(), json);
+    }
+
+    /// Serialized objects of the fixtures, used as seeds for mutations
+    fn fixture_blobs() -> Vec<Vec<u8>> {
+        let decode_fixtures: Value = serde_json::from_str(DECODE_FIXTURES).unwrap();
+        let codec_fixtures = [CODEC_FIXTURES, CODEC_REGRESSION]
+            .map(|fixtures| serde_json::from_str::<Value>(fixtures).unwrap());
+        decode_fixtures
+            .as_array()
+            .unwrap()
+            .iter()
+            .chain(codec_fixtures.iter().flat_map(|fixtures| {
+                let objects = fixtures["accountState"].as_array().unwrap();
+                objects
+                    .iter()
+                    .chain(fixtures["transactions"].as_array().unwrap())
+            }))
+            .map(|fixture| hex::decode(fixture["binary"].as_str().unwrap()).unwrap())
+            .collect()
+    }
+
+    /// Decoding must not panic, and a successfully decoded object must encode to bytes that
+    /// decode to the same object. Objects accepted in strict mode must encode to the same bytes.
+    fn check_decode_encode(bytes: &[u8]) {
+        if let Ok(json) = decode(bytes) {
+            let encoded = encode(&json).unwrap_or_else(|err| panic!("{:?}: {}", err, json));
+            assert_eq!(decode(&encoded).unwrap(), json);
+        }
+        if let Ok(json) = decode_strict(bytes) {
+            assert_eq!(encode(&json).unwrap(), bytes);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_decode_arbitrary_bytes(bytes in prop::collection::vec(any::<u8>(), 0..256)) {
+            check_decode_encode(&bytes);
+        }
+
+        #[test]
+        fn test_decode_mutated_fixtures(
+            fixture in any::<Index>(),
+            mutations in prop::collection::vec(any::<(Index, u8)>(), 1..4),
+            truncate in any::<Index>(),
+        ) {
+            let blobs = fixture_blobs();
+            let mut bytes = fixture.get(&blobs).clone();
+            for (index, byte) in mutations {
+                *index.get_mut(&mut bytes) = byte;
+            }
+            check_decode_encode(&bytes);
+            bytes.truncate(truncate.index(bytes.len()));
+            check_decode_encode(&bytes);
+        }
+    }
+}
@@ -0,0 +1,432 @@
+//! Ledger object ids (keylets) <https://xrpl.org/ledger-object-ids.html>. The id of a ledger object
+//! is the `index` used by `ledger_entry` and in the `AffectedNodes` of transaction metadata.
+
+use crate::alloc::vec::Vec;
+use crate::hash::sha512_half;
+use xrpl_types::{AccountId, Amount, Currency, Hash256};
+
+/// Namespaces of the ledger object ids, see `LedgerNameSpace` in
+/// <https://github.com/XRPLF/rippled/blob/develop/src/libxrpl/protocol/Indexes.cpp>
+mod space {
+    pub const ACCOUNT: u8 = b'a';
+    pub const DIR_NODE: u8 = b'd';
+    pub const TRUST_LINE: u8 = b'r';
+    pub const OFFER: u8 = b'o';
+    pub const OWNER_DIR: u8 = b'O';
+    pub const BOOK_DIR: u8 = b'B';
+    pub const ESCROW: u8 = b'u';
+    pub const TICKET: u8 = b'T';
+    pub const SIGNER_LIST: u8 = b'S';
+    pub const XRP_PAYMENT_CHANNEL: u8 = b'x';
+    pub const CHECK: u8 = b'C';
+    pub const DEPOSIT_PREAUTH: u8 = b'p';
+    pub const NFTOKEN_OFFER: u8 = b'q';
+    pub const AMM: u8 = b'A';
+    pub const DID: u8 = b'I';
+}
+
+/// Number of low bytes of an `NFTokenID` that determine the `NFTokenPage` containing the token
+const NFTOKEN_PAGE_TOKEN_BYTES: usize = 12;
+
+/// SHA-512Half of the 16 bit namespace followed by the given parts
+fn index(space: u8, parts: &[&[u8]]) -> Hash256 {
+    let mut data = Vec::with_capacity(2 + parts.iter().map(|part| part.len()).sum::<usize>());
+    data.extend_from_slice(&u16::from(space).to_be_bytes());
+    for part in parts {
+        data.extend_from_slice(part);
+    }
+    sha512_half(&data)
+}
+
+/// Currency code and issuer of the currency, both zero for XRP
+fn issue_bytes(currency: Currency) -> ([u8; 20], [u8; 20]) {
+    match currency {
+        Currency::Xrp => ([0; 20], [0; 20]),
+        Currency::Issued(issued) => (issued.currency_code().to_bytes(), issued.issuer().0),
+    }
+}
+
+/// <https://xrpl.org/accountroot.html#accountroot-id-format>
+pub fn account_root(account: AccountId) -> Hash256 {
+    index(space::ACCOUNT, &[&account.0])
+}
+
+/// Trust line between the two accounts, in any order <https://xrpl.org/ripplestate.html#ripplestate-id-format>
+pub fn ripple_state(
+    account: AccountId,
+    counterparty: AccountId,
+    currency_code: xrpl_types::CurrencyCode,
+) -> Hash256 {
+    let (low, high) = if account.0 < counterparty.0 {
+        (account, counterparty)
+    } else {
+        (counterparty, account)
+    };
+    index(
+        space::TRUST_LINE,
+        &[&low.0, &high.0, &currency_code.to_bytes()],
+    )
+}
+
+/// Offer created by the account with the given sequence <https://xrpl.org/offer.html#offer-id-format>
+pub fn offer(account: AccountId, sequence: u32) -> Hash256 {
+    index(space::OFFER, &[&account.0, &sequence.to_be_bytes()])
+}
+
+/// Root page of the owner directory of the account <https://xrpl.org/directorynode.html#directory-id-formats>
+pub fn owner_directory(account: AccountId) -> Hash256 {
+    index(space::OWNER_DIR, &[&account.0])
+}
+
+/// Page of the directory with the given root page. Page 0 is the root page itself.
+pub fn directory_page(root: Hash256, page: u64) -> Hash256 {
+    if page == 0 {
+        return root;
+    }
+    index(space::DIR_NODE, &[&root.0, &page.to_be_bytes()])
+}
+
+/// Order book directory of offers exchanging `taker_gets` for `taker_pays` at the given quality,
+/// see [`quality`] and <https://xrpl.org/directorynode.html#directory-id-formats>
+pub fn book_directory(taker_pays: Currency, taker_gets: Currency, quality: u64) -> Hash256 {
+    let (pays_currency, pays_issuer) = issue_bytes(taker_pays);
+    let (gets_currency, gets_issuer) = issue_bytes(taker_gets);
+    let mut book = index(
+        space::BOOK_DIR,
+        &[&pays_currency, &gets_currency, &pays_issuer, &gets_issuer],
+    );
+    book.0[24..].copy_from_slice(&quality.to_be_bytes());
+    book
+}
+
+/// Quality (exchange rate `taker_pays / taker_gets`) of an offer in the format used in the id of
+/// the order book directory, see `getRate` in
+/// <https://github.com/XRPLF/rippled/blob/develop/src/libxrpl/protocol/STAmount.cpp>. Like
+/// `getRate`, returns 0 when `taker_gets` is zero or the rate is out of the range of an amount.
+pub fn quality(taker_pays: Amount, taker_gets: Amount) -> u64 {
+    const MANTISSA_MIN: u128 = 1_000_000_000_000_000;
+    const MANTISSA_MAX: u128 = 9_999_999_999_999_999;
+    const EXPONENT_MIN: i32 = -96;
+    const EXPONENT_MAX: i32 = 80;
+
+    let mantissa_exponent = |amount: Amount| -> (u128, i32) {
+        match amount {
+            Amount::Drops(drops) => {
+                let mut mantissa = u128::from(drops.drops());
+                let mut exponent = 0;
+                while mantissa != 0 && mantissa < MANTISSA_MIN {
+                    mantissa *= 10;
+                    exponent -= 1;
+                }
+                (mantissa, exponent)
+            }
+            Amount::Issued(issued) => (
+                u128::from(issued.value().mantissa().unsigned_abs()),
+                i32::from(issued.value().exponent()),
+            ),
+        }
+    };
+
+    let (pays_mantissa, pays_exponent) = mantissa_exponent(taker_pays);
+    let (gets_mantissa, gets_exponent) = mantissa_exponent(taker_gets);
+    if gets_mantissa == 0 || pays_mantissa == 0 {
+        return 0;
+    }
+
+    let mut mantissa = pays_mantissa * 100_000_000_000_000_000 / gets_mantissa + 5;
+    let mut exponent = pays_exponent - gets_exponent - 17;
+    while mantissa > MANTISSA_MAX {
+        mantissa /= 10;
+        exponent += 1;
+    }
+    // rippled rounds a rate below the smallest amount to zero and fails on one above the largest
+    if !(EXPONENT_MIN..=EXPONENT_MAX).contains(&exponent) {
+        return 0;
+    }
+    ((exponent + 100) as u64) << 56 | mantissa as u64
+}
+
+/// Escrow created by the account with the given sequence <https://xrpl.org/escrow.html#escrow-id-format>
+pub fn escrow(account: AccountId, sequence: u32) -> Hash256 {
+    index(space::ESCROW, &[&account.0, &sequence.to_be_bytes()])
+}
+
+/// Payment channel created by the source account with the given sequence
+/// <https://xrpl.org/paychannel.html#paychannel-id-format>
+pub fn pay_channel(source: AccountId, destination: AccountId, sequence: u32) -> Hash256 {
+    index(
+        space::XRP_PAYMENT_CHANNEL,
+        &[&source.0, &destination.0, &sequence.to_be_bytes()],
+    )
+}
+
+/// Check created by the account with the given sequence <https://xrpl.org/check.html#check-id-format>
+pub fn check(account: AccountId, sequence: u32) -> Hash256 {
+    index(space::CHECK, &[&account.0, &sequence.to_be_bytes()])
+}
+
+/// Ticket of the account with the given ticket sequence <https://xrpl.org/ticket.html#ticket-id-format>
+pub fn ticket(account: AccountId, ticket_sequence: u32) -> Hash256 {
+    index(space::TICKET, &[&account.0, &ticket_sequence.to_be_bytes()])
+}
+
+/// <https://xrpl.org/signerlist.html#signerlist-id-format>
+pub fn signer_list(account: AccountId) -> Hash256 {
+    // the signer list id is always 0
+    index(space::SIGNER_LIST, &[&account.0, &0u32.to_be_bytes()])
+}
+
+/// Preauthorization of `authorized` by `owner` <https://xrpl.org/depositpreauth-object.html#depositpreauth-id-format>
+pub fn deposit_preauth(owner: AccountId, authorized: AccountId) -> Hash256 {
+    index(space::DEPOSIT_PREAUTH, &[&owner.0, &authorized.0])
+}
+
+/// Lowest possible id of an `NFTokenPage` of the owner <https://xrpl.org/nftokenpage.html#nftokenpage-id-format>
+pub fn nftoken_page_min(owner: AccountId) -> Hash256 {
+    let mut page = Hash256([0; 32]);
+    page.0[..20].copy_from_slice(&owner.0);
+    page
+}
+
+/// Highest possible id of an `NFTokenPage` of the owner, the id of the last page
+/// <https://xrpl.org/nftokenpage.html#nftokenpage-id-format>
+pub fn nftoken_page_max(owner: AccountId) -> Hash256 {
+    let mut page = Hash256([0xFF; 32]);
+    page.0[..20].copy_from_slice(&owner.0);
+    page
+}
+
+/// Id that the `NFTokenPage` containing the given token is located at or after. The token is
+/// stored on the page of the owner with the lowest id greater than or equal to the returned id
+/// <https://xrpl.org/nftokenpage.html#nftokenpage-id-format>
+pub fn nftoken_page(owner: AccountId, nftoken_id: Hash256) -> Hash256 {
+    let mut page = nftoken_page_min(owner);
+    page.0[32 - NFTOKEN_PAGE_TOKEN_BYTES..]
+        .copy_from_slice(&nftoken_id.0[32 - NFTOKEN_PAGE_TOKEN_BYTES..]);
+    page
+}
+
+/// NFToken offer created by the account with the given sequence
+/// <https://xrpl.org/nftokenoffer.html#nftokenoffer-id-format>
+pub fn nftoken_offer(account: AccountId, sequence: u32) -> Hash256 {
+    index(space::NFTOKEN_OFFER, &[&account.0, &sequence.to_be_bytes()])
+}
+
+/// AMM for the two assets, in any order <https://xrpl.org/amm.html#amm-id-format>
+pub fn amm(asset: Currency, asset2: Currency) -> Hash256 {
+    let issue = issue_bytes(asset);
+    let issue2 = issue_bytes(asset2);
+    // issues are ordered by currency code, then by issuer
+    let (min, max) = if issue <= issue2 {
+        (issue, issue2)
+    } else {
+        (issue2, issue)
+    };
+    index(space::AMM, &[&min.1, &min.0, &max.1, &max.0])
+}
+
+/// <https://xrpl.org/did.html#did-id-format>
+pub fn did(account: AccountId) -> Hash256 {
+    index(space::DID, &[&account.0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use xrpl_types::{CurrencyCode, IssuedValue};
+
+    fn account(address: &str) -> AccountId {
+        AccountId::from_address(address).unwrap()
+    }
+
+    #[test]
+    fn test_account_root() {
+        assert_eq!(
+            account_root(account("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn")).to_hex(),
+            "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8"
+        );
+    }
+
+    #[test]
+    fn test_ripple_state() {
+        let account1 = account("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn");
+        let account2 = account("rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW");
+        let usd: CurrencyCode = "USD".parse().unwrap();
+        assert_eq!(
+            ripple_state(account1, account2, usd).to_hex(),
+            "9CA88CDEDFF9252B3DE183CE35B038F57282BC9503CDFA1923EF9A95DF0D6F7B"
+        );
+        assert_eq!(
+            ripple_state(account2, account1, usd),
+            ripple_state(account1, account2, usd)
+        );
+    }
+
+    #[test]
+    fn test_offer() {
+        assert_eq!(
+            offer(account("r32UufnaCGL82HubijgJGDmdE5hac7ZvLw"), 137).to_hex(),
+            "03F0AED09DEEE74CEF85CD57A0429D6113507CF759C597BABB4ADB752F734CE3"
+        );
+    }
+
+    #[test]
+    fn test_directory_page() {
+        let root = owner_directory(account("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"));
+        assert_eq!(directory_page(root, 0), root);
+        assert_ne!(directory_page(root, 1), root);
+    }
+
+    #[test]
+    fn test_book_directory() {
+        let usd = Currency::issued(
+            "USD".parse().unwrap(),
+            account("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B"),
+        )
+        .unwrap();
+        assert_eq!(
+            book_directory(usd, Currency::xrp(), 0x4E1566CBCD3F2B5C).to_hex(),
+            "DFA3B6DDAB58C7E8E5D944E736DA4B7046C30E4F460FD9DE4E1566CBCD3F2B5C"
+        );
+    }
+
+    #[test]
+    fn test_quality() {
+        let usd = Amount::issued(
+            IssuedValue::from_mantissa_exponent(1, 0).unwrap(),
+            "USD".parse().unwrap(),
+            account("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B"),
+        )
+        .unwrap();
+        let xrp = Amount::drops(2_000_000).unwrap();
+        // 0.5e-6 USD per drop
+        assert_eq!(quality(usd, xrp), (78 << 56) | 5_000_000_000_000_000);
+        // 2e6 drops per USD
+        assert_eq!(quality(xrp, usd), (91 << 56) | 2_000_000_000_000_000);
+        // 0 like rippled's getRate, for a zero taker gets and for rates out of range
+        assert_eq!(quality(usd, Amount::drops(0).unwrap()), 0);
+        assert_eq!(quality(Amount::drops(0).unwrap(), usd), 0);
+        let issued = |exponent| {
+            Amount::issued(
+                IssuedValue::from_mantissa_exponent(1, exponent).unwrap(),
+                "USD".parse().unwrap(),
+                account("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B"),
+            )
+            .unwrap()
+        };
+        assert_eq!(quality(issued(80), issued(-96)), 0);
+        assert_eq!(quality(issued(-96), issued(80)), 0);
+        assert_eq!(
+            quality(issued(80), issued(0)),
+            (165 << 56) | 1_000_000_000_000_000
+        );
+    }
+
+    #[test]
+    fn test_pay_channel() {
+        assert_eq!(
+            pay_channel(
+                account("rDx69ebzbowuqztksVDmZXjizTd12BVr4x"),
+                account("rLFtVprxUEfsH54eCWKsZrEQzMDsx1wqso"),
+                82
+            )
+            .to_hex(),
+            "E35708503B3C3143FB522D749AAFCC296E8060F0FB371A9A56FAE0B1ED127366"
+        );
+    }
+
+    #[test]
+    fn test_escrow() {
+        // Escrow example of <https://xrpl.org/escrow.html>, created by the account's
+        // transaction with sequence 366
+        assert_eq!(
+            escrow(account("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"), 366).to_hex(),
+            "DC5F3851D8A1AB622F957761E5963BC5BD439D5C24AC6AD7AC4523F0640244AC"
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        // Check example of <https://xrpl.org/check.html>
+        assert_eq!(
+            check(account("rUn84CUYbNjRoTQ6mSW7BVJPSVJNLb1QLo"), 2).to_hex(),
+            "49647F0D748DC3FE26BDACBC57F251AADEFFF391403EC9BF87C97F67E9977FB0"
+        );
+    }
+
+    #[test]
+    fn test_signer_list() {
+        assert_eq!(
+            signer_list(account("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn")).to_hex(),
+            "A9C28A28B85CD533217F5C0A0C7767666B093FA58A0F2D80026FCC4CD932DDC7"
+        );
+    }
+
+    #[test]
+    fn test_deposit_preauth() {
+        // DepositPreauth example of <https://xrpl.org/depositpreauth-object.html>
+        assert_eq!(
+            deposit_preauth(
+                account("rsUiUMpnrgxQp24dJYZDhmV4bE3aBtQyt8"),
+                account("rEhxGqkqPPSxQ3P25J66ft5TwpzV14k2de")
+            )
+            .to_hex(),
+            "4A255038CC3ADCC1A9C91509279B59908251728D0DAADB248FFE297D0F7E068C"
+        );
+    }
+
+    #[test]
+    fn test_nftoken_page() {
+        let owner = account("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn");
+        let nftoken_id =
+            Hash256::from_hex("000B013A95F14B0044F78A264E41713C64B5F89242540EE208C3098E00000D65")
+                .unwrap();
+        let page = nftoken_page(owner, nftoken_id);
+        assert_eq!(
+            page.to_hex(),
+            "4B4E9C06F24296074F7BC48F92A97916C6DC5EA942540EE208C3098E00000D65"
+        );
+        assert!(nftoken_page_min(owner).0 <= page.0 && page.0 <= nftoken_page_max(owner).0);
+    }
+
+    #[test]
+    fn test_nftoken_offer() {
+        // NFTokenOffer example of <https://xrpl.org/nftokenoffer.html>, created by the owner's
+        // transaction with sequence 68906564
+        assert_eq!(
+            nftoken_offer(account("rhRxL3MNvuKEjWjL7TBbZSDacb8PmzAd7m"), 68906564).to_hex(),
+            "AEBABA4FAC212BF28E0F9A9C3788A47B085557EC5D1429E7A8266FB859C863B3"
+        );
+    }
+
+    #[test]
+    fn test_amm() {
+        let usd = Currency::issued(
+            "USD".parse().unwrap(),
+            account("rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq"),
+        )
+        .unwrap();
+        assert_eq!(amm(Currency::xrp(), usd), amm(usd, Currency::xrp()));
+        assert_ne!(amm(Currency::xrp(), usd), amm(usd, usd));
+        // XRP/TST pool of the AMM example of <https://xrpl.org/amm.html>, with the id computed
+        // from the AMM id format
+        let tst = Currency::issued(
+            "TST".parse().unwrap(),
+            account("rP9jPyP5kyvFRb6ZiRghAGw5u8SGAmU4bd"),
+        )
+        .unwrap();
+        assert_eq!(
+            amm(tst, Currency::xrp()).to_hex(),
+            "97DD92D4F3A791254A530BA769F6669DEBF6B2FC8CCA46842B9031ADCD4D1ADA"
+        );
+    }
+
+    #[test]
+    fn test_did() {
+        // DID example of <https://xrpl.org/did.html>
+        assert_eq!(
+            did(account("rpfqJrXg5uidNo2ZsRhRY6TiF1cvYmV9Fg")).to_hex(),
+            "46813BE38B798B3752CA590D44E7FEADB17485649074403AD1761A2835CE91FF"
+        );
+    }
+}
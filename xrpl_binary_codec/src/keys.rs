@@ -0,0 +1,330 @@
+use crate::alloc::{string::ToString, vec::Vec};
+use crate::{hash, BinaryCodecError};
+use core::fmt;
+use ed25519_dalek::SigningKey;
+use libsecp256k1::{PublicKey, SecretKey};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+use xrpl_address_codec::{decode_seed, KeyType, SEED_LENGTH};
+use xrpl_types::{AccountId, Blob};
+
+/// Prefix of Ed25519 public keys, to distinguish them from 33 byte compressed secp256k1 public keys
+pub const ED25519_PUBLIC_KEY_PREFIX: u8 = 0xED;
+
+/// Key pair of either signing algorithm, see <https://xrpl.org/cryptographic-keys.html#signing-algorithms>
+#[derive(Debug, Clone)]
+pub enum KeyPair {
+    Secp256k1(Secp256k1KeyPair),
+    Ed25519(Ed25519KeyPair),
+}
+
+impl KeyPair {
+    /// Derives the key pair of the given key type from the given seed entropy
+    pub fn from_seed_bytes(seed: &[u8; SEED_LENGTH], key_type: KeyType) -> Self {
+        match key_type {
+            KeyType::Secp256k1 => Self::Secp256k1(Secp256k1KeyPair::from_seed_bytes(seed)),
+            KeyType::Ed25519 => Self::Ed25519(Ed25519KeyPair::from_seed_bytes(seed)),
+        }
+    }
+
+    /// Derives the key pair from the given base58 encoded seed. The key type is
+    /// detected from the seed encoding (`sEd...` for Ed25519, `s...` for secp256k1)
+    pub fn from_seed(seed: &str) -> Result<Self, BinaryCodecError> {
+        let (seed, key_type) =
+            decode_seed(seed).map_err(|err| BinaryCodecError::InvalidKey(err.to_string()))?;
+        Ok(Self::from_seed_bytes(&seed, key_type))
+    }
+
+    pub fn key_type(&self) -> KeyType {
+        match self {
+            Self::Secp256k1(_) => KeyType::Secp256k1,
+            Self::Ed25519(_) => KeyType::Ed25519,
+        }
+    }
+
+    /// The 33 byte public key, as used in the `SigningPubKey` field
+    pub fn public_key(&self) -> Blob {
+        match self {
+            Self::Secp256k1(key_pair) => Blob(key_pair.public_key.serialize_compressed().to_vec()),
+            Self::Ed25519(key_pair) => Blob(key_pair.public_key().to_vec()),
+        }
+    }
+
+    /// The account id of the public key
+    pub fn account_id(&self) -> AccountId {
+        account_id_from_public_key(&self.public_key().0)
+    }
+}
+
+impl From<Secp256k1KeyPair> for KeyPair {
+    fn from(key_pair: Secp256k1KeyPair) -> Self {
+        Self::Secp256k1(key_pair)
+    }
+}
+
+impl From<Ed25519KeyPair> for KeyPair {
+    fn from(key_pair: Ed25519KeyPair) -> Self {
+        Self::Ed25519(key_pair)
+    }
+}
+
+/// secp256k1 key pair derived from a seed with the XRPL key derivation,
+/// see <https://xrpl.org/cryptographic-keys.html#secp256k1-key-derivation>
+#[derive(Clone, Eq, PartialEq)]
+pub struct Secp256k1KeyPair {
+    pub public_key: PublicKey,
+    pub secret_key: SecretKey,
+}
+
+/// Shows the public key only, the secret key is redacted
+impl fmt::Debug for Secp256k1KeyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Secp256k1KeyPair")
+            .field(
+                "public_key",
+                &Blob(self.public_key.serialize_compressed().to_vec()),
+            )
+            .finish_non_exhaustive()
+    }
+}
+
+impl Secp256k1KeyPair {
+    /// Derives the key pair of the first account (account index 0) of the given seed,
+    /// equivalent to the `master_key` returned by rippled's `wallet_propose`
+    pub fn from_seed_bytes(seed: &[u8; SEED_LENGTH]) -> Self {
+        let root_secret_key = derive_scalar(seed, None);
+        let root_public_key = PublicKey::from_secret_key(&root_secret_key);
+
+        let intermediate_secret_key =
+            derive_scalar(&root_public_key.serialize_compressed(), Some(0));
+
+        let mut secret_key = root_secret_key;
+        secret_key
+            .tweak_add_assign(&intermediate_secret_key)
+            .expect("sum of derived scalars is a valid secret key");
+
+        Self {
+            public_key: PublicKey::from_secret_key(&secret_key),
+            secret_key,
+        }
+    }
+
+    /// Derives the key pair from the given base58 encoded family seed (`s...`)
+    pub fn from_seed(seed: &str) -> Result<Self, BinaryCodecError> {
+        let (seed, key_type) =
+            decode_seed(seed).map_err(|err| BinaryCodecError::InvalidKey(err.to_string()))?;
+
+        if key_type != KeyType::Secp256k1 {
+            return Err(BinaryCodecError::InvalidKey(
+                "seed is not a secp256k1 seed".to_string(),
+            ));
+        }
+
+        Ok(Self::from_seed_bytes(&seed))
+    }
+
+    /// The account id of the public key
+    pub fn account_id(&self) -> AccountId {
+        account_id_from_public_key(&self.public_key.serialize_compressed())
+    }
+}
+
+/// Ed25519 key pair, see <https://xrpl.org/cryptographic-keys.html#ed25519-key-derivation>
+#[derive(Clone)]
+pub struct Ed25519KeyPair {
+    pub signing_key: SigningKey,
+}
+
+/// Shows the public key only, the signing key is redacted
+impl fmt::Debug for Ed25519KeyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ed25519KeyPair")
+            .field("public_key", &Blob(self.public_key().to_vec()))
+            .finish_non_exhaustive()
+    }
+}
+
+impl Ed25519KeyPair {
+    /// Derives the key pair from the given seed entropy. The secret key is the SHA-512Half of the seed.
+    pub fn from_seed_bytes(seed: &[u8; SEED_LENGTH]) -> Self {
+        Self::from_secret_key_bytes(&hash::sha512_half(seed).0)
+    }
+
+    /// Derives the key pair from the given base58 encoded Ed25519 seed (`sEd...`)
+    pub fn from_seed(seed: &str) -> Result<Self, BinaryCodecError> {
+        let (seed, key_type) =
+            decode_seed(seed).map_err(|err| BinaryCodecError::InvalidKey(err.to_string()))?;
+
+        if key_type != KeyType::Ed25519 {
+            return Err(BinaryCodecError::InvalidKey(
+                "seed is not an Ed25519 seed".to_string(),
+            ));
+        }
+
+        Ok(Self::from_seed_bytes(&seed))
+    }
+
+    pub fn from_secret_key_bytes(secret_key: &[u8; 32]) -> Self {
+        Self {
+            signing_key: SigningKey::from_bytes(secret_key),
+        }
+    }
+
+    /// The 33 byte public key, the 32 byte Ed25519 public key prefixed with `0xED`
+    pub fn public_key(&self) -> [u8; 33] {
+        let mut public_key = [0u8; 33];
+        public_key[0] = ED25519_PUBLIC_KEY_PREFIX;
+        public_key[1..].copy_from_slice(self.signing_key.verifying_key().as_bytes());
+        public_key
+    }
+
+    /// The account id of the public key
+    pub fn account_id(&self) -> AccountId {
+        account_id_from_public_key(&self.public_key())
+    }
+}
+
+/// Derives the first valid secp256k1 scalar from SHA-512Half of the given bytes, followed by the
+/// optional account index and a sequence number
+fn derive_scalar(bytes: &[u8], account_index: Option<u32>) -> SecretKey {
+    for sequence in 0u32.. {
+        let mut data = Vec::with_capacity(bytes.len() + 8);
+        data.extend_from_slice(bytes);
+        if let Some(account_index) = account_index {
+            data.extend_from_slice(&account_index.to_be_bytes());
+        }
+        data.extend_from_slice(&sequence.to_be_bytes());
+
+        // The parse fails for zero and values not less than the curve order
+        if let Ok(secret_key) = SecretKey::parse(&hash::sha512_half(&data).0) {
+            return secret_key;
+        }
+    }
+    unreachable!("no valid secp256k1 scalar derived")
+}
+
+/// Calculates the account id of the given public key, see <https://xrpl.org/accounts.html#address-encoding>
+pub fn account_id_from_public_key(public_key: &[u8]) -> AccountId {
+    let sha256 = Sha256::digest(public_key);
+    let ripemd160: [u8; 20] = Ripemd160::digest(sha256).into();
+    AccountId(ripemd160)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+
+    #[test]
+    fn test_secp256k1_key_pair_from_seed() {
+        // The genesis account, see `wallet_propose masterpassphrase`
+        let key_pair = Secp256k1KeyPair::from_seed("snoPBrXtMeMyMHUVTgbuqAfg1SUTb").unwrap();
+
+        assert_eq!(
+            hex::encode_upper(key_pair.secret_key.serialize()),
+            "1ACAAEDECE405B2A958212629E16F2EB46B153EEE94CDD350FDEFF52795525B7"
+        );
+        assert_eq!(
+            hex::encode_upper(key_pair.public_key.serialize_compressed()),
+            "0330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD020"
+        );
+        assert_eq!(
+            key_pair.account_id().to_address(),
+            "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"
+        );
+    }
+
+    #[test]
+    fn test_secp256k1_key_pair_from_seed_2() {
+        // See the ripple-keypairs test fixtures
+        let key_pair = Secp256k1KeyPair::from_seed("sp5fghtJtpUorTwvof1NpDXAzNwf5").unwrap();
+
+        assert_eq!(
+            hex::encode_upper(key_pair.secret_key.serialize()),
+            "D78B9735C3F26501C7337B8A5727FD53A6EFDBC6AA55984F098488561F985E23"
+        );
+        assert_eq!(
+            hex::encode_upper(key_pair.public_key.serialize_compressed()),
+            "030D58EB48B4420B1F7B9DF55087E0E29FEF0E8468F9A6825B01CA2C361042D435"
+        );
+        assert_eq!(
+            key_pair.account_id().to_address(),
+            "rU6K7V3Po4snVhBBaU29sesqs2qTQJWDw1"
+        );
+    }
+
+    #[test]
+    fn test_secp256k1_key_pair_from_ed25519_seed() {
+        let result = Secp256k1KeyPair::from_seed("sEdTM1uX8pu2do5XvTnutH6HsouMaM2");
+        assert_matches!(result, Err(BinaryCodecError::InvalidKey(message)) => {
+            assert!(message.contains("not a secp256k1 seed"), "message: {message}");
+        });
+    }
+
+    #[test]
+    fn test_ed25519_key_pair_from_seed() {
+        // See the ripple-keypairs test fixtures
+        let key_pair = Ed25519KeyPair::from_seed("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r").unwrap();
+
+        assert_eq!(
+            hex::encode_upper(key_pair.signing_key.to_bytes()),
+            "B4C4E046826BD26190D09715FC31F4E6A728204EADD112905B08B14B7F15C4F3"
+        );
+        assert_eq!(
+            hex::encode_upper(key_pair.public_key()),
+            "ED01FA53FA5A7E77798F882ECE20B1ABC00BB358A9E55A202D0D0676BD0CE37A63"
+        );
+        assert_eq!(
+            key_pair.account_id().to_address(),
+            "rLUEXYuLiQptky37CqLcm9USQpPiz5rkpD"
+        );
+    }
+
+    #[test]
+    fn test_ed25519_key_pair_from_secp256k1_seed() {
+        let result = Ed25519KeyPair::from_seed("snoPBrXtMeMyMHUVTgbuqAfg1SUTb");
+        assert_matches!(result, Err(BinaryCodecError::InvalidKey(message)) => {
+            assert!(message.contains("not an Ed25519 seed"), "message: {message}");
+        });
+    }
+
+    #[test]
+    fn test_key_pair_from_seed() {
+        let key_pair = KeyPair::from_seed("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r").unwrap();
+        assert_eq!(key_pair.key_type(), KeyType::Ed25519);
+        assert_eq!(
+            key_pair.account_id().to_address(),
+            "rLUEXYuLiQptky37CqLcm9USQpPiz5rkpD"
+        );
+
+        let key_pair = KeyPair::from_seed("snoPBrXtMeMyMHUVTgbuqAfg1SUTb").unwrap();
+        assert_eq!(key_pair.key_type(), KeyType::Secp256k1);
+        assert_eq!(
+            hex::encode_upper(key_pair.public_key().0),
+            "0330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD020"
+        );
+    }
+
+    #[test]
+    fn test_key_pair_debug_redacts_secret_key() {
+        let key_pair = KeyPair::from_seed("snoPBrXtMeMyMHUVTgbuqAfg1SUTb").unwrap();
+        assert_eq!(
+            format!("{:?}", key_pair),
+            "Secp256k1(Secp256k1KeyPair { public_key: \
+             0330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD020, .. })"
+        );
+
+        let key_pair = KeyPair::from_seed("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r").unwrap();
+        assert_eq!(
+            format!("{:?}", key_pair),
+            "Ed25519(Ed25519KeyPair { public_key: \
+             ED01FA53FA5A7E77798F882ECE20B1ABC00BB358A9E55A202D0D0676BD0CE37A63, .. })"
+        );
+    }
+
+    #[test]
+    fn test_secp256k1_key_pair_from_invalid_seed() {
+        let result = Secp256k1KeyPair::from_seed("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+        assert_matches!(result, Err(BinaryCodecError::InvalidKey(_)));
+    }
+}
@@ -3,12 +3,21 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 extern crate alloc;
 
+pub mod amm;
+pub mod crypto_condition;
+pub mod deserialize;
 pub mod deserializer;
 mod error;
 pub mod hash;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod keylet;
+pub mod keys;
 pub mod serialize;
 /// Implements serialization according to <https://xrpl.org/serialization.html>
 pub mod serializer;
+pub mod shamap;
 pub mod sign;
+pub mod wallet;
 
 pub use error::*;
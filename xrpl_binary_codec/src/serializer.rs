@@ -1,26 +1,57 @@
 use crate::alloc::{format, string::ToString, vec::Vec};
 use crate::error::BinaryCodecError;
+use crate::serializer::definitions::{definitions, Definitions};
 use crate::serializer::field_id::{FieldCode, FieldId, TypeCode};
 use xrpl_types::{
     serialize::{Serialize, SerializeArray},
-    AccountId, Amount, Blob, CurrencyCode, DropsAmount, Hash128, Hash160, Hash256, IssuedValue,
-    UInt16, UInt32, UInt8, Uint64,
+    AccountId, Amount, Blob, Currency, CurrencyCode, DropsAmount, Hash128, Hash160, Hash256,
+    IssuedValue, Number, Path, UInt16, UInt32, UInt8, Uint64, XChainBridge,
 };
 
+#[cfg(feature = "json")]
+use core::str::FromStr;
+#[cfg(feature = "json")]
+use serde_json::{Map, Value};
+#[cfg(feature = "json")]
+use xrpl_types::PathStep;
+
+pub mod definitions;
 pub mod field_id;
 pub mod field_info;
+pub mod transaction_result;
+
+/// Path set encoding <https://xrpl.org/serialization.html#pathset-fields>
+pub(crate) mod path_set {
+    pub const END_BYTE: u8 = 0x00;
+    pub const PATH_SEPARATOR_BYTE: u8 = 0xFF;
+    pub const ACCOUNT_STEP_BIT: u8 = 0x01;
+    pub const CURRENCY_STEP_BIT: u8 = 0x10;
+    pub const ISSUER_STEP_BIT: u8 = 0x20;
+    pub const STEP_TYPE_MASK: u8 = ACCOUNT_STEP_BIT | CURRENCY_STEP_BIT | ISSUER_STEP_BIT;
+}
 
-#[derive(Debug, Default)]
-pub struct Serializer {
+#[derive(Debug)]
+pub struct Serializer<'d> {
     /// Buffer in which fields are initially serialized. Fields are not sorted in this buffer
     buffer: Vec<u8>,
     /// Tracks which fields have been serialized to the buffer
     serialized_fields: Vec<SerializedFieldIndex>,
+    /// Definitions of the serialized fields
+    definitions: &'d Definitions,
+}
+
+impl Default for Serializer<'_> {
+    fn default() -> Self {
+        Self::with_definitions(definitions())
+    }
 }
 
-impl xrpl_types::serialize::Serializer for Serializer {
+impl<'d> xrpl_types::serialize::Serializer for Serializer<'d> {
     type Error = BinaryCodecError;
-    type SerializeArray<'a> = ArraySerializer<'a>;
+    type SerializeArray<'a>
+        = ArraySerializer<'a, 'd>
+    where
+        'd: 'a;
 
     fn serialize_account_id(
         &mut self,
@@ -134,15 +165,81 @@ impl xrpl_types::serialize::Serializer for Serializer {
             start_index,
         })
     }
+
+    fn serialize_object<T: Serialize>(
+        &mut self,
+        field_name: &str,
+        object: &T,
+    ) -> Result<(), BinaryCodecError> {
+        self.serialize_field(field_name, TypeCode::Object, |ser| {
+            ser.push_object(object)?;
+            Ok(())
+        })
+    }
+
+    fn serialize_path_set(
+        &mut self,
+        field_name: &str,
+        path_set: &[Path],
+    ) -> Result<(), BinaryCodecError> {
+        self.serialize_field(field_name, TypeCode::PathSet, |ser| {
+            ser.push_path_set(path_set)?;
+            Ok(())
+        })
+    }
+
+    fn serialize_vector256(
+        &mut self,
+        field_name: &str,
+        vector256: &[Hash256],
+    ) -> Result<(), BinaryCodecError> {
+        self.serialize_field(field_name, TypeCode::Vector256, |ser| {
+            ser.push_vector256(vector256)?;
+            Ok(())
+        })
+    }
+
+    fn serialize_issue(
+        &mut self,
+        field_name: &str,
+        issue: Currency,
+    ) -> Result<(), BinaryCodecError> {
+        self.serialize_field(field_name, TypeCode::Issue, |ser| {
+            ser.push_issue(issue)?;
+            Ok(())
+        })
+    }
+
+    fn serialize_xchain_bridge(
+        &mut self,
+        field_name: &str,
+        xchain_bridge: &XChainBridge,
+    ) -> Result<(), BinaryCodecError> {
+        self.serialize_field(field_name, TypeCode::XChainBridge, |ser| {
+            ser.push_xchain_bridge(xchain_bridge)?;
+            Ok(())
+        })
+    }
+
+    fn serialize_number(
+        &mut self,
+        field_name: &str,
+        number: Number,
+    ) -> Result<(), BinaryCodecError> {
+        self.serialize_field(field_name, TypeCode::Number, |ser| {
+            ser.push_number(number)?;
+            Ok(())
+        })
+    }
 }
 
 #[derive(Debug)]
-pub struct ArraySerializer<'a> {
-    serializer: &'a mut Serializer,
+pub struct ArraySerializer<'a, 'd> {
+    serializer: &'a mut Serializer<'d>,
     start_index: SerializeFieldStartIndex,
 }
 
-impl SerializeArray for ArraySerializer<'_> {
+impl SerializeArray for ArraySerializer<'_, '_> {
     type Error = BinaryCodecError;
 
     fn serialize_object<T: Serialize>(
@@ -150,14 +247,12 @@ impl SerializeArray for ArraySerializer<'_> {
         field_name: &str,
         object: &T,
     ) -> Result<(), Self::Error> {
-        let field_id = field_id(field_name, TypeCode::Object)?;
+        let field_id = self
+            .serializer
+            .definitions
+            .field_id(field_name, TypeCode::Object)?;
         self.serializer.push_field_id(field_id)?;
-        let mut object_serializer = Serializer::new();
-        object.serialize(&mut object_serializer)?;
-        self.serializer
-            .push_slice(&object_serializer.into_bytes()?)?;
-        self.serializer
-            .push_field_id(FieldId::from_type_field(TypeCode::Object, FieldCode(1)))?;
+        self.serializer.push_object(object)?;
         Ok(())
     }
 
@@ -203,11 +298,20 @@ impl SerializeFieldStartIndex {
     }
 }
 
-impl Serializer {
+impl Serializer<'static> {
+    /// Serializer using the built-in definitions
     pub fn new() -> Self {
+        Self::with_definitions(definitions())
+    }
+}
+
+impl<'d> Serializer<'d> {
+    /// Serializer using the given definitions, e.g. loaded with [`Definitions::from_json`]
+    pub fn with_definitions(definitions: &'d Definitions) -> Self {
         Self {
             buffer: Vec::new(),
             serialized_fields: Vec::new(),
+            definitions,
         }
     }
 
@@ -233,7 +337,7 @@ impl Serializer {
         field_name: &str,
         field_type: TypeCode,
     ) -> Result<SerializeFieldStartIndex, BinaryCodecError> {
-        let field_id = field_id(field_name, field_type)?;
+        let field_id = self.definitions.field_id(field_name, field_type)?;
         let start_index = SerializeFieldStartIndex::new(field_id, self.buffer.len());
         self.push_field_id(field_id)?;
         Ok(start_index)
@@ -248,7 +352,7 @@ impl Serializer {
         &mut self,
         field_name: &str,
         field_type: TypeCode,
-        serialize_field_data_closure: impl FnOnce(&mut Serializer) -> Result<(), BinaryCodecError>,
+        serialize_field_data_closure: impl FnOnce(&mut Self) -> Result<(), BinaryCodecError>,
     ) -> Result<(), BinaryCodecError> {
         let start_index = self.start_field(field_name, field_type)?;
         serialize_field_data_closure(self)?;
@@ -350,7 +454,7 @@ impl Serializer {
             1.. => (value.mantissa() as u64, true),
             ..=-1 => (-value.mantissa() as u64, false),
         };
-        let exponent = (value.exponent() + 97) as u64;
+        let exponent = (value.exponent() as i16 + 97) as u64;
         self.push_uint64(
             ISSUED_MASK | (if positive { POSITIVE_MASK } else { 0 }) | mantissa | (exponent << 54),
         )?;
@@ -391,22 +495,377 @@ impl Serializer {
     fn push_account_id_no_length_prefix(&mut self, id: AccountId) -> Result<(), BinaryCodecError> {
         self.push_slice(&id.0)
     }
+
+    /// Push the sorted fields of the object followed by the object end marker
+    /// <https://xrpl.org/serialization.html#object-fields>
+    fn push_object<T: Serialize>(&mut self, object: &T) -> Result<(), BinaryCodecError> {
+        let mut object_serializer = Serializer::with_definitions(self.definitions);
+        object.serialize(&mut object_serializer)?;
+        self.push_slice(&object_serializer.into_bytes()?)?;
+        self.push_field_id(FieldId::from_type_field(TypeCode::Object, FieldCode(1)))
+    }
+
+    /// <https://xrpl.org/serialization.html#pathset-fields>
+    fn push_path_set(&mut self, path_set: &[Path]) -> Result<(), BinaryCodecError> {
+        for (index, path) in path_set.iter().enumerate() {
+            if index > 0 {
+                self.push(path_set::PATH_SEPARATOR_BYTE)?;
+            }
+            for step in &path.0 {
+                let mut step_type = 0;
+                if step.account_id().is_some() {
+                    step_type |= path_set::ACCOUNT_STEP_BIT;
+                }
+                if step.currency_code().is_some() {
+                    step_type |= path_set::CURRENCY_STEP_BIT;
+                }
+                if step.issuer().is_some() {
+                    step_type |= path_set::ISSUER_STEP_BIT;
+                }
+                self.push(step_type)?;
+                if let Some(account) = step.account_id() {
+                    self.push_account_id_no_length_prefix(account)?;
+                }
+                if let Some(currency) = step.currency_code() {
+                    self.push_currency_code(currency)?;
+                }
+                if let Some(issuer) = step.issuer() {
+                    self.push_account_id_no_length_prefix(issuer)?;
+                }
+            }
+        }
+        self.push(path_set::END_BYTE)
+    }
+
+    /// <https://xrpl.org/serialization.html#vector256-fields>
+    fn push_vector256(&mut self, vector256: &[Hash256]) -> Result<(), BinaryCodecError> {
+        self.push_vl_prefix(vector256.len() * 32)?;
+        for hash in vector256 {
+            self.push_hash256(*hash)?;
+        }
+        Ok(())
+    }
+
+    /// Issue is the currency code, followed by the issuer for issued currencies
+    /// <https://xrpl.org/serialization.html#issue-fields>
+    fn push_issue(&mut self, issue: Currency) -> Result<(), BinaryCodecError> {
+        match issue {
+            Currency::Xrp => self.push_currency_code(CurrencyCode::Xrp),
+            Currency::Issued(issued) => {
+                self.push_currency_code(issued.currency_code())?;
+                self.push_account_id_no_length_prefix(issued.issuer())
+            }
+        }
+    }
+
+    /// The doors and issues of the bridge, in a fixed order without field ids
+    /// <https://xrpl.org/serialization.html#xchainbridge-fields>
+    fn push_xchain_bridge(&mut self, xchain_bridge: &XChainBridge) -> Result<(), BinaryCodecError> {
+        self.push_account_id(xchain_bridge.locking_chain_door)?;
+        self.push_issue(xchain_bridge.locking_chain_issue)?;
+        self.push_account_id(xchain_bridge.issuing_chain_door)?;
+        self.push_issue(xchain_bridge.issuing_chain_issue)
+    }
+
+    /// Signed 64 bit mantissa followed by signed 32 bit exponent, see `STNumber` in
+    /// <https://github.com/XRPLF/rippled/blob/develop/src/libxrpl/protocol/STNumber.cpp>
+    fn push_number(&mut self, number: Number) -> Result<(), BinaryCodecError> {
+        self.push_slice(&number.mantissa().to_be_bytes())?;
+        self.push_slice(&number.exponent().to_be_bytes())
+    }
+}
+
+/// Serialization of JSON objects in the format used by rippled for `tx_json` and ledger objects
+#[cfg(feature = "json")]
+impl Serializer<'_> {
+    /// Serializes the fields of the given JSON object, using the field types from [`field_info`].
+    /// Fields starting with a lower case letter, like `hash` or `index`, are added by rippled and
+    /// are not serialized. `DeliverMax`, the name of the `Amount` of payments in API v2 (and a copy
+    /// of it in API v1), is serialized as `Amount`.
+    pub fn serialize_json_object(
+        &mut self,
+        object: &Map<String, Value>,
+    ) -> Result<(), BinaryCodecError> {
+        for (field_name, value) in object {
+            if field_name.starts_with(|c: char| c.is_ascii_lowercase()) {
+                continue;
+            }
+            let field_name = match (field_name.as_str(), object.get("Amount")) {
+                ("DeliverMax", None) => "Amount",
+                ("DeliverMax", Some(amount)) if amount == value => continue,
+                ("DeliverMax", Some(_)) => {
+                    return Err(BinaryCodecError::InvalidField(
+                        "DeliverMax differs from Amount".into(),
+                    ))
+                }
+                (field_name, _) => field_name,
+            };
+            let field_info = self.definitions.field_info(field_name).ok_or_else(|| {
+                BinaryCodecError::InvalidField(format!(
+                    "Field with name {} is not known",
+                    field_name
+                ))
+            })?;
+            let field_type = field_info.field_type;
+            self.serialize_field(field_name, field_type, |ser| {
+                ser.push_json(field_name, field_type, value)
+            })?;
+        }
+        Ok(())
+    }
+
+    fn push_json(
+        &mut self,
+        field_name: &str,
+        field_type: TypeCode,
+        value: &Value,
+    ) -> Result<(), BinaryCodecError> {
+        match (field_type, field_name, value) {
+            (TypeCode::UInt8, "TransactionResult", Value::String(name)) => {
+                let code = self
+                    .definitions
+                    .transaction_result_code(name)
+                    .ok_or_else(|| {
+                        BinaryCodecError::InvalidField(format!(
+                            "Unknown transaction result {}",
+                            name
+                        ))
+                    })?;
+                self.push_uint8(code)
+            }
+            (TypeCode::UInt16, "TransactionType", Value::String(name)) => {
+                let code = self
+                    .definitions
+                    .transaction_type_code(name)
+                    .ok_or_else(|| {
+                        BinaryCodecError::InvalidField(format!("Unknown transaction type {}", name))
+                    })?;
+                self.push_uint16(code)
+            }
+            (TypeCode::UInt16, "LedgerEntryType", Value::String(name)) => {
+                let code = self
+                    .definitions
+                    .ledger_entry_type_code(name)
+                    .ok_or_else(|| {
+                        BinaryCodecError::InvalidField(format!(
+                            "Unknown ledger entry type {}",
+                            name
+                        ))
+                    })?;
+                self.push_uint16(code)
+            }
+            (TypeCode::UInt8, ..) => self.push_uint8(json_uint(field_name, value)?),
+            (TypeCode::UInt16, ..) => self.push_uint16(json_uint(field_name, value)?),
+            (TypeCode::UInt32, ..) => self.push_uint32(json_uint(field_name, value)?),
+            // rippled formats 64 bit integers as hex
+            (TypeCode::UInt64, _, Value::String(hex)) => {
+                let uint64 = Uint64::from_str_radix(hex, 16).map_err(|_| {
+                    BinaryCodecError::InvalidField(format!(
+                        "Field {} must be a hex encoded 64 bit integer",
+                        field_name
+                    ))
+                })?;
+                self.push_uint64(uint64)
+            }
+            (TypeCode::UInt64, ..) => self.push_uint64(json_uint(field_name, value)?),
+            (TypeCode::Hash128, ..) => {
+                self.push_hash128(Hash128::from_hex(json_str(field_name, value)?)?)
+            }
+            (TypeCode::Hash160, ..) => {
+                self.push_hash160(Hash160::from_hex(json_str(field_name, value)?)?)
+            }
+            (TypeCode::Hash256, ..) => {
+                self.push_hash256(Hash256::from_hex(json_str(field_name, value)?)?)
+            }
+            (TypeCode::Blob, ..) => self.push_blob(&Blob::from_hex(json_str(field_name, value)?)?),
+            (TypeCode::AccountId, ..) => self.push_account_id(json_account_id(field_name, value)?),
+            (TypeCode::Amount, ..) => self.push_amount(json_amount(field_name, value)?),
+            (TypeCode::Object, ..) => {
+                self.push_json_object(json_object(field_name, value)?)?;
+                self.push_field_id(FieldId::from_type_field(TypeCode::Object, FieldCode(1)))
+            }
+            (TypeCode::Array, _, Value::Array(elements)) => {
+                for element in elements {
+                    // Array elements are objects with a single field, like `{"Memo": {...}}`
+                    let element = json_object(field_name, element)?;
+                    let (object_field_name, object) = match (element.len(), element.iter().next()) {
+                        (1, Some(entry)) => entry,
+                        _ => {
+                            return Err(BinaryCodecError::InvalidField(format!(
+                                "Elements of field {} must be objects with a single field",
+                                field_name
+                            )))
+                        }
+                    };
+                    let field_id = self
+                        .definitions
+                        .field_id(object_field_name, TypeCode::Object)?;
+                    self.push_field_id(field_id)?;
+                    self.push_json_object(json_object(object_field_name, object)?)?;
+                    self.push_field_id(FieldId::from_type_field(TypeCode::Object, FieldCode(1)))?;
+                }
+                self.push_field_id(FieldId::from_type_field(TypeCode::Array, FieldCode(1)))
+            }
+            (TypeCode::PathSet, _, Value::Array(paths)) => {
+                self.push_path_set(&json_path_set(paths)?)
+            }
+            (TypeCode::Vector256, _, Value::Array(hashes)) => {
+                let hashes = hashes
+                    .iter()
+                    .map(|hash| Ok(Hash256::from_hex(json_str(field_name, hash)?)?))
+                    .collect::<Result<Vec<_>, BinaryCodecError>>()?;
+                self.push_vector256(&hashes)
+            }
+            (TypeCode::Issue, ..) => self.push_issue(json_issue(field_name, value)?),
+            (TypeCode::XChainBridge, ..) => {
+                let bridge = json_object(field_name, value)?;
+                let field = |name: &str| bridge.get(name).unwrap_or(&Value::Null);
+                self.push_xchain_bridge(&XChainBridge {
+                    locking_chain_door: json_account_id(
+                        "LockingChainDoor",
+                        field("LockingChainDoor"),
+                    )?,
+                    locking_chain_issue: json_issue(
+                        "LockingChainIssue",
+                        field("LockingChainIssue"),
+                    )?,
+                    issuing_chain_door: json_account_id(
+                        "IssuingChainDoor",
+                        field("IssuingChainDoor"),
+                    )?,
+                    issuing_chain_issue: json_issue(
+                        "IssuingChainIssue",
+                        field("IssuingChainIssue"),
+                    )?,
+                })
+            }
+            (TypeCode::Number, ..) => {
+                self.push_number(Number::from_str(json_str(field_name, value)?)?)
+            }
+            (TypeCode::Array | TypeCode::PathSet | TypeCode::Vector256, ..) => Err(
+                BinaryCodecError::InvalidField(format!("Field {} must be an array", field_name)),
+            ),
+        }
+    }
+
+    /// Pushes the sorted fields of the given object, without the object end marker
+    fn push_json_object(&mut self, object: &Map<String, Value>) -> Result<(), BinaryCodecError> {
+        let mut object_serializer = Serializer::with_definitions(self.definitions);
+        object_serializer.serialize_json_object(object)?;
+        self.push_slice(&object_serializer.into_bytes()?)
+    }
+}
+
+#[cfg(feature = "json")]
+fn json_str<'a>(field_name: &str, value: &'a Value) -> Result<&'a str, BinaryCodecError> {
+    value.as_str().ok_or_else(|| {
+        BinaryCodecError::InvalidField(format!("Field {} must be a string", field_name))
+    })
+}
+
+#[cfg(feature = "json")]
+fn json_account_id(field_name: &str, value: &Value) -> Result<AccountId, BinaryCodecError> {
+    Ok(AccountId::from_address(json_str(field_name, value)?)?)
+}
+
+#[cfg(feature = "json")]
+fn json_currency_code(field_name: &str, value: &Value) -> Result<CurrencyCode, BinaryCodecError> {
+    Ok(CurrencyCode::from_str(json_str(field_name, value)?)?)
+}
+
+#[cfg(feature = "json")]
+fn json_object<'a>(
+    field_name: &str,
+    value: &'a Value,
+) -> Result<&'a Map<String, Value>, BinaryCodecError> {
+    value.as_object().ok_or_else(|| {
+        BinaryCodecError::InvalidField(format!("Field {} must be an object", field_name))
+    })
+}
+
+/// Path set given as arrays of steps with `account`, `currency` and `issuer`. The `type` and
+/// `type_hex` of the steps returned by rippled are derived from the fields present.
+#[cfg(feature = "json")]
+fn json_path_set(paths: &[Value]) -> Result<Vec<Path>, BinaryCodecError> {
+    let mut path_set = Vec::with_capacity(paths.len());
+    for path in paths {
+        let steps = path.as_array().ok_or_else(|| {
+            BinaryCodecError::InvalidField("Paths must be arrays of steps".to_string())
+        })?;
+        let mut path = Vec::with_capacity(steps.len());
+        for step in steps {
+            let step = json_object("path step", step)?;
+            let account = step
+                .get("account")
+                .map(|account| json_account_id("account", account))
+                .transpose()?;
+            let currency = step
+                .get("currency")
+                .map(|currency| json_currency_code("currency", currency))
+                .transpose()?;
+            let issuer = step
+                .get("issuer")
+                .map(|issuer| json_account_id("issuer", issuer))
+                .transpose()?;
+            path.push(PathStep::new(account, currency, issuer)?);
+        }
+        path_set.push(Path(path));
+    }
+    Ok(path_set)
+}
+
+/// Issue given as an object with `currency` and, for issued currencies, `issuer`
+#[cfg(feature = "json")]
+fn json_issue(field_name: &str, value: &Value) -> Result<Currency, BinaryCodecError> {
+    let issue = json_object(field_name, value)?;
+    let currency = json_currency_code("currency", issue.get("currency").unwrap_or(&Value::Null))?;
+    match issue.get("issuer") {
+        Some(issuer) if !currency.is_xrp() => Ok(Currency::issued(
+            currency,
+            json_account_id("issuer", issuer)?,
+        )?),
+        None if currency.is_xrp() => Ok(Currency::Xrp),
+        _ => Err(BinaryCodecError::InvalidField(format!(
+            "Field {} must have an issuer if and only if the currency is not XRP",
+            field_name
+        ))),
+    }
+}
+
+/// Unsigned integer given as a JSON number or a decimal string
+#[cfg(feature = "json")]
+fn json_uint<T: TryFrom<u64>>(field_name: &str, value: &Value) -> Result<T, BinaryCodecError> {
+    let uint = match value {
+        Value::Number(number) => number.as_u64(),
+        Value::String(string) => string.parse().ok(),
+        _ => None,
+    };
+    uint.and_then(|uint| T::try_from(uint).ok()).ok_or_else(|| {
+        BinaryCodecError::InvalidField(format!(
+            "Field {} must be an unsigned integer within range",
+            field_name
+        ))
+    })
+}
+
+/// Amount given as a string of drops or an object with `currency`, `issuer` and `value`
+/// <https://xrpl.org/currency-formats.html#specifying-currency-amounts>
+#[cfg(feature = "json")]
+fn json_amount(field_name: &str, value: &Value) -> Result<Amount, BinaryCodecError> {
+    if let Value::Object(amount) = value {
+        let field = |name: &str| json_str(name, amount.get(name).unwrap_or(&Value::Null));
+        return Ok(Amount::issued(
+            IssuedValue::from_str(field("value")?)?,
+            CurrencyCode::from_str(field("currency")?)?,
+            AccountId::from_address(field("issuer")?)?,
+        )?);
+    }
+    Ok(Amount::drops(json_uint(field_name, value)?)?)
 }
 
+/// Id of the field with the given name in the built-in definitions, see [`Definitions::field_id`]
 pub fn field_id(field_name: &str, field_type: TypeCode) -> Result<FieldId, BinaryCodecError> {
-    let field_info = field_info::field_info(field_name).ok_or_else(|| {
-        BinaryCodecError::InvalidField(format!("Field with name {} is not known", field_name))
-    })?;
-    if field_type != field_info.field_type {
-        return Err(BinaryCodecError::InvalidField(format!(
-            "Field with name {} must have type {}",
-            field_name, field_info.field_type
-        )));
-    }
-    Ok(FieldId::from_type_field(
-        field_info.field_type,
-        field_info.field_code,
-    ))
+    definitions().field_id(field_name, field_type)
 }
 
 #[cfg(test)]
@@ -419,11 +878,11 @@ mod tests {
     use xrpl_types::serialize::{Serialize, Serializer};
     use xrpl_types::OfferCreateTransaction;
 
-    fn serializer() -> super::Serializer {
+    fn serializer() -> super::Serializer<'static> {
         super::Serializer::new()
     }
 
-    fn buffer(serializer: &super::Serializer) -> &[u8] {
+    fn buffer<'a>(serializer: &'a super::Serializer<'_>) -> &'a [u8] {
         &serializer.buffer
     }
 
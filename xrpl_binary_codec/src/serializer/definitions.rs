@@ -0,0 +1,383 @@
+use crate::alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use crate::error::BinaryCodecError;
+use crate::serializer::field_id::{FieldCode, FieldId, TypeCode};
+use crate::serializer::field_info::{field_info_lookup, FieldInfo};
+use crate::serializer::transaction_result::TRANSACTION_RESULTS;
+use serde_json::{Map, Value};
+use xrpl_types::{LedgerEntryType, TransactionType};
+
+#[cfg(not(feature = "std"))]
+use hashbrown::HashMap;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// Protocol definitions used for serialization: the fields, transaction types, ledger entry types
+/// and transaction results. The built-in definitions are returned by [`definitions`]. Definitions
+/// for other protocol versions or networks can be loaded with [`Definitions::from_json`].
+#[derive(Debug, Clone)]
+pub struct Definitions {
+    field_info_map: HashMap<String, FieldInfo>,
+    transaction_types: HashMap<String, u16>,
+    ledger_entry_types: HashMap<String, u16>,
+    transaction_results: HashMap<String, u8>,
+    skipped_fields: Vec<String>,
+}
+
+#[cfg(feature = "std")]
+static DEFINITIONS: std::sync::OnceLock<Definitions> = std::sync::OnceLock::new();
+
+/// The built-in definitions
+#[cfg(feature = "std")]
+pub fn definitions() -> &'static Definitions {
+    DEFINITIONS.get_or_init(Definitions::builtin)
+}
+
+#[cfg(not(feature = "std"))]
+static DEFINITIONS: spin::Once<Definitions> = spin::Once::new();
+
+/// The built-in definitions
+#[cfg(not(feature = "std"))]
+pub fn definitions() -> &'static Definitions {
+    DEFINITIONS.call_once(Definitions::builtin)
+}
+
+impl Default for Definitions {
+    fn default() -> Self {
+        definitions().clone()
+    }
+}
+
+impl Definitions {
+    /// Definitions from the field table in [`field_info`](super::field_info) and the enums in `xrpl_types`
+    fn builtin() -> Self {
+        let transaction_types = TransactionType::VALUES
+            .iter()
+            .map(|transaction_type| (format!("{:?}", transaction_type), *transaction_type as u16))
+            .collect();
+        let ledger_entry_types = LedgerEntryType::VALUES
+            .iter()
+            .map(|ledger_entry_type| {
+                (
+                    format!("{:?}", ledger_entry_type),
+                    *ledger_entry_type as u16,
+                )
+            })
+            .collect();
+        let transaction_results = TRANSACTION_RESULTS
+            .iter()
+            .map(|(name, code)| (name.to_string(), *code))
+            .collect();
+        Self {
+            field_info_map: field_info_lookup().clone(),
+            transaction_types,
+            ledger_entry_types,
+            transaction_results,
+            skipped_fields: Vec::new(),
+        }
+    }
+
+    /// Loads definitions in the format of `definitions.json` from xrpl.js
+    /// <https://github.com/XRPLF/xrpl.js/blob/main/packages/ripple-binary-codec/src/enums/definitions.json>
+    /// or the response of the rippled `server_definitions` method
+    /// <https://xrpl.org/docs/references/http-websocket-apis/public-api-methods/server-info-methods/server_definitions>.
+    /// Fields that are not serialized are skipped, as are transaction results that are not stored
+    /// in ledgers. Serialized fields with a type that is not supported by [`TypeCode`] are skipped
+    /// and listed in [`Definitions::skipped_fields`].
+    pub fn from_json(json: &Value) -> Result<Self, BinaryCodecError> {
+        let json = json.get("result").unwrap_or(json);
+
+        let types = json_object(json, "TYPES")?;
+        let mut field_info_map = HashMap::new();
+        let mut skipped_fields = Vec::new();
+        for field in json_array(json, "FIELDS")? {
+            let (name, info) = match field.as_array().map(Vec::as_slice) {
+                Some([Value::String(name), Value::Object(info)]) => (name, info),
+                _ => {
+                    return Err(BinaryCodecError::InvalidField(format!(
+                        "Invalid field definition: {}",
+                        field
+                    )))
+                }
+            };
+            if info.get("isSerialized") != Some(&Value::Bool(true)) {
+                continue;
+            }
+            let type_code = info
+                .get("type")
+                .and_then(Value::as_str)
+                .and_then(|type_name| types.get(type_name))
+                .and_then(Value::as_u64)
+                .ok_or_else(|| {
+                    BinaryCodecError::InvalidField(format!("Invalid type of field {}", name))
+                })?;
+            let field_type = match u16::try_from(type_code).map(TypeCode::try_from) {
+                Ok(Ok(field_type)) => field_type,
+                _ => {
+                    skipped_fields.push(name.clone());
+                    continue;
+                }
+            };
+            let field_code = info
+                .get("nth")
+                .and_then(Value::as_u64)
+                .and_then(|nth| u8::try_from(nth).ok())
+                .ok_or_else(|| {
+                    BinaryCodecError::InvalidField(format!("Invalid nth of field {}", name))
+                })?;
+            let is_vl_encoded = info.get("isVLEncoded") == Some(&Value::Bool(true));
+            field_info_map.insert(
+                name.clone(),
+                FieldInfo {
+                    field_type,
+                    field_code: FieldCode(field_code),
+                    is_vl_encoded,
+                },
+            );
+        }
+
+        Ok(Self {
+            field_info_map,
+            transaction_types: json_codes(json, "TRANSACTION_TYPES")?,
+            ledger_entry_types: json_codes(json, "LEDGER_ENTRY_TYPES")?,
+            transaction_results: json_codes(json, "TRANSACTION_RESULTS")?,
+            skipped_fields,
+        })
+    }
+
+    /// Serialized fields of the loaded definitions that are skipped because their type is not
+    /// supported by [`TypeCode`]. Objects with these fields cannot be encoded or decoded.
+    pub fn skipped_fields(&self) -> &[String] {
+        &self.skipped_fields
+    }
+
+    /// Fields by name
+    pub fn field_info_map(&self) -> &HashMap<String, FieldInfo> {
+        &self.field_info_map
+    }
+
+    pub fn field_info(&self, field_name: &str) -> Option<&FieldInfo> {
+        self.field_info_map.get(field_name)
+    }
+
+    /// Id of the field with the given name, which must have the given type
+    pub fn field_id(
+        &self,
+        field_name: &str,
+        field_type: TypeCode,
+    ) -> Result<FieldId, BinaryCodecError> {
+        let field_info = self.field_info(field_name).ok_or_else(|| {
+            BinaryCodecError::InvalidField(format!("Field with name {} is not known", field_name))
+        })?;
+        if field_type != field_info.field_type {
+            return Err(BinaryCodecError::InvalidField(format!(
+                "Field with name {} must have type {}",
+                field_name, field_info.field_type
+            )));
+        }
+        Ok(FieldId::from_type_field(
+            field_info.field_type,
+            field_info.field_code,
+        ))
+    }
+
+    pub fn transaction_type_code(&self, name: &str) -> Option<u16> {
+        self.transaction_types.get(name).copied()
+    }
+
+    pub fn transaction_type_name(&self, code: u16) -> Option<&str> {
+        name_of_code(&self.transaction_types, code)
+    }
+
+    pub fn ledger_entry_type_code(&self, name: &str) -> Option<u16> {
+        self.ledger_entry_types.get(name).copied()
+    }
+
+    pub fn ledger_entry_type_name(&self, code: u16) -> Option<&str> {
+        name_of_code(&self.ledger_entry_types, code)
+    }
+
+    pub fn transaction_result_code(&self, name: &str) -> Option<u8> {
+        self.transaction_results.get(name).copied()
+    }
+
+    pub fn transaction_result_name(&self, code: u8) -> Option<&str> {
+        name_of_code(&self.transaction_results, code)
+    }
+}
+
+fn name_of_code<T: PartialEq>(codes: &HashMap<String, T>, code: T) -> Option<&str> {
+    codes
+        .iter()
+        .find(|(_, entry_code)| **entry_code == code)
+        .map(|(name, _)| name.as_str())
+}
+
+fn json_object<'a>(json: &'a Value, key: &str) -> Result<&'a Map<String, Value>, BinaryCodecError> {
+    json.get(key)
+        .and_then(Value::as_object)
+        .ok_or_else(|| BinaryCodecError::FieldNotFound(format!("Definitions {}", key)))
+}
+
+fn json_array<'a>(json: &'a Value, key: &str) -> Result<&'a Vec<Value>, BinaryCodecError> {
+    json.get(key)
+        .and_then(Value::as_array)
+        .ok_or_else(|| BinaryCodecError::FieldNotFound(format!("Definitions {}", key)))
+}
+
+/// Reads names and codes, skipping codes that are out of range like the negative codes for
+/// invalid types and results that are not stored in ledgers
+fn json_codes<T: TryFrom<i64>>(
+    json: &Value,
+    key: &str,
+) -> Result<HashMap<String, T>, BinaryCodecError> {
+    let mut codes = HashMap::new();
+    for (name, code) in json_object(json, key)? {
+        let code = code.as_i64().ok_or_else(|| {
+            BinaryCodecError::InvalidField(format!("Invalid code of {} in {}", name, key))
+        })?;
+        if let Ok(code) = T::try_from(code) {
+            codes.insert(name.clone(), code);
+        }
+    }
+    Ok(codes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Excerpt of `definitions.json` with a field and transaction type that are not built in
+    const DEFINITIONS: &str = r#"{
+        "TYPES": {
+            "Done": -1,
+            "Unknown": -2,
+            "UInt16": 1,
+            "UInt32": 2,
+            "Amount": 6,
+            "AccountID": 8,
+            "UInt384": 22,
+            "Transaction": 10001
+        },
+        "LEDGER_ENTRY_TYPES": {
+            "Invalid": -1,
+            "AccountRoot": 97
+        },
+        "FIELDS": [
+            ["Generic", {"nth": 0, "isVLEncoded": false, "isSerialized": false, "isSigningField": false, "type": "Unknown"}],
+            ["Transaction", {"nth": 1, "isVLEncoded": false, "isSerialized": false, "isSigningField": false, "type": "Transaction"}],
+            ["TransactionType", {"nth": 2, "isVLEncoded": false, "isSerialized": true, "isSigningField": true, "type": "UInt16"}],
+            ["Sequence", {"nth": 4, "isVLEncoded": false, "isSerialized": true, "isSigningField": true, "type": "UInt32"}],
+            ["NewField", {"nth": 99, "isVLEncoded": false, "isSerialized": true, "isSigningField": true, "type": "UInt32"}],
+            ["Fee", {"nth": 8, "isVLEncoded": false, "isSerialized": true, "isSigningField": true, "type": "Amount"}],
+            ["Account", {"nth": 1, "isVLEncoded": true, "isSerialized": true, "isSigningField": true, "type": "AccountID"}],
+            ["NewWideField", {"nth": 1, "isVLEncoded": false, "isSerialized": true, "isSigningField": true, "type": "UInt384"}]
+        ],
+        "TRANSACTION_RESULTS": {
+            "telLOCAL_ERROR": -399,
+            "tesSUCCESS": 0,
+            "tecCLAIM": 100
+        },
+        "TRANSACTION_TYPES": {
+            "Invalid": -1,
+            "Payment": 0,
+            "NewTransaction": 99
+        }
+    }"#;
+
+    #[test]
+    fn test_from_json() {
+        let definitions =
+            Definitions::from_json(&serde_json::from_str(DEFINITIONS).unwrap()).unwrap();
+        assert_eq!(definitions.field_info_map().len(), 5);
+        assert!(definitions.field_info("Generic").is_none());
+        assert!(definitions.field_info("Transaction").is_none());
+        assert!(definitions.field_info("NewWideField").is_none());
+        assert_eq!(definitions.skipped_fields(), ["NewWideField"]);
+        let field_info = definitions.field_info("NewField").unwrap();
+        assert_eq!(field_info.field_type, TypeCode::UInt32);
+        assert_eq!(field_info.field_code, FieldCode(99));
+        assert!(definitions.field_info("Account").unwrap().is_vl_encoded);
+        assert_eq!(
+            definitions.transaction_type_code("NewTransaction"),
+            Some(99)
+        );
+        assert_eq!(definitions.transaction_type_name(0), Some("Payment"));
+        assert_eq!(definitions.transaction_type_code("Invalid"), None);
+        assert_eq!(definitions.ledger_entry_type_name(97), Some("AccountRoot"));
+        assert_eq!(definitions.transaction_result_code("tecCLAIM"), Some(100));
+        assert_eq!(definitions.transaction_result_code("telLOCAL_ERROR"), None);
+    }
+
+    /// The `server_definitions` response has the definitions in `result`
+    #[test]
+    fn test_from_server_definitions_response() {
+        let response = format!(r#"{{"result": {}}}"#, DEFINITIONS);
+        let definitions =
+            Definitions::from_json(&serde_json::from_str(&response).unwrap()).unwrap();
+        assert_eq!(
+            definitions.transaction_type_code("NewTransaction"),
+            Some(99)
+        );
+    }
+
+    #[test]
+    fn test_from_json_invalid() {
+        let result = Definitions::from_json(&serde_json::json!({"TYPES": {}}));
+        assert!(matches!(result, Err(BinaryCodecError::FieldNotFound(_))));
+        let result = Definitions::from_json(&serde_json::json!({
+            "TYPES": {},
+            "FIELDS": [["Sequence", {"nth": 4, "isSerialized": true, "type": "UInt32"}]]
+        }));
+        assert!(matches!(result, Err(BinaryCodecError::InvalidField(_))));
+    }
+
+    #[test]
+    fn test_builtin_definitions() {
+        let definitions = definitions();
+        assert_eq!(
+            definitions.field_info_map().len(),
+            field_info_lookup().len()
+        );
+        assert_eq!(definitions.transaction_type_code("Payment"), Some(0));
+        assert_eq!(definitions.transaction_type_name(7), Some("OfferCreate"));
+        assert_eq!(
+            definitions.ledger_entry_type_code("AccountRoot"),
+            Some(0x61)
+        );
+        assert_eq!(definitions.transaction_result_name(0), Some("tesSUCCESS"));
+        assert_eq!(
+            definitions.field_id("Sequence", TypeCode::UInt32).unwrap(),
+            FieldId::from_type_field(TypeCode::UInt32, FieldCode(4))
+        );
+        assert!(definitions.field_id("Sequence", TypeCode::UInt16).is_err());
+        assert!(definitions.skipped_fields().is_empty());
+
+        // Every code of the enums in `xrpl_types` is included
+        for code in 0..=u16::MAX {
+            if let Ok(transaction_type) = TransactionType::try_from(code) {
+                assert_eq!(
+                    definitions.transaction_type_code(&format!("{:?}", transaction_type)),
+                    Some(code)
+                );
+            }
+            if let Ok(ledger_entry_type) = LedgerEntryType::try_from(code) {
+                assert_eq!(
+                    definitions.ledger_entry_type_code(&format!("{:?}", ledger_entry_type)),
+                    Some(code)
+                );
+            }
+        }
+        assert_eq!(
+            definitions.transaction_types.len(),
+            TransactionType::VALUES.len()
+        );
+        assert_eq!(
+            definitions.ledger_entry_types.len(),
+            LedgerEntryType::VALUES.len()
+        );
+    }
+}
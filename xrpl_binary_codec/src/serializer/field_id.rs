@@ -1,5 +1,7 @@
 use super::field_info::FieldInfo;
+use crate::alloc::format;
 use crate::serializer::Vec;
+use crate::BinaryCodecError;
 use core::fmt;
 
 /// Field data type codes <https://xrpl.org/serialization.html#type-list>
@@ -19,6 +21,41 @@ pub enum TypeCode {
     UInt64 = 3,
     Array = 15,
     Object = 14,
+    PathSet = 18,
+    Vector256 = 19,
+    Number = 9,
+    Issue = 24,
+    XChainBridge = 25,
+}
+
+impl TryFrom<u16> for TypeCode {
+    type Error = BinaryCodecError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {
+            8 => Ok(Self::AccountId),
+            6 => Ok(Self::Amount),
+            7 => Ok(Self::Blob),
+            4 => Ok(Self::Hash128),
+            17 => Ok(Self::Hash160),
+            5 => Ok(Self::Hash256),
+            16 => Ok(Self::UInt8),
+            1 => Ok(Self::UInt16),
+            2 => Ok(Self::UInt32),
+            3 => Ok(Self::UInt64),
+            15 => Ok(Self::Array),
+            14 => Ok(Self::Object),
+            18 => Ok(Self::PathSet),
+            19 => Ok(Self::Vector256),
+            9 => Ok(Self::Number),
+            24 => Ok(Self::Issue),
+            25 => Ok(Self::XChainBridge),
+            _ => Err(BinaryCodecError::OutOfRange(format!(
+                "Unknown type code {}",
+                value
+            ))),
+        }
+    }
 }
 
 impl fmt::Display for TypeCode {
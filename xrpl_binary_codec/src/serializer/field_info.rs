@@ -48,7 +48,7 @@ macro_rules! insert_field_info {
                     field_type: TypeCode::$field_type,
                     field_code: FieldCode($field_code),
                     is_vl_encoded: match TypeCode::$field_type {
-                        TypeCode::AccountId | TypeCode::Blob => true,
+                        TypeCode::AccountId | TypeCode::Blob | TypeCode::Vector256 => true,
                         _ => false,
                     },
                 },
@@ -73,6 +73,8 @@ fn create_field_info_map() -> HashMap<String, FieldInfo> {
     insert_field_info!(map, "TransactionType", 2, UInt16);
     insert_field_info!(map, "SignerWeight", 3, UInt16);
     insert_field_info!(map, "TransferFee", 4, UInt16);
+    insert_field_info!(map, "TradingFee", 5, UInt16);
+    insert_field_info!(map, "DiscountedFee", 6, UInt16);
     insert_field_info!(map, "Version", 16, UInt16);
     insert_field_info!(map, "HookStateChangeCount", 17, UInt16);
     insert_field_info!(map, "HookEmitCount", 18, UInt16);
@@ -123,6 +125,7 @@ fn create_field_info_map() -> HashMap<String, FieldInfo> {
     insert_field_info!(map, "BurnedNFTokens", 44, UInt32);
     insert_field_info!(map, "HookStateCount", 45, UInt32);
     insert_field_info!(map, "EmitGeneration", 46, UInt32);
+    insert_field_info!(map, "VoteWeight", 48, UInt32);
     insert_field_info!(map, "IndexNext", 1, UInt64);
     insert_field_info!(map, "IndexPrevious", 2, UInt64);
     insert_field_info!(map, "BookNode", 3, UInt64);
@@ -158,6 +161,7 @@ fn create_field_info_map() -> HashMap<String, FieldInfo> {
     insert_field_info!(map, "EmitParentTxnID", 11, Hash256);
     insert_field_info!(map, "EmitNonce", 12, Hash256);
     insert_field_info!(map, "EmitHookHash", 13, Hash256);
+    insert_field_info!(map, "AMMID", 14, Hash256);
     insert_field_info!(map, "BookDirectory", 16, Hash256);
     insert_field_info!(map, "InvoiceID", 17, Hash256);
     insert_field_info!(map, "Nickname", 18, Hash256);
@@ -185,10 +189,18 @@ fn create_field_info_map() -> HashMap<String, FieldInfo> {
     insert_field_info!(map, "Fee", 8, Amount);
     insert_field_info!(map, "SendMax", 9, Amount);
     insert_field_info!(map, "DeliverMin", 10, Amount);
+    insert_field_info!(map, "Amount2", 11, Amount);
+    insert_field_info!(map, "BidMin", 12, Amount);
+    insert_field_info!(map, "BidMax", 13, Amount);
     insert_field_info!(map, "MinimumOffer", 16, Amount);
     insert_field_info!(map, "RippleEscrow", 17, Amount);
     insert_field_info!(map, "DeliveredAmount", 18, Amount);
     insert_field_info!(map, "NFTokenBrokerFee", 19, Amount);
+    insert_field_info!(map, "LPTokenOut", 25, Amount);
+    insert_field_info!(map, "LPTokenIn", 26, Amount);
+    insert_field_info!(map, "EPrice", 27, Amount);
+    insert_field_info!(map, "Price", 28, Amount);
+    insert_field_info!(map, "LPTokenBalance", 31, Amount);
     insert_field_info!(map, "PublicKey", 1, Blob);
     insert_field_info!(map, "MessageKey", 2, Blob);
     insert_field_info!(map, "SigningPubKey", 3, Blob);
@@ -223,6 +235,12 @@ fn create_field_info_map() -> HashMap<String, FieldInfo> {
     insert_field_info!(map, "NFTokenMinter", 9, AccountId);
     insert_field_info!(map, "EmitCallback", 10, AccountId);
     insert_field_info!(map, "HookAccount", 16, AccountId);
+    insert_field_info!(map, "OtherChainSource", 18, AccountId);
+    insert_field_info!(map, "OtherChainDestination", 19, AccountId);
+    insert_field_info!(map, "AttestationSignerAccount", 20, AccountId);
+    insert_field_info!(map, "AttestationRewardAccount", 21, AccountId);
+    insert_field_info!(map, "LockingChainDoor", 22, AccountId);
+    insert_field_info!(map, "IssuingChainDoor", 23, AccountId);
     insert_field_info!(map, "TransactionMetaData", 2, Object);
     insert_field_info!(map, "CreatedNode", 3, Object);
     insert_field_info!(map, "DeletedNode", 4, Object);
@@ -244,6 +262,9 @@ fn create_field_info_map() -> HashMap<String, FieldInfo> {
     insert_field_info!(map, "HookDefinition", 22, Object);
     insert_field_info!(map, "HookParameter", 23, Object);
     insert_field_info!(map, "HookGrant", 24, Object);
+    insert_field_info!(map, "VoteEntry", 25, Object);
+    insert_field_info!(map, "AuctionSlot", 26, Object);
+    insert_field_info!(map, "AuthAccount", 27, Object);
     insert_field_info!(map, "ObjectEndMarker", 1, Object);
     insert_field_info!(map, "Signers", 3, Array);
     insert_field_info!(map, "SignerEntries", 4, Array);
@@ -254,11 +275,28 @@ fn create_field_info_map() -> HashMap<String, FieldInfo> {
     insert_field_info!(map, "Memos", 9, Array);
     insert_field_info!(map, "NFTokens", 10, Array);
     insert_field_info!(map, "Hooks", 11, Array);
+    insert_field_info!(map, "VoteSlots", 12, Array);
     insert_field_info!(map, "Majorities", 16, Array);
     insert_field_info!(map, "DisabledValidators", 17, Array);
     insert_field_info!(map, "HookExecutions", 18, Array);
     insert_field_info!(map, "HookParameters", 19, Array);
     insert_field_info!(map, "HookGrants", 20, Array);
+    insert_field_info!(map, "AuthAccounts", 25, Array);
     insert_field_info!(map, "ArrayEndMarker", 1, Array);
+    insert_field_info!(map, "Paths", 1, PathSet);
+    insert_field_info!(map, "Indexes", 1, Vector256);
+    insert_field_info!(map, "Hashes", 2, Vector256);
+    insert_field_info!(map, "Amendments", 3, Vector256);
+    insert_field_info!(map, "NFTokenOffers", 4, Vector256);
+    insert_field_info!(map, "LockingChainIssue", 1, Issue);
+    insert_field_info!(map, "IssuingChainIssue", 2, Issue);
+    insert_field_info!(map, "Asset", 3, Issue);
+    insert_field_info!(map, "Asset2", 4, Issue);
+    insert_field_info!(map, "XChainBridge", 1, XChainBridge);
+    insert_field_info!(map, "Number", 1, Number);
+    insert_field_info!(map, "AssetsAvailable", 2, Number);
+    insert_field_info!(map, "AssetsMaximum", 3, Number);
+    insert_field_info!(map, "AssetsTotal", 4, Number);
+    insert_field_info!(map, "LossUnrealized", 5, Number);
     map
 }
@@ -0,0 +1,341 @@
+//! SHAMap, the radix-16 Merkle tree holding the transactions and the account state of a ledger
+//! <https://xrpl.org/ledgers.html#tree-format>. The root hashes are the `transaction_hash` and
+//! `account_hash` of the ledger header.
+
+#[cfg(feature = "json")]
+use crate::alloc::format;
+use crate::alloc::{collections::BTreeMap, vec::Vec};
+use crate::deserializer::encode_variable_length;
+use crate::hash::{
+    hash, transaction_blob_hash, HASH_PREFIX_INNER_NODE, HASH_PREFIX_LEAF_NODE,
+    HASH_PREFIX_TRANSACTION_NODE,
+};
+use crate::BinaryCodecError;
+#[cfg(feature = "json")]
+use serde_json::Value;
+use xrpl_types::Hash256;
+
+pub mod proof;
+
+/// Number of children of an inner node
+pub const BRANCH_FACTOR: usize = 16;
+
+/// SHAMap built from the hashes of its leaf nodes. The structure of the tree only depends on the
+/// keys, a leaf node is located at the shallowest depth where the prefix of its key is unique.
+#[derive(Debug, Clone, Default)]
+pub struct ShaMap {
+    leaves: BTreeMap<[u8; 32], Hash256>,
+}
+
+impl ShaMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a leaf node with the given key and hash, replacing a leaf node with the same key
+    pub fn insert(&mut self, key: Hash256, leaf_hash: Hash256) {
+        self.leaves.insert(key.0, leaf_hash);
+    }
+
+    /// Inserts a transaction with its metadata, keyed by the transaction id. Returns the
+    /// transaction id.
+    pub fn insert_transaction(
+        &mut self,
+        tx_blob: &[u8],
+        meta_blob: &[u8],
+    ) -> Result<Hash256, BinaryCodecError> {
+        let transaction_id = transaction_blob_hash(tx_blob);
+        self.insert(
+            transaction_id,
+            transaction_leaf_hash(transaction_id, tx_blob, meta_blob)?,
+        );
+        Ok(transaction_id)
+    }
+
+    /// Inserts a ledger entry with the given ledger object id, see [`crate::keylet`]
+    pub fn insert_ledger_entry(&mut self, index: Hash256, data: &[u8]) {
+        self.insert(index, ledger_entry_leaf_hash(index, data));
+    }
+
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// Hash of the root node, zero for an empty map
+    pub fn root_hash(&self) -> Hash256 {
+        if self.leaves.is_empty() {
+            return Hash256::zero();
+        }
+        subtree_hash(&self.sorted_leaves(), 0)
+    }
+
+    fn sorted_leaves(&self) -> Vec<([u8; 32], Hash256)> {
+        self.leaves
+            .iter()
+            .map(|(key, hash)| (*key, *hash))
+            .collect()
+    }
+}
+
+#[cfg(feature = "json")]
+impl ShaMap {
+    /// Inserts a transaction of an expanded `ledger` response, either in binary format with
+    /// `tx_blob` and `meta`, or in JSON format with the metadata in `metaData`. In JSON format,
+    /// the `DeliverMax` field of API v2 payments is accepted, see [`crate::json::encode`].
+    pub fn insert_transaction_json(
+        &mut self,
+        transaction: &Value,
+    ) -> Result<Hash256, BinaryCodecError> {
+        let (tx_blob, meta_blob) = match (transaction.get("tx_blob"), transaction.get("meta")) {
+            (Some(tx_blob), Some(meta)) => (json_hex(tx_blob)?, json_hex(meta)?),
+            _ => {
+                let meta = transaction.get("metaData").ok_or_else(|| {
+                    BinaryCodecError::FieldNotFound("Transaction metaData not found".into())
+                })?;
+                (
+                    crate::json::encode(transaction)?,
+                    crate::json::encode(meta)?,
+                )
+            }
+        };
+        self.insert_transaction(&tx_blob, &meta_blob)
+    }
+
+    /// Inserts a ledger entry of a binary `ledger_data` response, with `index` and `data`
+    pub fn insert_ledger_entry_json(&mut self, entry: &Value) -> Result<(), BinaryCodecError> {
+        let index = entry.get("index").and_then(Value::as_str).ok_or_else(|| {
+            BinaryCodecError::FieldNotFound("Ledger entry index not found".into())
+        })?;
+        let data = entry
+            .get("data")
+            .ok_or_else(|| BinaryCodecError::FieldNotFound("Ledger entry data not found".into()))?;
+        self.insert_ledger_entry(Hash256::from_hex(index)?, &json_hex(data)?);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "json")]
+fn json_hex(value: &Value) -> Result<Vec<u8>, BinaryCodecError> {
+    value
+        .as_str()
+        .and_then(|hex| hex::decode(hex).ok())
+        .ok_or_else(|| {
+            BinaryCodecError::InvalidField(format!("Expected hex string, found {}", value))
+        })
+}
+
+/// Nibble of the key selecting the branch of the inner node at the given depth
+fn branch(key: &[u8; 32], depth: usize) -> usize {
+    let byte = key[depth / 2];
+    if depth.is_multiple_of(2) {
+        usize::from(byte >> 4)
+    } else {
+        usize::from(byte & 0x0F)
+    }
+}
+
+/// Leaves of the SHAMap sorted by key
+type Leaves<'a> = &'a [([u8; 32], Hash256)];
+
+/// Splits the given sorted leaves of the inner node at the given depth by branch
+fn split_branches(leaves: Leaves<'_>, depth: usize) -> [Leaves<'_>; BRANCH_FACTOR] {
+    let mut branches: [Leaves<'_>; BRANCH_FACTOR] = [&[]; BRANCH_FACTOR];
+    let mut rest = leaves;
+    while let Some((key, _)) = rest.first() {
+        let child_branch = branch(key, depth);
+        let count = rest
+            .iter()
+            .take_while(|(key, _)| branch(key, depth) == child_branch)
+            .count();
+        let (child, tail) = rest.split_at(count);
+        branches[child_branch] = child;
+        rest = tail;
+    }
+    branches
+}
+
+/// Hash of the child node containing the given sorted leaves, a leaf node if there is a single
+/// leaf and an inner node at the given depth otherwise
+fn child_hash(leaves: Leaves<'_>, depth: usize) -> Hash256 {
+    match leaves {
+        [] => Hash256::zero(),
+        [(_, leaf_hash)] => *leaf_hash,
+        _ => subtree_hash(leaves, depth),
+    }
+}
+
+/// Hash of the inner node at the given depth containing the given sorted leaves
+fn subtree_hash(leaves: Leaves<'_>, depth: usize) -> Hash256 {
+    let branches = split_branches(leaves, depth);
+    let mut children = [Hash256::zero(); BRANCH_FACTOR];
+    for (child, leaves) in children.iter_mut().zip(branches) {
+        *child = child_hash(leaves, depth + 1);
+    }
+    inner_node_hash(&children)
+}
+
+/// Hash of an inner node with the given child hashes, zero for empty branches
+pub fn inner_node_hash(children: &[Hash256; BRANCH_FACTOR]) -> Hash256 {
+    if children.iter().all(|child| child.is_zero()) {
+        return Hash256::zero();
+    }
+    let mut data = Vec::with_capacity(BRANCH_FACTOR * 32);
+    for child in children {
+        data.extend_from_slice(&child.0);
+    }
+    hash(HASH_PREFIX_INNER_NODE, &data)
+}
+
+/// Hash of the leaf node of a transaction with metadata in the transaction tree
+pub fn transaction_leaf_hash(
+    transaction_id: Hash256,
+    tx_blob: &[u8],
+    meta_blob: &[u8],
+) -> Result<Hash256, BinaryCodecError> {
+    let mut data = Vec::with_capacity(tx_blob.len() + meta_blob.len() + 38);
+    data.extend(encode_variable_length(tx_blob.len())?);
+    data.extend_from_slice(tx_blob);
+    data.extend(encode_variable_length(meta_blob.len())?);
+    data.extend_from_slice(meta_blob);
+    data.extend_from_slice(&transaction_id.0);
+    Ok(hash(HASH_PREFIX_TRANSACTION_NODE, &data))
+}
+
+/// Hash of the leaf node of a ledger entry in the account state tree
+pub fn ledger_entry_leaf_hash(index: Hash256, data: &[u8]) -> Hash256 {
+    let mut leaf = Vec::with_capacity(data.len() + 32);
+    leaf.extend_from_slice(data);
+    leaf.extend_from_slice(&index.0);
+    hash(HASH_PREFIX_LEAF_NODE, &leaf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(hex: &str) -> Hash256 {
+        Hash256::from_hex(hex).unwrap()
+    }
+
+    #[test]
+    fn test_empty_root_hash() {
+        assert_eq!(ShaMap::new().root_hash(), Hash256::zero());
+    }
+
+    #[test]
+    fn test_single_leaf_root_hash() {
+        let mut map = ShaMap::new();
+        let index = key("A000000000000000000000000000000000000000000000000000000000000001");
+        map.insert_ledger_entry(index, &[1, 2, 3]);
+
+        let mut children = [Hash256::zero(); BRANCH_FACTOR];
+        children[0xA] = ledger_entry_leaf_hash(index, &[1, 2, 3]);
+        assert_eq!(map.root_hash(), inner_node_hash(&children));
+    }
+
+    #[test]
+    fn test_common_prefix_root_hash() {
+        let key1 = key("AB10000000000000000000000000000000000000000000000000000000000000");
+        let key2 = key("AB20000000000000000000000000000000000000000000000000000000000000");
+        let key3 = key("1000000000000000000000000000000000000000000000000000000000000000");
+        let leaf1 = Hash256([1; 32]);
+        let leaf2 = Hash256([2; 32]);
+        let leaf3 = Hash256([3; 32]);
+        let mut map = ShaMap::new();
+        map.insert(key1, leaf1);
+        map.insert(key2, leaf2);
+        map.insert(key3, leaf3);
+
+        // key1 and key2 share the prefix AB and split at depth 2
+        let mut depth2 = [Hash256::zero(); BRANCH_FACTOR];
+        depth2[1] = leaf1;
+        depth2[2] = leaf2;
+        let mut depth1 = [Hash256::zero(); BRANCH_FACTOR];
+        depth1[0xB] = inner_node_hash(&depth2);
+        let mut root = [Hash256::zero(); BRANCH_FACTOR];
+        root[0x1] = leaf3;
+        root[0xA] = inner_node_hash(&depth1);
+        assert_eq!(map.root_hash(), inner_node_hash(&root));
+    }
+
+    #[test]
+    fn test_root_hash_independent_of_insertion_order() {
+        let entries: Vec<_> = (0u8..50)
+            .map(|i| (crate::hash::sha512_half(&[i]), [i; 3]))
+            .collect();
+        let mut map = ShaMap::new();
+        for (index, data) in &entries {
+            map.insert_ledger_entry(*index, data);
+        }
+        let mut reversed = ShaMap::new();
+        for (index, data) in entries.iter().rev() {
+            reversed.insert_ledger_entry(*index, data);
+        }
+        assert_eq!(map.len(), 50);
+        assert_eq!(map.root_hash(), reversed.root_hash());
+    }
+
+    #[test]
+    fn test_transaction_leaf_hash() {
+        let tx_blob = [0x12, 0x00, 0x00];
+        let meta_blob = [0xE1];
+        let mut map = ShaMap::new();
+        let transaction_id = map.insert_transaction(&tx_blob, &meta_blob).unwrap();
+        assert_eq!(transaction_id, transaction_blob_hash(&tx_blob));
+
+        let mut data = vec![3, 0x12, 0x00, 0x00, 1, 0xE1];
+        data.extend_from_slice(&transaction_id.0);
+        let mut children = [Hash256::zero(); BRANCH_FACTOR];
+        children[branch(&transaction_id.0, 0)] = hash(HASH_PREFIX_TRANSACTION_NODE, &data);
+        assert_eq!(map.root_hash(), inner_node_hash(&children));
+    }
+
+    /// Binary and JSON format of a transaction in an expanded `ledger` response
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_insert_transaction_json() {
+        let fixtures: Value =
+            serde_json::from_str(include_str!("../fixtures/json-decode.json")).unwrap();
+        let payment = &fixtures[0];
+        let meta = &fixtures[2];
+        assert_eq!(meta["description"], "transaction metadata");
+
+        let mut binary = ShaMap::new();
+        let binary_id = binary
+            .insert_transaction_json(&serde_json::json!({
+                "tx_blob": payment["binary"],
+                "meta": meta["binary"],
+            }))
+            .unwrap();
+        let mut tx_json = payment["json"].clone();
+        tx_json["metaData"] = meta["json"].clone();
+        tx_json["hash"] = Value::String(binary_id.to_hex());
+        let mut json = ShaMap::new();
+        let json_id = json.insert_transaction_json(&tx_json).unwrap();
+
+        assert_eq!(json_id, binary_id);
+        assert_eq!(json.root_hash(), binary.root_hash());
+
+        // API v2 format, where the `Amount` of payments is named `DeliverMax`
+        let mut tx_json_v2 = tx_json.clone();
+        let amount = tx_json_v2
+            .as_object_mut()
+            .unwrap()
+            .remove("Amount")
+            .unwrap();
+        tx_json_v2["DeliverMax"] = amount;
+        let mut json_v2 = ShaMap::new();
+        assert_eq!(
+            json_v2.insert_transaction_json(&tx_json_v2).unwrap(),
+            binary_id
+        );
+        assert_eq!(json_v2.root_hash(), binary.root_hash());
+        assert!(ShaMap::new()
+            .insert_transaction_json(&payment["json"])
+            .is_err());
+    }
+}
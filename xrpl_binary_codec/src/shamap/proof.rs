@@ -0,0 +1,531 @@
+//! Inclusion proofs for SHAMap leaf nodes. A [`LedgerProof`] proves that a transaction or ledger
+//! entry is part of a ledger with a given ledger hash, and can be verified offline.
+
+use super::{
+    branch, child_hash, inner_node_hash, ledger_entry_leaf_hash, split_branches,
+    transaction_leaf_hash, ShaMap, BRANCH_FACTOR,
+};
+#[cfg(feature = "json")]
+use crate::alloc::string::ToString;
+use crate::alloc::{format, vec::Vec};
+use crate::hash::{ledger_hash, transaction_blob_hash};
+use crate::BinaryCodecError;
+#[cfg(feature = "json")]
+use serde_json::{Map, Value};
+use xrpl_types::{Hash256, LedgerHeader};
+
+/// Maximum number of inner nodes on the path to a leaf, one for each nibble of the 256-bit key
+const MAX_INNER_NODES: usize = 64;
+
+/// Proof that a leaf node is included in a SHAMap, the child hashes of the inner nodes on the path
+/// from the root to the leaf. The child hash on the path itself is computed during verification
+/// and is zero in the proof.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ShaMapProof {
+    /// Key of the leaf node
+    pub key: Hash256,
+    /// Child hashes of the inner nodes on the path, starting at the root
+    pub inner_nodes: Vec<[Hash256; BRANCH_FACTOR]>,
+}
+
+impl ShaMapProof {
+    /// Root hash of the SHAMap if it contains a leaf node with the given hash at the key of the
+    /// proof
+    pub fn root_hash(&self, leaf_hash: Hash256) -> Result<Hash256, BinaryCodecError> {
+        self.check_depth()?;
+        let mut hash = leaf_hash;
+        for (depth, children) in self.inner_nodes.iter().enumerate().rev() {
+            let mut children = *children;
+            children[branch(&self.key.0, depth)] = hash;
+            hash = inner_node_hash(&children);
+        }
+        Ok(hash)
+    }
+
+    /// Checks that the number of inner nodes is possible for a 256-bit key, between 1 and 64
+    pub fn check_depth(&self) -> Result<(), BinaryCodecError> {
+        if !(1..=MAX_INNER_NODES).contains(&self.inner_nodes.len()) {
+            return Err(BinaryCodecError::InvalidProof(format!(
+                "Proof has {} inner nodes, expected 1 to {}",
+                self.inner_nodes.len(),
+                MAX_INNER_NODES
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl ShaMap {
+    /// Proof of inclusion of the leaf node with the given key, `None` if the key is not in the map
+    pub fn proof(&self, key: Hash256) -> Option<ShaMapProof> {
+        let leaves = self.sorted_leaves();
+        let mut leaves = leaves.as_slice();
+        let mut inner_nodes = Vec::new();
+        for depth in 0.. {
+            let branches = split_branches(leaves, depth);
+            let mut children = [Hash256::zero(); BRANCH_FACTOR];
+            for (child, leaves) in children.iter_mut().zip(branches) {
+                *child = child_hash(leaves, depth + 1);
+            }
+            let path_branch = branch(&key.0, depth);
+            children[path_branch] = Hash256::zero();
+            inner_nodes.push(children);
+
+            leaves = branches[path_branch];
+            match leaves {
+                [] => return None,
+                [(leaf_key, _)] => {
+                    return (*leaf_key == key.0).then_some(ShaMapProof { key, inner_nodes });
+                }
+                _ => {}
+            }
+        }
+        unreachable!("keys are unique and split before depth 64")
+    }
+}
+
+/// Proven leaf node of a [`LedgerProof`]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ProofLeaf {
+    /// Transaction with metadata in the transaction tree
+    Transaction {
+        tx_blob: Vec<u8>,
+        meta_blob: Vec<u8>,
+    },
+    /// Ledger entry in the account state tree
+    LedgerEntry { index: Hash256, data: Vec<u8> },
+}
+
+impl ProofLeaf {
+    /// Key of the leaf node, the transaction id or the ledger object id
+    pub fn key(&self) -> Hash256 {
+        match self {
+            ProofLeaf::Transaction { tx_blob, .. } => transaction_blob_hash(tx_blob),
+            ProofLeaf::LedgerEntry { index, .. } => *index,
+        }
+    }
+
+    fn leaf_hash(&self) -> Result<Hash256, BinaryCodecError> {
+        match self {
+            ProofLeaf::Transaction { tx_blob, meta_blob } => {
+                transaction_leaf_hash(transaction_blob_hash(tx_blob), tx_blob, meta_blob)
+            }
+            ProofLeaf::LedgerEntry { index, data } => Ok(ledger_entry_leaf_hash(*index, data)),
+        }
+    }
+}
+
+/// Self-contained proof that a transaction or ledger entry is included in the ledger with the
+/// given header
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LedgerProof {
+    pub header: LedgerHeader,
+    pub leaf: ProofLeaf,
+    pub proof: ShaMapProof,
+}
+
+impl LedgerProof {
+    /// Creates proof that the given leaf is included in the given tree of the ledger. The tree
+    /// must be the transaction tree for transactions and the account state tree for ledger entries.
+    pub fn new(
+        header: LedgerHeader,
+        tree: &ShaMap,
+        leaf: ProofLeaf,
+    ) -> Result<Self, BinaryCodecError> {
+        let proof = tree.proof(leaf.key()).ok_or_else(|| {
+            BinaryCodecError::InvalidProof(format!(
+                "Key {} is not in the tree",
+                leaf.key().to_hex()
+            ))
+        })?;
+        let ledger_proof = Self {
+            header,
+            leaf,
+            proof,
+        };
+        ledger_proof.verify_header()?;
+        Ok(ledger_proof)
+    }
+
+    /// Verifies that the leaf is included in the ledger with the given trusted ledger hash, e.g.
+    /// the hash of a validated ledger
+    pub fn verify(&self, ledger_hash: Hash256) -> Result<(), BinaryCodecError> {
+        self.verify_header()?;
+        let header_hash = self.ledger_hash();
+        if header_hash != ledger_hash {
+            return Err(BinaryCodecError::InvalidProof(format!(
+                "Ledger hash {} does not match {}",
+                header_hash.to_hex(),
+                ledger_hash.to_hex()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Verifies that the leaf is included in the tree with the root hash in the header
+    fn verify_header(&self) -> Result<(), BinaryCodecError> {
+        if self.proof.key != self.leaf.key() {
+            return Err(BinaryCodecError::InvalidProof(format!(
+                "Proof key {} does not match leaf key {}",
+                self.proof.key.to_hex(),
+                self.leaf.key().to_hex()
+            )));
+        }
+        let root_hash = self.proof.root_hash(self.leaf.leaf_hash()?)?;
+        let expected_root_hash = match self.leaf {
+            ProofLeaf::Transaction { .. } => self.header.transaction_hash,
+            ProofLeaf::LedgerEntry { .. } => self.header.account_hash,
+        };
+        if root_hash != expected_root_hash {
+            return Err(BinaryCodecError::InvalidProof(format!(
+                "Root hash {} does not match ledger header {}",
+                root_hash.to_hex(),
+                expected_root_hash.to_hex()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Hash of the ledger the leaf is included in
+    pub fn ledger_hash(&self) -> Hash256 {
+        ledger_hash(&self.header)
+    }
+}
+
+/// Proof file format. The ledger header has the format returned by rippled for `ledger`, and the
+/// leaf has the format of the binary `ledger` (`tx_blob` and `meta`) and `ledger_data` (`index`
+/// and `data`) responses. The inner nodes only contain the non-empty child hashes not on the path,
+/// keyed by the hex digit of the branch.
+#[cfg(feature = "json")]
+impl LedgerProof {
+    pub fn to_json(&self) -> Value {
+        let header = &self.header;
+        let mut ledger = Map::new();
+        ledger.insert("account_hash".into(), header.account_hash.to_hex().into());
+        ledger.insert("close_flags".into(), header.close_flags.into());
+        ledger.insert("close_time".into(), header.close_time.into());
+        ledger.insert(
+            "close_time_resolution".into(),
+            header.close_time_resolution.into(),
+        );
+        ledger.insert("ledger_hash".into(), self.ledger_hash().to_hex().into());
+        ledger.insert(
+            "ledger_index".into(),
+            header.ledger_index.to_string().into(),
+        );
+        ledger.insert("parent_close_time".into(), header.parent_close_time.into());
+        ledger.insert("parent_hash".into(), header.parent_hash.to_hex().into());
+        ledger.insert("total_coins".into(), header.total_coins.to_string().into());
+        ledger.insert(
+            "transaction_hash".into(),
+            header.transaction_hash.to_hex().into(),
+        );
+
+        let mut json = Map::new();
+        json.insert("ledger".into(), Value::Object(ledger));
+        match &self.leaf {
+            ProofLeaf::Transaction { tx_blob, meta_blob } => {
+                json.insert("hash".into(), self.leaf.key().to_hex().into());
+                json.insert("tx_blob".into(), hex::encode_upper(tx_blob).into());
+                json.insert("meta".into(), hex::encode_upper(meta_blob).into());
+            }
+            ProofLeaf::LedgerEntry { index, data } => {
+                json.insert("index".into(), index.to_hex().into());
+                json.insert("data".into(), hex::encode_upper(data).into());
+            }
+        }
+        let inner_nodes = self
+            .proof
+            .inner_nodes
+            .iter()
+            .map(|children| {
+                let children = children
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, child)| !child.is_zero())
+                    .map(|(branch, child)| (format!("{:X}", branch), child.to_hex().into()))
+                    .collect();
+                Value::Object(children)
+            })
+            .collect();
+        json.insert("inner_nodes".into(), Value::Array(inner_nodes));
+        Value::Object(json)
+    }
+
+    pub fn from_json(json: &Value) -> Result<Self, BinaryCodecError> {
+        let ledger = json_field(json, "ledger")?;
+        let header = LedgerHeader {
+            ledger_index: json_str(ledger, "ledger_index")?
+                .parse()
+                .map_err(|_| invalid_field("ledger_index"))?,
+            total_coins: json_str(ledger, "total_coins")?
+                .parse()
+                .map_err(|_| invalid_field("total_coins"))?,
+            parent_hash: Hash256::from_hex(json_str(ledger, "parent_hash")?)?,
+            transaction_hash: Hash256::from_hex(json_str(ledger, "transaction_hash")?)?,
+            account_hash: Hash256::from_hex(json_str(ledger, "account_hash")?)?,
+            parent_close_time: json_uint(ledger, "parent_close_time")?,
+            close_time: json_uint(ledger, "close_time")?,
+            close_time_resolution: json_uint(ledger, "close_time_resolution")?,
+            close_flags: json_uint(ledger, "close_flags")?,
+        };
+
+        let leaf = if json.get("tx_blob").is_some() {
+            ProofLeaf::Transaction {
+                tx_blob: json_hex(json, "tx_blob")?,
+                meta_blob: json_hex(json, "meta")?,
+            }
+        } else {
+            ProofLeaf::LedgerEntry {
+                index: Hash256::from_hex(json_str(json, "index")?)?,
+                data: json_hex(json, "data")?,
+            }
+        };
+
+        let inner_nodes = json_field(json, "inner_nodes")?
+            .as_array()
+            .ok_or_else(|| invalid_field("inner_nodes"))?
+            .iter()
+            .map(|children_json| {
+                let children_json = children_json
+                    .as_object()
+                    .ok_or_else(|| invalid_field("inner_nodes"))?;
+                let mut children = [Hash256::zero(); BRANCH_FACTOR];
+                for (branch, child) in children_json {
+                    let branch = usize::from_str_radix(branch, 16)
+                        .ok()
+                        .filter(|branch| *branch < BRANCH_FACTOR)
+                        .ok_or_else(|| invalid_field("inner_nodes"))?;
+                    let child = child.as_str().ok_or_else(|| invalid_field("inner_nodes"))?;
+                    children[branch] = Hash256::from_hex(child)?;
+                }
+                Ok(children)
+            })
+            .collect::<Result<_, BinaryCodecError>>()?;
+
+        let proof = ShaMapProof {
+            key: leaf.key(),
+            inner_nodes,
+        };
+        proof.check_depth()?;
+        Ok(Self {
+            header,
+            proof,
+            leaf,
+        })
+    }
+}
+
+#[cfg(feature = "json")]
+fn invalid_field(field_name: &str) -> BinaryCodecError {
+    BinaryCodecError::InvalidField(format!("Invalid proof field {}", field_name))
+}
+
+#[cfg(feature = "json")]
+fn json_field<'a>(json: &'a Value, field_name: &str) -> Result<&'a Value, BinaryCodecError> {
+    json.get(field_name)
+        .ok_or_else(|| BinaryCodecError::FieldNotFound(format!("Proof field {}", field_name)))
+}
+
+#[cfg(feature = "json")]
+fn json_str<'a>(json: &'a Value, field_name: &str) -> Result<&'a str, BinaryCodecError> {
+    json_field(json, field_name)?
+        .as_str()
+        .ok_or_else(|| invalid_field(field_name))
+}
+
+#[cfg(feature = "json")]
+fn json_hex(json: &Value, field_name: &str) -> Result<Vec<u8>, BinaryCodecError> {
+    hex::decode(json_str(json, field_name)?).map_err(|_| invalid_field(field_name))
+}
+
+#[cfg(feature = "json")]
+fn json_uint<T: TryFrom<u64>>(json: &Value, field_name: &str) -> Result<T, BinaryCodecError> {
+    json_field(json, field_name)?
+        .as_u64()
+        .and_then(|uint| T::try_from(uint).ok())
+        .ok_or_else(|| invalid_field(field_name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+
+    fn ledger_entries() -> Vec<(Hash256, Vec<u8>)> {
+        (0u8..40)
+            .map(|i| (crate::hash::sha512_half(&[i]), vec![i; 10]))
+            .collect()
+    }
+
+    fn state_tree() -> ShaMap {
+        let mut tree = ShaMap::new();
+        for (index, data) in ledger_entries() {
+            tree.insert_ledger_entry(index, &data);
+        }
+        tree
+    }
+
+    fn transaction_tree() -> ShaMap {
+        let mut tree = ShaMap::new();
+        for i in 0u8..5 {
+            tree.insert_transaction(&[0x12, 0x00, i], &[0xE1, i])
+                .unwrap();
+        }
+        tree
+    }
+
+    fn header(transaction_tree: &ShaMap, state_tree: &ShaMap) -> LedgerHeader {
+        LedgerHeader {
+            ledger_index: 15202439,
+            total_coins: 99998831688050493,
+            parent_hash: Hash256([1; 32]),
+            transaction_hash: transaction_tree.root_hash(),
+            account_hash: state_tree.root_hash(),
+            parent_close_time: 492656460,
+            close_time: 492656470,
+            close_time_resolution: 10,
+            close_flags: 0,
+        }
+    }
+
+    #[test]
+    fn test_proof_root_hash() {
+        let tree = state_tree();
+        for (index, data) in ledger_entries() {
+            let proof = tree.proof(index).unwrap();
+            assert_eq!(
+                proof
+                    .root_hash(ledger_entry_leaf_hash(index, &data))
+                    .unwrap(),
+                tree.root_hash()
+            );
+            assert_ne!(
+                proof
+                    .root_hash(ledger_entry_leaf_hash(index, &[0xFF]))
+                    .unwrap(),
+                tree.root_hash()
+            );
+        }
+        assert_eq!(tree.proof(Hash256([0xAB; 32])), None);
+    }
+
+    #[test]
+    fn test_proof_single_leaf() {
+        let mut tree = ShaMap::new();
+        let index = Hash256([0x5A; 32]);
+        tree.insert_ledger_entry(index, &[1]);
+        let proof = tree.proof(index).unwrap();
+        assert_eq!(proof.inner_nodes.len(), 1);
+        assert_eq!(
+            proof
+                .root_hash(ledger_entry_leaf_hash(index, &[1]))
+                .unwrap(),
+            tree.root_hash()
+        );
+
+        let mut too_deep = proof;
+        too_deep.inner_nodes = vec![[Hash256::zero(); BRANCH_FACTOR]; MAX_INNER_NODES + 1];
+        let result = too_deep.root_hash(ledger_entry_leaf_hash(index, &[1]));
+        assert_matches!(result, Err(BinaryCodecError::InvalidProof(message)) => {
+            assert!(message.contains("65 inner nodes"), "message: {}", message);
+        });
+    }
+
+    #[test]
+    fn test_ledger_proof_transaction() {
+        let transaction_tree = transaction_tree();
+        let header = header(&transaction_tree, &state_tree());
+        let leaf = ProofLeaf::Transaction {
+            tx_blob: vec![0x12, 0x00, 3],
+            meta_blob: vec![0xE1, 3],
+        };
+        let proof = LedgerProof::new(header, &transaction_tree, leaf).unwrap();
+        proof.verify(ledger_hash(&header)).unwrap();
+
+        let result = proof.verify(Hash256([2; 32]));
+        assert_matches!(result, Err(BinaryCodecError::InvalidProof(message)) => {
+            assert!(message.contains("Ledger hash"), "message: {}", message);
+        });
+
+        let mut tampered = proof.clone();
+        tampered.leaf = ProofLeaf::Transaction {
+            tx_blob: vec![0x12, 0x00, 3],
+            meta_blob: vec![0xE1, 4],
+        };
+        let result = tampered.verify(ledger_hash(&header));
+        assert_matches!(result, Err(BinaryCodecError::InvalidProof(message)) => {
+            assert!(message.contains("Root hash"), "message: {}", message);
+        });
+    }
+
+    #[test]
+    fn test_ledger_proof_ledger_entry() {
+        let state_tree = state_tree();
+        let header = header(&transaction_tree(), &state_tree);
+        let (index, data) = ledger_entries().remove(7);
+        let leaf = ProofLeaf::LedgerEntry { index, data };
+        let proof = LedgerProof::new(header, &state_tree, leaf.clone()).unwrap();
+        proof.verify(ledger_hash(&header)).unwrap();
+
+        // ledger entry is not in the transaction tree
+        let result = LedgerProof::new(header, &transaction_tree(), leaf);
+        assert_matches!(result, Err(BinaryCodecError::InvalidProof(_)));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_ledger_proof_json() {
+        let transaction_tree = transaction_tree();
+        let state_tree = state_tree();
+        let header = header(&transaction_tree, &state_tree);
+        let leaves = [
+            ProofLeaf::Transaction {
+                tx_blob: vec![0x12, 0x00, 1],
+                meta_blob: vec![0xE1, 1],
+            },
+            ProofLeaf::LedgerEntry {
+                index: ledger_entries()[0].0,
+                data: ledger_entries()[0].1.clone(),
+            },
+        ];
+        for (leaf, tree) in leaves.into_iter().zip([&transaction_tree, &state_tree]) {
+            let proof = LedgerProof::new(header, tree, leaf).unwrap();
+            let json = proof.to_json();
+            assert_eq!(json["ledger"]["ledger_hash"], ledger_hash(&header).to_hex());
+
+            let file = serde_json::to_string_pretty(&json).unwrap();
+            let parsed = LedgerProof::from_json(&serde_json::from_str(&file).unwrap()).unwrap();
+            assert_eq!(parsed, proof);
+            parsed.verify(ledger_hash(&header)).unwrap();
+        }
+
+        assert!(LedgerProof::from_json(&serde_json::json!({})).is_err());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_ledger_proof_json_depth() {
+        let state_tree = state_tree();
+        let header = header(&transaction_tree(), &state_tree);
+        let (index, data) = ledger_entries().remove(0);
+        let proof =
+            LedgerProof::new(header, &state_tree, ProofLeaf::LedgerEntry { index, data }).unwrap();
+
+        for inner_nodes in [0, MAX_INNER_NODES + 1] {
+            let mut json = proof.to_json();
+            json["inner_nodes"] = Value::Array(vec![serde_json::json!({}); inner_nodes]);
+            let result = LedgerProof::from_json(&json);
+            assert_matches!(result, Err(BinaryCodecError::InvalidProof(message)) => {
+                assert!(message.contains("inner nodes"), "message: {}", message);
+            });
+
+            let mut tampered = proof.clone();
+            tampered.proof.inner_nodes = vec![[Hash256::zero(); BRANCH_FACTOR]; inner_nodes];
+            let result = tampered.verify(ledger_hash(&header));
+            assert_matches!(result, Err(BinaryCodecError::InvalidProof(_)));
+            let result = tampered.proof.root_hash(Hash256::zero());
+            assert_matches!(result, Err(BinaryCodecError::InvalidProof(_)));
+        }
+    }
+}
@@ -1,6 +1,18 @@
+use crate::alloc::{format, string::ToString, vec::Vec};
+use crate::deserializer::Deserializer;
+use crate::keys::{
+    account_id_from_public_key, KeyPair, Secp256k1KeyPair, ED25519_PUBLIC_KEY_PREFIX,
+};
+use crate::serializer::field_info::field_info_lookup;
 use crate::{hash, serialize, BinaryCodecError};
+use ed25519_dalek::Signer as _;
 use libsecp256k1::{Message, PublicKey, SecretKey};
-use xrpl_types::{Blob, Transaction};
+use xrpl_types::{AccountId, Blob, DropsAmount, Hash256, Signer, Transaction};
+
+#[cfg(feature = "json")]
+use crate::json;
+#[cfg(feature = "json")]
+use serde_json::Value;
 
 /// Sign given transaction with secp256k1 <https://xrpl.org/cryptographic-keys.html#signing-algorithms>
 pub fn sign_transaction<T: Transaction>(
@@ -8,31 +20,301 @@ pub fn sign_transaction<T: Transaction>(
     public_key: &PublicKey,
     secret_key: &SecretKey,
 ) -> Result<(), BinaryCodecError> {
-    transaction.common_mut().signing_pub_key =
-        Some(Blob(public_key.serialize_compressed().to_vec()));
+    let key_pair = KeyPair::Secp256k1(Secp256k1KeyPair {
+        public_key: *public_key,
+        secret_key: *secret_key,
+    });
+    sign_transaction_with_key_pair(transaction, &key_pair)
+}
+
+/// Sign given transaction with secp256k1 or Ed25519, depending on the key pair
+/// <https://xrpl.org/cryptographic-keys.html#signing-algorithms>
+pub fn sign_transaction_with_key_pair<T: Transaction>(
+    transaction: &mut T,
+    key_pair: &KeyPair,
+) -> Result<(), BinaryCodecError> {
+    transaction.common_mut().signing_pub_key = Some(key_pair.public_key());
     let serialized = serialize::serialize(transaction)?;
     let signature = signature(
         hash::HASH_PREFIX_UNSIGNED_TRANSACTION_SINGLE,
         &serialized,
-        secret_key,
+        key_pair,
     );
     transaction.common_mut().txn_signature = Some(signature);
     Ok(())
 }
 
-/// Calculate secp256k1 signature <https://xrpl.org/cryptographic-keys.html#signing-algorithms>
-fn signature(prefix: [u8; 4], data: &[u8], secret_key: &SecretKey) -> Blob {
-    let hash = hash::hash(prefix, data);
-    let message = Message::parse(&hash.0);
-    let (signature, _) = libsecp256k1::sign(&message, secret_key);
-    Blob(signature.serialize_der().as_ref().to_vec())
+/// Sign the given transaction JSON (`tx_json`) with secp256k1 or Ed25519, depending on the key pair.
+/// Sets `SigningPubKey` and `TxnSignature`, the signed transaction is encoded with [`json::encode`].
+/// This allows signing transaction types that are not modelled in `xrpl_types`.
+#[cfg(feature = "json")]
+pub fn sign_json_transaction(
+    tx_json: &mut Value,
+    key_pair: &KeyPair,
+) -> Result<(), BinaryCodecError> {
+    let object = tx_json.as_object_mut().ok_or_else(|| {
+        BinaryCodecError::InvalidField("Transaction JSON must be an object".to_string())
+    })?;
+    object.insert(
+        "SigningPubKey".to_string(),
+        Value::String(key_pair.public_key().to_hex()),
+    );
+    let serialized = json::encode_for_signing(tx_json)?;
+    let signature = signature(
+        hash::HASH_PREFIX_UNSIGNED_TRANSACTION_SINGLE,
+        &serialized,
+        key_pair,
+    );
+    if let Value::Object(object) = tx_json {
+        object.insert(
+            "TxnSignature".to_string(),
+            Value::String(signature.to_hex()),
+        );
+    }
+    Ok(())
+}
+
+/// Multi-sign given transaction on behalf of the given signer account <https://xrpl.org/multi-signing.html>.
+/// The key pair is the master or regular key pair of the signer account. Sets an empty `SigningPubKey`
+/// on the transaction, as required for multi-signed transactions. The returned signer is combined
+/// with the signatures of the other signers with [`combine_signers`].
+pub fn sign_for<T: Transaction>(
+    transaction: &mut T,
+    account: AccountId,
+    key_pair: &KeyPair,
+) -> Result<Signer, BinaryCodecError> {
+    let common = transaction.common_mut();
+    common.signing_pub_key = Some(Blob(Vec::new()));
+    common.txn_signature = None;
+
+    let data = multi_signing_data(transaction, account)?;
+    let signature = signature(
+        hash::HASH_PREFIX_UNSIGNED_TRANSACTION_MULTI,
+        &data,
+        key_pair,
+    );
+
+    Ok(Signer {
+        account,
+        txn_signature: signature,
+        signing_pub_key: key_pair.public_key(),
+    })
+}
+
+/// The data signed by the given signer of a multi-signed transaction: the transaction without
+/// `Signers`, followed by the signer account id
+fn multi_signing_data<T: Transaction>(
+    transaction: &mut T,
+    account: AccountId,
+) -> Result<Vec<u8>, BinaryCodecError> {
+    // The signatures of the other signers are not part of the signed data
+    let signers = core::mem::take(&mut transaction.common_mut().signers);
+    let serialized = serialize::serialize(transaction);
+    transaction.common_mut().signers = signers;
+
+    let mut data = serialized?;
+    data.extend_from_slice(&account.0);
+    Ok(data)
+}
+
+/// Add the given signers to the multi-signed transaction. The `Signers` array is kept sorted by
+/// account id, as required by <https://xrpl.org/transaction-common-fields.html#signers-field>.
+pub fn combine_signers<T: Transaction>(
+    transaction: &mut T,
+    signers: impl IntoIterator<Item = Signer>,
+) -> Result<(), BinaryCodecError> {
+    let common = transaction.common_mut();
+    common.signers.extend(signers);
+    common.signers.sort_by_key(|signer| signer.account.0);
+
+    if let Some(signers) = common
+        .signers
+        .windows(2)
+        .find(|signers| signers[0].account == signers[1].account)
+    {
+        return Err(BinaryCodecError::InvalidSignature(format!(
+            "duplicate signer {}",
+            signers[0].account.to_address()
+        )));
+    }
+
+    common.signing_pub_key = Some(Blob(Vec::new()));
+    common.txn_signature = None;
+    Ok(())
+}
+
+/// Sign a payment channel claim, authorizing the destination to redeem the given total amount
+/// of XRP from the channel. Produces the same signature as
+/// <https://xrpl.org/channel_authorize.html>, without sending the secret to a server.
+pub fn sign_payment_channel_claim(
+    channel: &Hash256,
+    amount: DropsAmount,
+    key_pair: &KeyPair,
+) -> Blob {
+    signature(
+        hash::HASH_PREFIX_PAYMENT_CHANNEL_CLAIM,
+        &payment_channel_claim_data(channel, amount),
+        key_pair,
+    )
+}
+
+/// Verify a payment channel claim signature by the given public key, the `PublicKey` of the
+/// channel, see <https://xrpl.org/channel_verify.html>
+pub fn verify_payment_channel_claim(
+    channel: &Hash256,
+    amount: DropsAmount,
+    public_key: &[u8],
+    signature: &[u8],
+) -> Result<(), BinaryCodecError> {
+    verify_signature(
+        hash::HASH_PREFIX_PAYMENT_CHANNEL_CLAIM,
+        &payment_channel_claim_data(channel, amount),
+        public_key,
+        signature,
+    )
+}
+
+/// The signed data of a claim is the channel id followed by the amount in drops as a
+/// big-endian 64-bit integer
+fn payment_channel_claim_data(channel: &Hash256, amount: DropsAmount) -> [u8; 40] {
+    let mut data = [0u8; 40];
+    data[..32].copy_from_slice(&channel.0);
+    data[32..].copy_from_slice(&amount.drops().to_be_bytes());
+    data
+}
+
+/// Calculate signature <https://xrpl.org/cryptographic-keys.html#signing-algorithms>.
+/// secp256k1 signs the SHA-512Half of the prefixed data and produces a DER encoded signature,
+/// Ed25519 signs the prefixed data itself.
+fn signature(prefix: [u8; 4], data: &[u8], key_pair: &KeyPair) -> Blob {
+    match key_pair {
+        KeyPair::Secp256k1(key_pair) => {
+            let hash = hash::hash(prefix, data);
+            let message = Message::parse(&hash.0);
+            let (signature, _) = libsecp256k1::sign(&message, &key_pair.secret_key);
+            Blob(signature.serialize_der().as_ref().to_vec())
+        }
+        KeyPair::Ed25519(key_pair) => {
+            let mut message = Vec::with_capacity(prefix.len() + data.len());
+            message.extend_from_slice(&prefix);
+            message.extend_from_slice(data);
+            Blob(key_pair.signing_key.sign(&message).to_bytes().to_vec())
+        }
+    }
+}
+
+/// Verify the signature of given single signed transaction. Checks that `TxnSignature` is a valid
+/// signature of the transaction by `SigningPubKey`, and that `SigningPubKey` belongs to `Account`
+/// or to the given regular key of the account.
+pub fn verify_transaction<T: Transaction>(
+    transaction: &T,
+    regular_key: Option<&AccountId>,
+) -> Result<(), BinaryCodecError> {
+    let blob = serialize::serialize(transaction)?;
+    verify_transaction_blob(&blob, regular_key)
+}
+
+/// Verify the signature of given serialized single signed transaction, see [`verify_transaction`]
+pub fn verify_transaction_blob(
+    blob: &[u8],
+    regular_key: Option<&AccountId>,
+) -> Result<(), BinaryCodecError> {
+    let mut deserializer = Deserializer::new(blob.to_vec(), field_info_lookup());
+
+    let mut account = None;
+    let mut signing_pub_key = None;
+    let mut txn_signature = None;
+    // The transaction blob without the `TxnSignature` field
+    let mut unsigned = Vec::with_capacity(blob.len());
+
+    while !deserializer.end() {
+        let start = blob.len() - deserializer.remaining();
+        let field = deserializer.read_field()?;
+        let value = deserializer.read_field_value(&field.info)?;
+        let end = blob.len() - deserializer.remaining();
+
+        match field.name.as_str() {
+            "Account" => account = Some(value),
+            "SigningPubKey" => signing_pub_key = Some(value),
+            "TxnSignature" => {
+                txn_signature = Some(value);
+                continue;
+            }
+            _ => {}
+        }
+        unsigned.extend_from_slice(&blob[start..end]);
+    }
+
+    let account = account.ok_or_else(|| BinaryCodecError::FieldNotFound("Account".into()))?;
+    let signing_pub_key =
+        signing_pub_key.ok_or_else(|| BinaryCodecError::FieldNotFound("SigningPubKey".into()))?;
+    let txn_signature =
+        txn_signature.ok_or_else(|| BinaryCodecError::FieldNotFound("TxnSignature".into()))?;
+
+    if signing_pub_key.is_empty() {
+        return Err(BinaryCodecError::InvalidSignature(
+            "transaction is not single signed".to_string(),
+        ));
+    }
+
+    let key_account_id = account_id_from_public_key(&signing_pub_key);
+    if key_account_id.0[..] != account[..] && Some(&key_account_id) != regular_key {
+        return Err(BinaryCodecError::InvalidSignature(
+            "signing public key does not belong to the account or its regular key".to_string(),
+        ));
+    }
+
+    verify_signature(
+        hash::HASH_PREFIX_UNSIGNED_TRANSACTION_SINGLE,
+        &unsigned,
+        &signing_pub_key,
+        &txn_signature,
+    )
+}
+
+/// Verify secp256k1 or Ed25519 signature, depending on the public key
+/// <https://xrpl.org/cryptographic-keys.html#signing-algorithms>
+fn verify_signature(
+    prefix: [u8; 4],
+    data: &[u8],
+    public_key: &[u8],
+    signature: &[u8],
+) -> Result<(), BinaryCodecError> {
+    let valid = if public_key.first() == Some(&ED25519_PUBLIC_KEY_PREFIX) {
+        let public_key = ed25519_dalek::VerifyingKey::try_from(&public_key[1..])
+            .map_err(|err| BinaryCodecError::InvalidKey(err.to_string()))?;
+        let signature = ed25519_dalek::Signature::from_slice(signature)
+            .map_err(|err| BinaryCodecError::InvalidSignature(err.to_string()))?;
+        let mut message = Vec::with_capacity(prefix.len() + data.len());
+        message.extend_from_slice(&prefix);
+        message.extend_from_slice(data);
+        public_key.verify_strict(&message, &signature).is_ok()
+    } else {
+        let public_key = PublicKey::parse_slice(public_key, None)
+            .map_err(|err| BinaryCodecError::InvalidKey(err.to_string()))?;
+        let signature = libsecp256k1::Signature::parse_der(signature)
+            .map_err(|err| BinaryCodecError::InvalidSignature(err.to_string()))?;
+        let hash = hash::hash(prefix, data);
+        libsecp256k1::verify(&Message::parse(&hash.0), &signature, &public_key)
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(BinaryCodecError::InvalidSignature(
+            "signature verification failed".to_string(),
+        ))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use assert_matches::assert_matches;
     use enumflags2::BitFlags;
-    use xrpl_types::{AccountId, Amount, DropsAmount, PaymentTransaction};
+    use xrpl_types::{
+        Amount, DropsAmount, OfferCancelTransaction, PaymentTransaction, TrustSetTransaction,
+    };
 
     #[test]
     fn test_sign_transaction() {
@@ -65,4 +347,283 @@ mod tests {
 
         assert_eq!(tx_hex, "120000228000000024011769EB201B01176A3F6140000000014FB18068400000000000000C7321037D37332B158AC75D7BA8E7EF1F3F4C7C0FA7B4BD8818B9C03545D3AED40BB3A974463044022059E8475EF21F380A0A8FF70FF976F53DFB2EEAADD98860F642BF4004A008BEF7022014279499218DD1460B753135AEAED5A63935ACE5975869C3204886B1F346569E811471CFCE39CE9B97E7E519AF8B282DDBE140A278748314F667B0CA50CC7709A220B0561B85E53A48461FA8");
     }
+
+    #[test]
+    fn test_sign_transaction_ed25519() {
+        let key_pair = KeyPair::from_seed("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r").unwrap();
+
+        let mut tx = PaymentTransaction::new(
+            key_pair.account_id(),
+            Amount::drops(22_000_000).unwrap(),
+            AccountId::from_address("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe").unwrap(),
+        );
+        tx.common.fee = Some(DropsAmount::from_drops(12).unwrap());
+        tx.common.sequence = Some(1);
+
+        sign_transaction_with_key_pair(&mut tx, &key_pair).unwrap();
+
+        let signing_pub_key = tx.common.signing_pub_key.clone().unwrap();
+        assert_eq!(
+            hex::encode_upper(&signing_pub_key.0),
+            "ED01FA53FA5A7E77798F882ECE20B1ABC00BB358A9E55A202D0D0676BD0CE37A63"
+        );
+
+        // Ed25519 signatures are 64 bytes and computed over the prefixed transaction without signature
+        let txn_signature = tx.common.txn_signature.take().unwrap();
+        assert_eq!(txn_signature.0.len(), 64);
+
+        let mut message = hash::HASH_PREFIX_UNSIGNED_TRANSACTION_SINGLE.to_vec();
+        message.extend(serialize::serialize(&tx).unwrap());
+        let verifying_key =
+            ed25519_dalek::VerifyingKey::from_bytes(signing_pub_key.0[1..].try_into().unwrap())
+                .unwrap();
+        let signature = ed25519_dalek::Signature::from_slice(&txn_signature.0).unwrap();
+        verifying_key.verify_strict(&message, &signature).unwrap();
+    }
+
+    const SIGNED_PAYMENT: &str = "120000228000000024011769EB201B01176A3F6140000000014FB18068400000000000000C7321037D37332B158AC75D7BA8E7EF1F3F4C7C0FA7B4BD8818B9C03545D3AED40BB3A974463044022059E8475EF21F380A0A8FF70FF976F53DFB2EEAADD98860F642BF4004A008BEF7022014279499218DD1460B753135AEAED5A63935ACE5975869C3204886B1F346569E811471CFCE39CE9B97E7E519AF8B282DDBE140A278748314F667B0CA50CC7709A220B0561B85E53A48461FA8";
+
+    #[test]
+    fn test_verify_transaction_blob() {
+        let blob = hex::decode(SIGNED_PAYMENT).unwrap();
+        verify_transaction_blob(&blob, None).unwrap();
+    }
+
+    #[test]
+    fn test_verify_transaction_blob_tampered() {
+        let mut blob = hex::decode(SIGNED_PAYMENT).unwrap();
+        // Change the amount from 22 to 23 XRP
+        let amount_index = SIGNED_PAYMENT.find("6140000000014FB180").unwrap() / 2;
+        blob[amount_index + 8] = 0x5F;
+
+        let result = verify_transaction_blob(&blob, None);
+        assert_matches!(result, Err(BinaryCodecError::InvalidSignature(message)) => {
+            assert!(message.contains("verification failed"), "message: {message}");
+        });
+    }
+
+    #[test]
+    fn test_verify_transaction_ed25519() {
+        let key_pair = KeyPair::from_seed("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r").unwrap();
+
+        let mut tx = OfferCancelTransaction::new(key_pair.account_id(), 5);
+        tx.common.fee = Some(DropsAmount::from_drops(12).unwrap());
+        tx.common.sequence = Some(6);
+
+        sign_transaction_with_key_pair(&mut tx, &key_pair).unwrap();
+        verify_transaction(&tx, None).unwrap();
+
+        tx.offer_sequence = 4;
+        let result = verify_transaction(&tx, None);
+        assert_matches!(result, Err(BinaryCodecError::InvalidSignature(_)));
+    }
+
+    #[test]
+    fn test_verify_transaction_regular_key() {
+        let account = AccountId::from_address("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe").unwrap();
+        let regular_key_pair = KeyPair::from_seed("snoPBrXtMeMyMHUVTgbuqAfg1SUTb").unwrap();
+
+        let mut tx = OfferCancelTransaction::new(account, 5);
+        tx.common.fee = Some(DropsAmount::from_drops(12).unwrap());
+        tx.common.sequence = Some(6);
+
+        sign_transaction_with_key_pair(&mut tx, &regular_key_pair).unwrap();
+
+        let result = verify_transaction(&tx, None);
+        assert_matches!(result, Err(BinaryCodecError::InvalidSignature(message)) => {
+            assert!(message.contains("does not belong to the account"), "message: {message}");
+        });
+
+        verify_transaction(&tx, Some(&regular_key_pair.account_id())).unwrap();
+    }
+
+    #[test]
+    fn test_verify_transaction_unsigned() {
+        let tx = OfferCancelTransaction::new(
+            AccountId::from_address("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe").unwrap(),
+            5,
+        );
+        let result = verify_transaction(&tx, None);
+        assert_matches!(result, Err(BinaryCodecError::FieldNotFound(_)));
+    }
+
+    #[test]
+    fn test_multi_sign_transaction() {
+        let account = AccountId::from_address("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe").unwrap();
+        let key_pair1 = KeyPair::from_seed("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r").unwrap();
+        let key_pair2 = KeyPair::from_seed("snoPBrXtMeMyMHUVTgbuqAfg1SUTb").unwrap();
+
+        let mut tx = OfferCancelTransaction::new(account, 5);
+        tx.common.fee = Some(DropsAmount::from_drops(36).unwrap());
+        tx.common.sequence = Some(6);
+
+        // Each signer signs independently, in any order
+        let signer1 = sign_for(&mut tx.clone(), key_pair1.account_id(), &key_pair1).unwrap();
+        let signer2 = sign_for(&mut tx.clone(), key_pair2.account_id(), &key_pair2).unwrap();
+
+        combine_signers(&mut tx, [signer1.clone(), signer2.clone()]).unwrap();
+
+        // rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh sorts before rLUEXYuLiQptky37CqLcm9USQpPiz5rkpD
+        assert_eq!(tx.common.signers[0].account, key_pair2.account_id());
+        assert_eq!(tx.common.signers[1].account, key_pair1.account_id());
+
+        assert!(tx.common.txn_signature.is_none());
+        let tx_hex = hex::encode_upper(serialize::serialize(&tx).unwrap());
+        // Empty `SigningPubKey` followed by `Account` and the `Signers` array
+        assert!(
+            tx_hex.contains("73008114F667B0CA50CC7709A220B0561B85E53A48461FA8F3E010"),
+            "tx: {tx_hex}"
+        );
+
+        // The signatures are over the transaction without `Signers`, followed by the signer account id
+        let mut unsigned = tx.clone();
+        unsigned.common.signers.clear();
+        for signer in [&signer1, &signer2] {
+            let mut data = serialize::serialize(&unsigned).unwrap();
+            data.extend_from_slice(&signer.account.0);
+            verify_signature(
+                hash::HASH_PREFIX_UNSIGNED_TRANSACTION_MULTI,
+                &data,
+                &signer.signing_pub_key.0,
+                &signer.txn_signature.0,
+            )
+            .unwrap();
+        }
+    }
+
+    /// Multi-signed `TrustSet` of the `submit_multisigned` example, see
+    /// <https://xrpl.org/submit_multisigned.html>
+    const MULTI_SIGNED_TRUST_SET: &str = "1200142200040000240000000263D5038D7EA4C680000000000000000000000000005553440000000000B5F762798A53D543A014CAF8B297CFF8F2F937E868400000000000753073008114A3780F5CB5A44D366520FC44055E8ED44D9A2270F3E010732102B3EC4E5DD96029A647CFA20DA07FE1F85296505552CCAC114087E66B46BD77DF744730450221009C195DBBF7967E223D8626CA19CF02073667F2B22E206727BFE848FF42BEAC8A022048C323B0BED19A988BDBEFA974B6DE8AA9DCAE250AA82BBD1221787032A864E58114204288D2E47F8EF6C99BCC457966320D12409711E1E0107321028FFB276505F9AC3F57E8D5242B386A597EF6C40A7999F37F1948636FD484E25B744630440220680BBD745004E9CFB6B13A137F505FB92298AD309071D16C7B982825188FD1AE022004200B1F7E4A6A84BB0E4FC09E1E3BA2B66EBD32F0E6D121A34BA3B04AD99BC181147908A7F0EDD48EA896C3580A399F0EE78611C8E3E1F1";
+
+    #[test]
+    fn test_multi_signed_transaction_submit_multisigned() {
+        let blob = hex::decode(MULTI_SIGNED_TRUST_SET).unwrap();
+        assert_eq!(
+            hash::transaction_blob_hash(&blob).to_hex(),
+            "BD636194C48FD7A100DE4C972336534C8E710FD008C0F3CF7BC5BF34DAF3C3E6"
+        );
+
+        let mut tx: TrustSetTransaction = crate::deserialize::deserialize(&blob).unwrap();
+        let signers = tx.common.signers.clone();
+        assert_eq!(signers.len(), 2);
+        for signer in &signers {
+            let data = multi_signing_data(&mut tx, signer.account).unwrap();
+            verify_signature(
+                hash::HASH_PREFIX_UNSIGNED_TRANSACTION_MULTI,
+                &data,
+                &signer.signing_pub_key.0,
+                &signer.txn_signature.0,
+            )
+            .unwrap();
+        }
+
+        // Combining the signers in reverse order restores the canonical order
+        tx.common.signers.clear();
+        combine_signers(&mut tx, signers.into_iter().rev()).unwrap();
+        assert_eq!(serialize::serialize(&tx).unwrap(), blob);
+    }
+
+    #[test]
+    fn test_combine_signers_duplicate() {
+        let account = AccountId::from_address("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe").unwrap();
+        let key_pair = KeyPair::from_seed("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r").unwrap();
+
+        let mut tx = OfferCancelTransaction::new(account, 5);
+        let signer = sign_for(&mut tx, key_pair.account_id(), &key_pair).unwrap();
+
+        let result = combine_signers(&mut tx, [signer.clone(), signer]);
+        assert_matches!(result, Err(BinaryCodecError::InvalidSignature(message)) => {
+            assert!(message.contains("duplicate signer"), "message: {message}");
+        });
+    }
+
+    const CHANNEL: &str = "5DB01B7FFED6B67E6B0414DED11E051D2EE2B7619CE0EAA6286D67A3A4D5BDB3";
+
+    #[test]
+    fn test_sign_payment_channel_claim() {
+        let channel = Hash256::from_hex(CHANNEL).unwrap();
+        let amount = DropsAmount::from_drops(1_000_000).unwrap();
+
+        for seed in [
+            "snoPBrXtMeMyMHUVTgbuqAfg1SUTb",
+            "sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r",
+        ] {
+            let key_pair = KeyPair::from_seed(seed).unwrap();
+            let signature = sign_payment_channel_claim(&channel, amount, &key_pair);
+            verify_payment_channel_claim(&channel, amount, &key_pair.public_key().0, &signature.0)
+                .unwrap();
+
+            let result = verify_payment_channel_claim(
+                &channel,
+                DropsAmount::from_drops(1_000_001).unwrap(),
+                &key_pair.public_key().0,
+                &signature.0,
+            );
+            assert_matches!(result, Err(BinaryCodecError::InvalidSignature(_)));
+        }
+    }
+
+    /// Claim of the `channel_authorize` and `channel_verify` examples, see
+    /// <https://xrpl.org/channel_authorize.html> and <https://xrpl.org/channel_verify.html>
+    #[test]
+    fn test_verify_payment_channel_claim_channel_verify() {
+        let channel = Hash256::from_hex(CHANNEL).unwrap();
+        let public_key = xrpl_address_codec::decode_account_public_key(
+            "aB44YfzW24VDEJQ2UuLPV2PvqcPCSoLnL7y5M1EzhdW4LnK5xMS3",
+        )
+        .unwrap();
+        let signature = hex::decode(
+            "304402204EF0AFB78AC23ED1C472E74F4299C0C21F1B21D07EFC0A3838A420F76D783A40\
+             0220154FB11B6F54320666E4C36CA7F686C16A3A0456800BBC43746F34AF50290064",
+        )
+        .unwrap();
+
+        let amount = DropsAmount::from_drops(1_000_000).unwrap();
+        verify_payment_channel_claim(&channel, amount, &public_key, &signature).unwrap();
+
+        let amount = DropsAmount::from_drops(1_000_001).unwrap();
+        let result = verify_payment_channel_claim(&channel, amount, &public_key, &signature);
+        assert_matches!(result, Err(BinaryCodecError::InvalidSignature(_)));
+    }
+
+    #[test]
+    fn test_sign_payment_channel_claim_data() {
+        // The claim is signed over `CLM\0`, the channel id and the amount as 64-bit big-endian
+        let key_pair = KeyPair::from_seed("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r").unwrap();
+        let channel = Hash256::from_hex(CHANNEL).unwrap();
+        let signature = sign_payment_channel_claim(
+            &channel,
+            DropsAmount::from_drops(0x0102).unwrap(),
+            &key_pair,
+        );
+
+        let message = hex::decode(format!("434C4D00{}0000000000000102", CHANNEL)).unwrap();
+        let verifying_key = ed25519_dalek::VerifyingKey::from_bytes(
+            key_pair.public_key().0[1..].try_into().unwrap(),
+        )
+        .unwrap();
+        let signature = ed25519_dalek::Signature::from_slice(&signature.0).unwrap();
+        verifying_key.verify_strict(&message, &signature).unwrap();
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_sign_json_transaction() {
+        let key_pair = KeyPair::from_seed("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r").unwrap();
+        let mut tx = PaymentTransaction::new(
+            account_id_from_public_key(&key_pair.public_key().0),
+            Amount::drops(22_000_000).unwrap(),
+            AccountId::from_address("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe").unwrap(),
+        );
+        tx.common.fee = Some(DropsAmount::from_drops(12).unwrap());
+        tx.common.sequence = Some(18311659);
+        let mut tx_json = json::decode(&serialize::serialize(&tx).unwrap()).unwrap();
+
+        sign_transaction_with_key_pair(&mut tx, &key_pair).unwrap();
+        sign_json_transaction(&mut tx_json, &key_pair).unwrap();
+
+        let blob = json::encode(&tx_json).unwrap();
+        assert_eq!(blob, serialize::serialize(&tx).unwrap());
+        verify_transaction_blob(&blob, None).unwrap();
+    }
 }
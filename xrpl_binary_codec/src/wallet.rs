@@ -0,0 +1,187 @@
+use crate::alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use crate::keys::KeyPair;
+use crate::{hash, serialize, sign, BinaryCodecError};
+use core::fmt;
+use xrpl_address_codec::{encode_seed, encode_x_address, KeyType, Network, SEED_LENGTH};
+use xrpl_types::{AccountId, Blob, Hash256, Transaction, UInt32};
+
+/// A signed and serialized transaction, ready to be submitted
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SignedTransaction {
+    /// The serialized transaction
+    pub blob: Vec<u8>,
+    /// The hash (transaction id) of the transaction
+    pub hash: Hash256,
+}
+
+impl SignedTransaction {
+    /// The serialized transaction in hex, as expected by <https://xrpl.org/submit.html>
+    pub fn blob_hex(&self) -> String {
+        hex::encode_upper(&self.blob)
+    }
+}
+
+/// A key pair with the account it signs for, see <https://xrpl.org/cryptographic-keys.html>
+#[derive(Clone)]
+pub struct Wallet {
+    seed: Option<[u8; SEED_LENGTH]>,
+    key_pair: KeyPair,
+    account_id: AccountId,
+}
+
+/// Shows the address and public key only, the seed and secret key are redacted
+impl fmt::Debug for Wallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Wallet")
+            .field("address", &self.classic_address())
+            .field("public_key", &self.public_key())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Wallet {
+    /// Generates a wallet from a new random seed
+    #[cfg(feature = "std")]
+    pub fn generate(key_type: KeyType) -> Result<Self, BinaryCodecError> {
+        let mut seed = [0u8; SEED_LENGTH];
+        getrandom::getrandom(&mut seed).map_err(|err| {
+            BinaryCodecError::InvalidKey(std::format!("failed to generate random seed: {}", err))
+        })?;
+        Ok(Self::from_seed_bytes(&seed, key_type))
+    }
+
+    /// Restores the wallet from the given base58 encoded seed (`s...` or `sEd...`)
+    pub fn from_seed(seed: &str) -> Result<Self, BinaryCodecError> {
+        let (seed, key_type) = xrpl_address_codec::decode_seed(seed)
+            .map_err(|err| BinaryCodecError::InvalidKey(err.to_string()))?;
+        Ok(Self::from_seed_bytes(&seed, key_type))
+    }
+
+    /// Restores the wallet from the given seed entropy
+    pub fn from_seed_bytes(seed: &[u8; SEED_LENGTH], key_type: KeyType) -> Self {
+        let key_pair = KeyPair::from_seed_bytes(seed, key_type);
+        Self {
+            seed: Some(*seed),
+            account_id: key_pair.account_id(),
+            key_pair,
+        }
+    }
+
+    /// Creates a wallet from a key pair, without a seed
+    pub fn from_key_pair(key_pair: KeyPair) -> Self {
+        Self {
+            seed: None,
+            account_id: key_pair.account_id(),
+            key_pair,
+        }
+    }
+
+    /// The base58 encoded seed, if the wallet was created from a seed
+    pub fn seed(&self) -> Option<String> {
+        self.seed
+            .as_ref()
+            .map(|seed| encode_seed(seed, self.key_pair.key_type()))
+    }
+
+    pub fn key_type(&self) -> KeyType {
+        self.key_pair.key_type()
+    }
+
+    pub fn key_pair(&self) -> &KeyPair {
+        &self.key_pair
+    }
+
+    /// The 33 byte public key
+    pub fn public_key(&self) -> Blob {
+        self.key_pair.public_key()
+    }
+
+    /// The account id of the public key
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// The classic address (`r...`) of the account
+    pub fn classic_address(&self) -> String {
+        self.account_id.to_address()
+    }
+
+    /// The X-address of the account, with an optional tag
+    pub fn x_address(&self, tag: Option<UInt32>, network: Network) -> String {
+        encode_x_address(&self.account_id, tag, network)
+    }
+
+    /// Signs the given transaction and returns it serialized along with its hash
+    pub fn sign<T: Transaction>(
+        &self,
+        transaction: &mut T,
+    ) -> Result<SignedTransaction, BinaryCodecError> {
+        sign::sign_transaction_with_key_pair(transaction, &self.key_pair)?;
+        let blob = serialize::serialize(transaction)?;
+        let hash = hash::transaction_blob_hash(&blob);
+        Ok(SignedTransaction { blob, hash })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use xrpl_types::{DropsAmount, OfferCancelTransaction};
+
+    #[test]
+    fn test_wallet_from_seed() {
+        let wallet = Wallet::from_seed("snoPBrXtMeMyMHUVTgbuqAfg1SUTb").unwrap();
+        assert_eq!(wallet.key_type(), KeyType::Secp256k1);
+        assert_eq!(
+            wallet.classic_address(),
+            "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"
+        );
+        assert_eq!(
+            wallet.seed().as_deref(),
+            Some("snoPBrXtMeMyMHUVTgbuqAfg1SUTb")
+        );
+        assert!(wallet.x_address(None, Network::Mainnet).starts_with('X'));
+        assert!(wallet.x_address(Some(1), Network::Testnet).starts_with('T'));
+    }
+
+    #[test]
+    fn test_wallet_debug_redacts_seed() {
+        let wallet = Wallet::from_seed("snoPBrXtMeMyMHUVTgbuqAfg1SUTb").unwrap();
+        assert_eq!(
+            format!("{:?}", wallet),
+            "Wallet { address: \"rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh\", public_key: \
+             0330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD020, .. }"
+        );
+    }
+
+    #[test]
+    fn test_wallet_generate() {
+        let wallet = Wallet::generate(KeyType::Ed25519).unwrap();
+        assert_eq!(wallet.public_key().0[0], 0xED);
+
+        let restored = Wallet::from_seed(&wallet.seed().unwrap()).unwrap();
+        assert_eq!(restored.account_id(), wallet.account_id());
+        assert_ne!(
+            Wallet::generate(KeyType::Ed25519).unwrap().account_id(),
+            wallet.account_id()
+        );
+    }
+
+    #[test]
+    fn test_wallet_sign() {
+        let wallet = Wallet::from_seed("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r").unwrap();
+
+        let mut tx = OfferCancelTransaction::new(wallet.account_id(), 5);
+        tx.common.fee = Some(DropsAmount::from_drops(12).unwrap());
+        tx.common.sequence = Some(6);
+
+        let signed = wallet.sign(&mut tx).unwrap();
+
+        assert_eq!(signed.blob, serialize::serialize(&tx).unwrap());
+        assert_eq!(signed.hash, hash::transaction_hash(&tx).unwrap());
+        sign::verify_transaction_blob(&signed.blob, None).unwrap();
+    }
+}
@@ -2,3 +2,45 @@ pub mod balances;
 pub mod info;
 pub mod offers;
 pub mod trustlines;
+
+use clap::ArgMatches;
+use libsecp256k1::{PublicKey, SecretKey};
+use xrpl_binary_codec::keys::{Ed25519KeyPair, Secp256k1KeyPair, ED25519_PUBLIC_KEY_PREFIX};
+use xrpl_binary_codec::wallet::Wallet;
+
+/// Resolves the signing wallet of the account, either restored from the `--seed` option
+/// or from the hex encoded `--public-key` and `--secret-key` options.
+pub fn signing_wallet(account_matches: &ArgMatches) -> anyhow::Result<Wallet> {
+    if let Some(seed) = account_matches.get_one::<String>("SEED") {
+        return Ok(Wallet::from_seed(seed)?);
+    }
+
+    let public_key: &String = account_matches
+        .get_one("PUBLIC_KEY")
+        .expect("missing public key");
+    let secret_key: &String = account_matches
+        .get_one("SECRET_KEY")
+        .expect("missing secret key");
+
+    // #insight
+    // The secret/private key is 32 bytes, the public key is 33 bytes.
+
+    let secret_key = hex::decode(secret_key)?;
+    let public_key = hex::decode(public_key)?;
+
+    if public_key.first() == Some(&ED25519_PUBLIC_KEY_PREFIX) {
+        let key_pair = Ed25519KeyPair::from_secret_key_bytes(secret_key.as_slice().try_into()?);
+        anyhow::ensure!(
+            key_pair.public_key().as_slice() == public_key,
+            "public key does not match secret key"
+        );
+        return Ok(Wallet::from_key_pair(key_pair.into()));
+    }
+
+    let key_pair = Secp256k1KeyPair {
+        public_key: PublicKey::parse_compressed(public_key.as_slice().try_into()?)?,
+        secret_key: SecretKey::parse_slice(&secret_key)?,
+    };
+
+    Ok(Wallet::from_key_pair(key_pair.into()))
+}
@@ -8,6 +8,7 @@ use clap::ArgMatches;
 use self::create_offer::create_offer;
 use self::list_offers::list_offers;
 use self::remove_offer::remove_offer;
+use super::signing_wallet;
 
 pub async fn account_offers(
     account_matches: &ArgMatches,
@@ -18,23 +19,13 @@ pub async fn account_offers(
         .expect("missing account id");
 
     if let Some(create_offer_matches) = offers_matches.subcommand_matches("create") {
-        let public_key: &String = account_matches
-            .get_one("PUBLIC_KEY")
-            .expect("missing public key");
-        let secret_key: &String = account_matches
-            .get_one("SECRET_KEY")
-            .expect("missing secret key");
+        let wallet = signing_wallet(account_matches)?;
 
-        create_offer(account, public_key, secret_key, create_offer_matches).await?;
+        create_offer(account, &wallet, create_offer_matches).await?;
     } else if let Some(remove_offer_matches) = offers_matches.subcommand_matches("remove") {
-        let public_key: &String = account_matches
-            .get_one("PUBLIC_KEY")
-            .expect("missing public key");
-        let secret_key: &String = account_matches
-            .get_one("SECRET_KEY")
-            .expect("missing secret key");
+        let wallet = signing_wallet(account_matches)?;
 
-        remove_offer(account, public_key, secret_key, remove_offer_matches).await?;
+        remove_offer(account, &wallet, remove_offer_matches).await?;
     } else if let Some(list_offers_matches) = offers_matches.subcommand_matches("list") {
         list_offers(account, list_offers_matches).await?;
     }
@@ -1,18 +1,16 @@
 use clap::ArgMatches;
-use libsecp256k1::{PublicKey, SecretKey};
 
-use xrpl_binary_codec::{serialize, sign};
+use xrpl_binary_codec::wallet::Wallet;
 use xrpl_http_client::{Client, SubmitRequest};
 use xrpl_types::{AccountId, OfferCreateTransaction, Transaction};
 
 use crate::fmt::amount_from_str;
 
-// xrpl account <ADDRESS> --public-key="..." --secret-key="..." offers create --taker-pays="5.0 USD:rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq" --taker-gets="1.0 XRP"
+// xrpl account <ADDRESS> --seed="..." offers create --taker-pays="5.0 USD:rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq" --taker-gets="1.0 XRP"
 
 pub async fn create_offer(
     account: impl AsRef<str>,
-    public_key: impl AsRef<str>,
-    secret_key: impl AsRef<str>,
+    wallet: &Wallet,
     matches: &ArgMatches,
 ) -> anyhow::Result<()> {
     let account = account.as_ref();
@@ -31,21 +29,17 @@ pub async fn create_offer(
 
     client.prepare_transaction(tx.common_mut()).await?;
 
-    // #insight
-    // The secret/private key is 32 bytes, the public key is 33 bytes.
+    let signed = wallet.sign(&mut tx)?;
 
-    let secret_key = SecretKey::parse_slice(&hex::decode(secret_key.as_ref())?)?;
-    let public_key =
-        PublicKey::parse_compressed(&hex::decode(public_key.as_ref())?.as_slice().try_into()?)?;
+    println!("Transaction hash: {}", signed.hash.to_hex());
 
-    sign::sign_transaction(&mut tx, &public_key, &secret_key)?;
-
-    let tx_blob = serialize::serialize(&tx)?;
-
-    let req = SubmitRequest::new(hex::encode(&tx_blob));
+    let req = SubmitRequest::new(signed.blob_hex());
     let resp = client.call(req).await?;
 
-    println!("{resp:?}");
+    println!(
+        "Result: {:?}, {}",
+        resp.engine_result, resp.engine_result_message
+    );
 
     Ok(())
 }
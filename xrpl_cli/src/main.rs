@@ -43,6 +43,14 @@ async fn main() -> anyhow::Result<()> {
                 .required(false)
                 .action(ArgAction::Set),
         )
+        .arg(
+            Arg::new("SEED")
+                .help("The seed of the account (secp256k1 or Ed25519), used instead of the key pair")
+                .long("seed")
+                .required(false)
+                .conflicts_with_all(["PUBLIC_KEY", "SECRET_KEY"])
+                .action(ArgAction::Set),
+        )
         .subcommand(
             Command::new("info")
                 .about("info")
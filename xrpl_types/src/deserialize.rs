@@ -0,0 +1,17 @@
+mod deserializer;
+
+pub use deserializer::*;
+
+use crate::alloc::format;
+use crate::Error;
+
+/// Deserializes XRPL objects from a [`Deserializer`]
+pub trait Deserialize: Sized {
+    /// Deserialize the object
+    fn deserialize<D: Deserializer>(deserializer: &mut D) -> Result<Self, D::Error>;
+}
+
+/// Returns the value of a required field, or an error if the field is not present
+pub fn required<T>(field_name: &str, value: Option<T>) -> Result<T, Error> {
+    value.ok_or_else(|| Error::InvalidData(format!("Required field {} is missing", field_name)))
+}
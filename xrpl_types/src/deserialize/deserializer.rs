@@ -0,0 +1,67 @@
+use crate::alloc::vec::Vec;
+use crate::deserialize::Deserialize;
+use crate::{
+    AccountId, Amount, Blob, Currency, Error, Hash128, Hash160, Hash256, Number, Path, UInt16,
+    UInt32, UInt8, Uint64, XChainBridge,
+};
+use core::fmt;
+
+/// Deserialize for XRPL types and objects. Fields are looked up by name, and `None`
+/// is returned for fields not present in the deserialized object.
+pub trait Deserializer {
+    type Error: fmt::Debug + fmt::Display + From<Error>;
+
+    fn deserialize_account_id(
+        &mut self,
+        field_name: &str,
+    ) -> Result<Option<AccountId>, Self::Error>;
+
+    fn deserialize_amount(&mut self, field_name: &str) -> Result<Option<Amount>, Self::Error>;
+
+    fn deserialize_blob(&mut self, field_name: &str) -> Result<Option<Blob>, Self::Error>;
+
+    fn deserialize_hash128(&mut self, field_name: &str) -> Result<Option<Hash128>, Self::Error>;
+
+    fn deserialize_hash160(&mut self, field_name: &str) -> Result<Option<Hash160>, Self::Error>;
+
+    fn deserialize_hash256(&mut self, field_name: &str) -> Result<Option<Hash256>, Self::Error>;
+
+    fn deserialize_uint8(&mut self, field_name: &str) -> Result<Option<UInt8>, Self::Error>;
+
+    fn deserialize_uint16(&mut self, field_name: &str) -> Result<Option<UInt16>, Self::Error>;
+
+    fn deserialize_uint32(&mut self, field_name: &str) -> Result<Option<UInt32>, Self::Error>;
+
+    fn deserialize_uint64(&mut self, field_name: &str) -> Result<Option<Uint64>, Self::Error>;
+
+    /// Deserializes the array with the given name, with elements that are objects with the
+    /// given object field name. Returns an empty vector if the array is not present.
+    fn deserialize_array<T: Deserialize>(
+        &mut self,
+        field_name: &str,
+        object_field_name: &str,
+    ) -> Result<Vec<T>, Self::Error>;
+
+    /// Deserializes a nested object field
+    fn deserialize_object<T: Deserialize>(
+        &mut self,
+        field_name: &str,
+    ) -> Result<Option<T>, Self::Error>;
+
+    fn deserialize_path_set(&mut self, field_name: &str) -> Result<Option<Vec<Path>>, Self::Error>;
+
+    fn deserialize_vector256(
+        &mut self,
+        field_name: &str,
+    ) -> Result<Option<Vec<Hash256>>, Self::Error>;
+
+    /// Deserializes an `Issue` field, the currency without an amount
+    fn deserialize_issue(&mut self, field_name: &str) -> Result<Option<Currency>, Self::Error>;
+
+    fn deserialize_xchain_bridge(
+        &mut self,
+        field_name: &str,
+    ) -> Result<Option<XChainBridge>, Self::Error>;
+
+    fn deserialize_number(&mut self, field_name: &str) -> Result<Option<Number>, Self::Error>;
+}
@@ -4,6 +4,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 extern crate alloc;
 
+pub mod deserialize;
 mod error;
 pub mod serialize;
 /// Types in internal canonical binary format <https://xrpl.org/serialization.html#type-list>
@@ -1,5 +1,8 @@
 use crate::serialize::Serialize;
-use crate::{AccountId, Amount, Blob, Hash128, Hash160, Hash256, UInt16, UInt32, UInt8, Uint64};
+use crate::{
+    AccountId, Amount, Blob, Currency, Hash128, Hash160, Hash256, Number, Path, UInt16, UInt32,
+    UInt8, Uint64, XChainBridge,
+};
 use core::fmt;
 
 /// Serialize for XRPL types and objects
@@ -37,6 +40,36 @@ pub trait Serializer {
         &mut self,
         field_name: &str,
     ) -> Result<Self::SerializeArray<'_>, Self::Error>;
+
+    /// Serializes a nested object field
+    fn serialize_object<T: Serialize>(
+        &mut self,
+        field_name: &str,
+        object: &T,
+    ) -> Result<(), Self::Error>;
+
+    fn serialize_path_set(
+        &mut self,
+        field_name: &str,
+        path_set: &[Path],
+    ) -> Result<(), Self::Error>;
+
+    fn serialize_vector256(
+        &mut self,
+        field_name: &str,
+        vector256: &[Hash256],
+    ) -> Result<(), Self::Error>;
+
+    /// Serializes an `Issue` field, the currency without an amount
+    fn serialize_issue(&mut self, field_name: &str, issue: Currency) -> Result<(), Self::Error>;
+
+    fn serialize_xchain_bridge(
+        &mut self,
+        field_name: &str,
+        xchain_bridge: &XChainBridge,
+    ) -> Result<(), Self::Error>;
+
+    fn serialize_number(&mut self, field_name: &str, number: Number) -> Result<(), Self::Error>;
 }
 
 pub trait SerializeArray {
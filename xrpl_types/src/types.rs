@@ -1,13 +1,25 @@
 mod amount;
 mod currency;
 mod currency_code;
+mod ledger_entry_type;
+mod ledger_header;
 mod ledger_timestamp;
+mod nftoken_id;
+mod number;
+mod path;
 mod primitive;
 mod transaction;
+mod xchain_bridge;
 
 pub use amount::*;
 pub use currency::*;
 pub use currency_code::*;
+pub use ledger_entry_type::*;
+pub use ledger_header::*;
 pub use ledger_timestamp::*;
+pub use nftoken_id::*;
+pub use number::*;
+pub use path::*;
 pub use primitive::*;
 pub use transaction::*;
+pub use xchain_bridge::*;
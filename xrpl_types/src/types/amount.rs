@@ -1,6 +1,8 @@
 use crate::alloc::{format, string::ToString};
 use crate::{AccountId, CurrencyCode, Error};
-use core::fmt::Debug;
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
+use core::str::FromStr;
 
 /// Amount of XRP or issued token. See <https://xrpl.org/currency-formats.html#specifying-currency-amounts>
 /// and <https://xrpl.org/serialization.html#amount-fields>
@@ -186,6 +188,114 @@ impl IssuedValue {
     }
 }
 
+/// Formats the value like rippled does in JSON, see `STAmount::getText` in
+/// <https://github.com/XRPLF/rippled/blob/develop/src/libxrpl/protocol/STAmount.cpp>.
+/// Values with an exponent outside of `-25..=-5` use scientific notation.
+impl Display for IssuedValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_decimal(f, self.mantissa, self.exponent as i32)
+    }
+}
+
+/// Parses a decimal string like `"-1.5"` or `"1e-3"`, as used for the `value` of issued amounts in
+/// JSON. Digits beyond the precision of the mantissa are truncated.
+impl FromStr for IssuedValue {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (mantissa, exponent) = parse_decimal(s)
+            .ok_or_else(|| Error::InvalidData(format!("Invalid issued value: {}", s)))?;
+        if mantissa == 0 || exponent < i8::MIN as i32 {
+            return Ok(Self::zero());
+        }
+        let exponent = i8::try_from(exponent).map_err(|_| {
+            Error::OutOfRange(format!("Issued value too big to be normalized: {}", s))
+        })?;
+        Self::from_mantissa_exponent(mantissa, exponent)
+    }
+}
+
+/// Formats a decimal number with the given mantissa and exponent like rippled, see [`IssuedValue`]
+pub(crate) fn fmt_decimal(f: &mut Formatter<'_>, mantissa: i64, exponent: i32) -> fmt::Result {
+    if mantissa == 0 {
+        return f.write_str("0");
+    }
+    if mantissa < 0 {
+        f.write_str("-")?;
+    }
+    let mantissa = mantissa.unsigned_abs();
+    if exponent != 0 && !(-25..=-5).contains(&exponent) {
+        return write!(f, "{}e{}", mantissa, exponent);
+    }
+
+    let digits = mantissa.to_string();
+    let point = digits.len() as i32 + exponent;
+    let (integer, fraction) = if point > 0 {
+        let (integer, fraction) = digits.split_at(point as usize);
+        (integer, fraction.to_string())
+    } else {
+        ("0", "0".repeat(-point as usize) + &digits)
+    };
+    f.write_str(integer)?;
+    let fraction = fraction.trim_end_matches('0');
+    if !fraction.is_empty() {
+        write!(f, ".{}", fraction)?;
+    }
+    Ok(())
+}
+
+/// Parses a decimal string to a signed mantissa and an exponent. Non-zero mantissas are scaled to
+/// 18 digits so that they can be normalized without losing precision, further digits are
+/// truncated. Returns `None` if the string is not a decimal number.
+pub(crate) fn parse_decimal(s: &str) -> Option<(i64, i32)> {
+    // rippled implementation: https://github.com/XRPLF/rippled/blob/develop/src/libxrpl/protocol/STAmount.cpp (amountFromString)
+
+    const MANTISSA_DIGITS_MAX: i64 = 100_000_000_000_000_000;
+
+    let (negative, unsigned) = match s.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let (decimal, exponent) = match unsigned.find(['e', 'E']) {
+        Some(index) => (
+            &unsigned[..index],
+            unsigned[index + 1..].parse::<i32>().ok()?,
+        ),
+        None => (unsigned, 0),
+    };
+    let (integer, fraction) = decimal.split_once('.').unwrap_or((decimal, ""));
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+
+    let mut mantissa: i64 = 0;
+    let mut exponent = exponent;
+    for (digit, is_fraction) in integer
+        .chars()
+        .map(|c| (c, false))
+        .chain(fraction.chars().map(|c| (c, true)))
+    {
+        let digit = digit.to_digit(10)? as i64;
+        if mantissa < MANTISSA_DIGITS_MAX {
+            mantissa = mantissa * 10 + digit;
+            if is_fraction {
+                exponent = exponent.saturating_sub(1);
+            }
+        } else if !is_fraction {
+            exponent = exponent.saturating_add(1);
+        }
+    }
+
+    if mantissa == 0 {
+        return Some((0, 0));
+    }
+    while mantissa < MANTISSA_DIGITS_MAX {
+        mantissa *= 10;
+        exponent = exponent.saturating_sub(1);
+    }
+    Some((if negative { -mantissa } else { mantissa }, exponent))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -394,6 +504,24 @@ mod test {
         });
     }
 
+    #[test]
+    fn test_issued_value_display() {
+        let display = |mantissa, exponent| {
+            IssuedValue::from_mantissa_exponent(mantissa, exponent)
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(display(0, 0), "0");
+        assert_eq!(display(1, 0), "1");
+        assert_eq!(display(-15, -1), "-1.5");
+        assert_eq!(display(123456, -3), "123.456");
+        assert_eq!(display(1, -10), "0.0000000001");
+        assert_eq!(display(1_234_567_890_123_456, 0), "1234567890123456");
+        assert_eq!(display(1, 16), "1000000000000000e1");
+        assert_eq!(display(1, -11), "1000000000000000e-26");
+        assert_eq!(display(-1, -20), "-1000000000000000e-35");
+    }
+
     #[test]
     fn test_amount_drops() {
         let amount = Amount::drops(0).unwrap();
@@ -410,4 +538,63 @@ mod test {
         .unwrap();
         assert!(amount.is_issued());
     }
+
+    #[test]
+    fn test_issued_value_from_str() {
+        let value: IssuedValue = "1".parse().unwrap();
+        assert_eq!(value, IssuedValue::from_mantissa_exponent(1, 0).unwrap());
+        let value: IssuedValue = "-25.05".parse().unwrap();
+        assert_eq!(
+            value,
+            IssuedValue::from_mantissa_exponent(-2505, -2).unwrap()
+        );
+        let value: IssuedValue = "0.000012".parse().unwrap();
+        assert_eq!(value, IssuedValue::from_mantissa_exponent(12, -6).unwrap());
+        let value: IssuedValue = "1.5e3".parse().unwrap();
+        assert_eq!(value, IssuedValue::from_mantissa_exponent(1500, 0).unwrap());
+        let value: IssuedValue = "+.5E-1".parse().unwrap();
+        assert_eq!(value, IssuedValue::from_mantissa_exponent(5, -2).unwrap());
+        let value: IssuedValue = "0.0".parse().unwrap();
+        assert_eq!(value, IssuedValue::zero());
+        let value: IssuedValue = "1e-200".parse().unwrap();
+        assert_eq!(value, IssuedValue::zero());
+    }
+
+    /// Digits beyond the precision of the mantissa are truncated
+    #[test]
+    fn test_issued_value_from_str_truncate() {
+        let value: IssuedValue = "123456789012345678901234".parse().unwrap();
+        assert_eq!(value.mantissa(), 1_234_567_890_123_456);
+        assert_eq!(value.exponent(), 8);
+        let value: IssuedValue = "1.23456789012345678901234".parse().unwrap();
+        assert_eq!(value.mantissa(), 1_234_567_890_123_456);
+        assert_eq!(value.exponent(), -15);
+    }
+
+    #[test]
+    fn test_issued_value_from_str_display_round_trip() {
+        for text in [
+            "0",
+            "1",
+            "-25.05",
+            "0.00001",
+            "1000000000000000e-41",
+            "1234567890123456e10",
+        ] {
+            let value: IssuedValue = text.parse().unwrap();
+            assert_eq!(value.to_string(), text);
+        }
+    }
+
+    #[test]
+    fn test_issued_value_from_str_invalid() {
+        for text in ["", ".", "-", "1.2.3", "abc", "1e", "1e1.5", "--1"] {
+            let result = text.parse::<IssuedValue>();
+            assert_matches!(result, Err(Error::InvalidData(message)) => {
+                assert!(message.contains("Invalid issued value"), "message: {}", message);
+            });
+        }
+        let result = "1e200".parse::<IssuedValue>();
+        assert_matches!(result, Err(Error::OutOfRange(_)));
+    }
 }
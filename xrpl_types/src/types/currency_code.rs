@@ -35,6 +35,17 @@ impl CurrencyCode {
         matches!(self, CurrencyCode::Xrp)
     }
 
+    /// Currency code in the 160 bit binary format <https://xrpl.org/currency-formats.html#currency-codes>
+    pub fn to_bytes(&self) -> [u8; 20] {
+        let mut bytes = [0u8; 20];
+        match self {
+            CurrencyCode::Xrp => {}
+            CurrencyCode::Standard(code) => bytes[12..15].copy_from_slice(&code.as_bytes()),
+            CurrencyCode::NonStandard(code) => bytes = *code.as_bytes(),
+        }
+        bytes
+    }
+
     pub fn is_standard(&self) -> bool {
         matches!(self, CurrencyCode::Standard(_))
     }
@@ -190,6 +201,21 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_currency_code_to_bytes() {
+        assert_eq!(CurrencyCode::xrp().to_bytes(), [0u8; 20]);
+        let code = CurrencyCode::from_str("USD").unwrap();
+        assert_eq!(
+            hex::encode_upper(code.to_bytes()),
+            "0000000000000000000000005553440000000000"
+        );
+        let code = CurrencyCode::from_str("0158415500000000C1F76FF6ECB0BAC600000000").unwrap();
+        assert_eq!(
+            hex::encode_upper(code.to_bytes()),
+            "0158415500000000C1F76FF6ECB0BAC600000000"
+        );
+    }
+
     /// Code is invalid if first byte is 0x00, see <https://xrpl.org/currency-formats.html#nonstandard-currency-codes>
     #[test]
     fn test_non_standard_code_from_bytes_invalid_code() {
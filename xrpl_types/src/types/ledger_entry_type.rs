@@ -0,0 +1,109 @@
+use crate::alloc::format;
+use crate::Error;
+
+/// Type of a ledger object <https://xrpl.org/ledger-object-types.html>
+#[repr(u16)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, serde::Serialize, serde::Deserialize)]
+pub enum LedgerEntryType {
+    // Discriminant values can be found at https://github.com/XRPLF/xrpl.js/blob/main/packages/ripple-binary-codec/src/enums/definitions.json
+    AccountRoot = 0x0061,
+    Amendments = 0x0066,
+    AMM = 0x0079,
+    Bridge = 0x0069,
+    Check = 0x0043,
+    DepositPreauth = 0x0070,
+    DID = 0x0049,
+    DirectoryNode = 0x0064,
+    Escrow = 0x0075,
+    FeeSettings = 0x0073,
+    LedgerHashes = 0x0068,
+    NegativeUNL = 0x004E,
+    NFTokenOffer = 0x0037,
+    NFTokenPage = 0x0050,
+    Offer = 0x006F,
+    PayChannel = 0x0078,
+    RippleState = 0x0072,
+    SignerList = 0x0053,
+    Ticket = 0x0054,
+    XChainOwnedClaimID = 0x0071,
+    XChainOwnedCreateAccountClaimID = 0x0074,
+}
+
+impl LedgerEntryType {
+    /// All ledger entry types
+    pub const VALUES: &'static [Self] = &[
+        Self::AccountRoot,
+        Self::Amendments,
+        Self::AMM,
+        Self::Bridge,
+        Self::Check,
+        Self::DepositPreauth,
+        Self::DID,
+        Self::DirectoryNode,
+        Self::Escrow,
+        Self::FeeSettings,
+        Self::LedgerHashes,
+        Self::NegativeUNL,
+        Self::NFTokenOffer,
+        Self::NFTokenPage,
+        Self::Offer,
+        Self::PayChannel,
+        Self::RippleState,
+        Self::SignerList,
+        Self::Ticket,
+        Self::XChainOwnedClaimID,
+        Self::XChainOwnedCreateAccountClaimID,
+    ];
+}
+
+impl TryFrom<u16> for LedgerEntryType {
+    type Error = Error;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {
+            0x0061 => Ok(Self::AccountRoot),
+            0x0066 => Ok(Self::Amendments),
+            0x0079 => Ok(Self::AMM),
+            0x0069 => Ok(Self::Bridge),
+            0x0043 => Ok(Self::Check),
+            0x0070 => Ok(Self::DepositPreauth),
+            0x0049 => Ok(Self::DID),
+            0x0064 => Ok(Self::DirectoryNode),
+            0x0075 => Ok(Self::Escrow),
+            0x0073 => Ok(Self::FeeSettings),
+            0x0068 => Ok(Self::LedgerHashes),
+            0x004E => Ok(Self::NegativeUNL),
+            0x0037 => Ok(Self::NFTokenOffer),
+            0x0050 => Ok(Self::NFTokenPage),
+            0x006F => Ok(Self::Offer),
+            0x0078 => Ok(Self::PayChannel),
+            0x0072 => Ok(Self::RippleState),
+            0x0053 => Ok(Self::SignerList),
+            0x0054 => Ok(Self::Ticket),
+            0x0071 => Ok(Self::XChainOwnedClaimID),
+            0x0074 => Ok(Self::XChainOwnedCreateAccountClaimID),
+            _ => Err(Error::InvalidData(format!(
+                "Unknown ledger entry type: {}",
+                value
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ledger_entry_type_try_from() {
+        assert_eq!(
+            LedgerEntryType::try_from(0x0061).unwrap(),
+            LedgerEntryType::AccountRoot
+        );
+        assert_eq!(
+            LedgerEntryType::try_from(LedgerEntryType::NFTokenOffer as u16).unwrap(),
+            LedgerEntryType::NFTokenOffer
+        );
+        assert!(LedgerEntryType::try_from(0x0000).is_err());
+    }
+}
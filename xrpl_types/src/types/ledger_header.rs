@@ -0,0 +1,19 @@
+use crate::{Hash256, LedgerTimestamp, UInt32, UInt8, Uint64};
+
+/// Ledger header, the fields identifying a ledger version. The ledger hash is the hash of the
+/// header <https://xrpl.org/ledger-header.html>
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct LedgerHeader {
+    pub ledger_index: UInt32,
+    /// Total number of XRP drops in the network
+    pub total_coins: Uint64,
+    pub parent_hash: Hash256,
+    /// Root hash of the transaction tree
+    pub transaction_hash: Hash256,
+    /// Root hash of the account state tree
+    pub account_hash: Hash256,
+    pub parent_close_time: LedgerTimestamp,
+    pub close_time: LedgerTimestamp,
+    pub close_time_resolution: UInt8,
+    pub close_flags: UInt8,
+}
@@ -0,0 +1,138 @@
+use crate::alloc::string::String;
+use crate::{AccountId, Error, Hash256, UInt16, UInt32};
+use enumflags2::{bitflags, BitFlags};
+
+/// Identifier of a non-fungible token, the `NFTokenID` field. Packs the flags, transfer fee,
+/// issuer, scrambled taxon and sequence of the token into 256 bits.
+/// See <https://xrpl.org/nftoken.html#nftokenid>
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct NFTokenId {
+    /// Raw flags, see [`NFTokenId::flags`]
+    pub raw_flags: UInt16,
+    /// Fee charged by the issuer on secondary sales, in units of 1/100,000
+    pub transfer_fee: UInt16,
+    pub issuer: AccountId,
+    /// Taxon of the token, as set by `NFTokenMint`. Stored scrambled in the id.
+    pub taxon: UInt32,
+    /// Sequence number of the token, the `MintedNFTokens` of the issuer when minted
+    pub sequence: UInt32,
+}
+
+/// Flags of a non-fungible token, set from the `NFTokenMint` flags
+#[bitflags]
+#[repr(u16)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum NFTokenFlags {
+    Burnable = 0x0001,
+    OnlyXRP = 0x0002,
+    TrustLine = 0x0004,
+    Transferable = 0x0008,
+}
+
+impl NFTokenId {
+    /// Parses the given token id
+    pub fn from_hash(id: Hash256) -> Self {
+        let bytes = id.0;
+        let sequence = u32::from_be_bytes(bytes[28..32].try_into().expect("4 bytes"));
+        let scrambled_taxon = u32::from_be_bytes(bytes[24..28].try_into().expect("4 bytes"));
+        Self {
+            raw_flags: u16::from_be_bytes([bytes[0], bytes[1]]),
+            transfer_fee: u16::from_be_bytes([bytes[2], bytes[3]]),
+            issuer: AccountId(bytes[4..24].try_into().expect("20 bytes")),
+            taxon: scramble_taxon(scrambled_taxon, sequence),
+            sequence,
+        }
+    }
+
+    /// Builds the token id
+    pub fn to_hash(&self) -> Hash256 {
+        let mut bytes = [0u8; 32];
+        bytes[0..2].copy_from_slice(&self.raw_flags.to_be_bytes());
+        bytes[2..4].copy_from_slice(&self.transfer_fee.to_be_bytes());
+        bytes[4..24].copy_from_slice(&self.issuer.0);
+        bytes[24..28].copy_from_slice(&scramble_taxon(self.taxon, self.sequence).to_be_bytes());
+        bytes[28..32].copy_from_slice(&self.sequence.to_be_bytes());
+        Hash256(bytes)
+    }
+
+    pub fn from_hex(hex: &str) -> Result<Self, Error> {
+        Ok(Self::from_hash(Hash256::from_hex(hex)?))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.to_hash().to_hex()
+    }
+
+    /// The known flags of the token, flags not defined in [`NFTokenFlags`] are ignored
+    pub fn flags(&self) -> BitFlags<NFTokenFlags> {
+        BitFlags::from_bits_truncate(self.raw_flags)
+    }
+}
+
+/// Scrambles the taxon with the token sequence, such that tokens of the same taxon are not
+/// stored sequentially. The scrambling is its own inverse.
+/// See <https://xrpl.org/nftoken.html#taxon>
+pub fn scramble_taxon(taxon: UInt32, sequence: UInt32) -> UInt32 {
+    taxon ^ 384160001u32.wrapping_mul(sequence).wrapping_add(2459)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nftoken_id_parse() {
+        let id =
+            NFTokenId::from_hex("000B0C4495F14B0E44F78A264E41713C64B5F89242540EE2BC8B858E00000D65")
+                .unwrap();
+        assert_eq!(
+            id.flags(),
+            NFTokenFlags::Burnable | NFTokenFlags::OnlyXRP | NFTokenFlags::Transferable
+        );
+        assert_eq!(id.transfer_fee, 3140);
+        assert_eq!(id.issuer.to_address(), "rNCFjv8Ek5oDrNiMJ3pw6eLLFtMjZLJnf2");
+        assert_eq!(id.taxon, 146999694);
+        assert_eq!(id.sequence, 3429);
+        assert_eq!(
+            id.to_hex(),
+            "000B0C4495F14B0E44F78A264E41713C64B5F89242540EE2BC8B858E00000D65"
+        );
+    }
+
+    #[test]
+    fn test_nftoken_id_build() {
+        let issuer = AccountId::from_address("rNCFjv8Ek5oDrNiMJ3pw6eLLFtMjZLJnf2").unwrap();
+        let id = NFTokenId {
+            raw_flags: (NFTokenFlags::Transferable | NFTokenFlags::Burnable).bits(),
+            transfer_fee: 0,
+            issuer,
+            taxon: 0,
+            sequence: 0,
+        };
+        // With sequence 0 the taxon is scrambled with 2459
+        assert_eq!(
+            id.to_hex(),
+            "0009000095F14B0E44F78A264E41713C64B5F89242540EE20000099B00000000"
+        );
+        assert_eq!(NFTokenId::from_hash(id.to_hash()), id);
+    }
+
+    #[test]
+    fn test_nftoken_id_unknown_flags() {
+        let id =
+            NFTokenId::from_hex("0018000095F14B0E44F78A264E41713C64B5F89242540EE20000099B00000000")
+                .unwrap();
+        assert_eq!(id.raw_flags, 0x0018);
+        assert_eq!(id.flags(), NFTokenFlags::Transferable);
+    }
+
+    #[test]
+    fn test_scramble_taxon() {
+        for (taxon, sequence) in [(0, 0), (146999694, 3429), (u32::MAX, u32::MAX)] {
+            assert_eq!(
+                scramble_taxon(scramble_taxon(taxon, sequence), sequence),
+                taxon
+            );
+        }
+    }
+}
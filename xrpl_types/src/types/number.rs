@@ -0,0 +1,210 @@
+use crate::alloc::format;
+use crate::types::amount::{fmt_decimal, parse_decimal};
+use crate::Error;
+use core::fmt;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Decimal number with a signed 64 bit mantissa and a 32 bit exponent, the value of `Number` fields.
+/// See `Number` in <https://github.com/XRPLF/rippled/blob/develop/include/xrpl/basics/Number.h>
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct Number {
+    // fields are private since it is validated when the Number value is created
+    mantissa: i64,
+    exponent: i32,
+}
+
+impl Number {
+    /// Creates number from given mantissa and exponent. The created number will be normalized
+    /// like rippled does. If the number cannot be represented, an error is returned.
+    pub fn from_mantissa_exponent(mantissa: i64, exponent: i32) -> Result<Self, Error> {
+        Self { mantissa, exponent }.normalize()
+    }
+
+    /// The number zero, which rippled represents with the lowest exponent
+    pub fn zero() -> Self {
+        Self {
+            mantissa: 0,
+            exponent: i32::MIN,
+        }
+    }
+
+    /// Signed and normalized mantissa
+    pub fn mantissa(&self) -> i64 {
+        self.mantissa
+    }
+
+    /// Normalized exponent
+    pub fn exponent(&self) -> i32 {
+        self.exponent
+    }
+
+    /// Normalizes mantissa into `1e15..1e16` and exponent into `-32768..=32768`. Digits dropped
+    /// from the mantissa are rounded to nearest, ties to even, like rippled does.
+    fn normalize(self) -> Result<Self, Error> {
+        // rippled implementation: https://github.com/XRPLF/rippled/blob/develop/src/libxrpl/basics/Number.cpp (Number::normalize)
+
+        const MANTISSA_MIN: u64 = 1000000000000000;
+        const MANTISSA_MAX: u64 = 9999999999999999;
+        const EXPONENT_MIN: i32 = -32768;
+        const EXPONENT_MAX: i32 = 32768;
+
+        if self.mantissa == 0 {
+            return Ok(Self::zero());
+        }
+        let negative = self.mantissa < 0;
+        let mut mantissa = self.mantissa.unsigned_abs();
+        let mut exponent = self.exponent;
+        let too_big = || Error::OutOfRange(format!("Number too big to be normalized: {:?}", self));
+
+        while mantissa < MANTISSA_MIN && exponent > EXPONENT_MIN {
+            mantissa *= 10;
+            exponent -= 1;
+        }
+        // The last dropped digit, and whether any digit dropped before it is not zero
+        let mut round_digit = 0;
+        let mut sticky = false;
+        while mantissa > MANTISSA_MAX {
+            if exponent >= EXPONENT_MAX {
+                return Err(too_big());
+            }
+            sticky |= round_digit != 0;
+            round_digit = mantissa % 10;
+            mantissa /= 10;
+            exponent += 1;
+        }
+
+        if exponent < EXPONENT_MIN || mantissa < MANTISSA_MIN {
+            return Ok(Self::zero());
+        }
+        if round_digit > 5 || (round_digit == 5 && (sticky || mantissa % 2 == 1)) {
+            mantissa += 1;
+            if mantissa > MANTISSA_MAX {
+                mantissa /= 10;
+                exponent += 1;
+            }
+        }
+        if exponent > EXPONENT_MAX {
+            return Err(too_big());
+        }
+
+        let mantissa = mantissa as i64;
+        Ok(Self {
+            mantissa: if negative { -mantissa } else { mantissa },
+            exponent,
+        })
+    }
+}
+
+/// Formats the number like rippled does in JSON, see [`IssuedValue`](crate::IssuedValue)
+impl Display for Number {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_decimal(f, self.mantissa, self.exponent)
+    }
+}
+
+/// Parses a decimal string like `"-1.5"` or `"1e-3"`. Digits beyond the precision of the mantissa
+/// are rounded, after truncating the string to 18 significant digits.
+impl FromStr for Number {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (mantissa, exponent) =
+            parse_decimal(s).ok_or_else(|| Error::InvalidData(format!("Invalid number: {}", s)))?;
+        Self::from_mantissa_exponent(mantissa, exponent)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::alloc::string::ToString;
+    use assert_matches::assert_matches;
+
+    #[test]
+    fn test_number_normalize() {
+        let number = Number::from_mantissa_exponent(-123, 200).unwrap();
+        assert_eq!(number.mantissa(), -1_230_000_000_000_000);
+        assert_eq!(number.exponent(), 187);
+        let number = Number::from_mantissa_exponent(19_999_999_999_999_999, 0).unwrap();
+        assert_eq!(number.mantissa(), 2_000_000_000_000_000);
+        assert_eq!(number.exponent(), 1);
+        let number = Number::from_mantissa_exponent(0, 10).unwrap();
+        assert_eq!(number, Number::zero());
+        let number = Number::from_mantissa_exponent(1, -40000).unwrap();
+        assert_eq!(number, Number::zero());
+    }
+
+    /// Cases of `test_limits` in rippled `src/test/basics/Number_test.cpp`
+    #[test]
+    fn test_number_normalize_rippled() {
+        let cases = [
+            (
+                (10_000_000_000_000_000, 32767),
+                (1_000_000_000_000_000, 32768),
+            ),
+            ((1_000_000_000_000_000, -32769), (0, i32::MIN)),
+            (
+                (1_000_000_000_000_001_500, 32000),
+                (1_000_000_000_000_002, 32003),
+            ),
+            ((i64::MIN, 0), (-9_223_372_036_854_776, 3)),
+            ((i64::MAX, 0), (9_223_372_036_854_776, 3)),
+        ];
+        for ((mantissa, exponent), expected) in cases {
+            let number = Number::from_mantissa_exponent(mantissa, exponent).unwrap();
+            assert_eq!((number.mantissa(), number.exponent()), expected);
+        }
+
+        for (mantissa, exponent) in [
+            (10_000_000_000_000_000, 32768),
+            (99_999_999_999_999_999, 32767),
+        ] {
+            let result = Number::from_mantissa_exponent(mantissa, exponent);
+            assert_matches!(result, Err(Error::OutOfRange(_)));
+        }
+    }
+
+    #[test]
+    fn test_number_round_half_to_even() {
+        let cases = [
+            (10_000_000_000_000_005, 1_000_000_000_000_000),
+            (10_000_000_000_000_015, 1_000_000_000_000_002),
+            (10_000_000_000_000_014, 1_000_000_000_000_001),
+            (100_000_000_000_000_051, 1_000_000_000_000_001),
+            (-10_000_000_000_000_016, -1_000_000_000_000_002),
+        ];
+        for (mantissa, expected) in cases {
+            let number = Number::from_mantissa_exponent(mantissa, 0).unwrap();
+            assert_eq!(number.mantissa(), expected, "{}", mantissa);
+        }
+        let number = Number::from_mantissa_exponent(99_999_999_999_999_995, 0).unwrap();
+        assert_eq!(
+            (number.mantissa(), number.exponent()),
+            (1_000_000_000_000_000, 2)
+        );
+    }
+
+    #[test]
+    fn test_number_out_of_range() {
+        let result = Number::from_mantissa_exponent(1, 40000);
+        assert_matches!(result, Err(Error::OutOfRange(message)) => {
+            assert!(message.contains("Number too big to be normalized"), "message: {}", message);
+        });
+    }
+
+    #[test]
+    fn test_number_from_str_display() {
+        let number: Number = "-25.05".parse().unwrap();
+        assert_eq!(number, Number::from_mantissa_exponent(-2505, -2).unwrap());
+        assert_eq!(number.to_string(), "-25.05");
+        let number: Number = "1e300".parse().unwrap();
+        assert_eq!(number.to_string(), "1000000000000000e285");
+        let number: Number = "19999999999999999".parse().unwrap();
+        assert_eq!(number.to_string(), "2000000000000000e1");
+        let number: Number = "0".parse().unwrap();
+        assert_eq!(number, Number::zero());
+        assert_eq!(number.to_string(), "0");
+        assert_matches!("1.2.3".parse::<Number>(), Err(Error::InvalidData(_)));
+    }
+}
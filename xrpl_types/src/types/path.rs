@@ -0,0 +1,83 @@
+use crate::alloc::{string::ToString, vec::Vec};
+use crate::{AccountId, CurrencyCode, Error};
+
+/// Step of a payment path, see <https://xrpl.org/paths.html#path-specifications> and
+/// <https://xrpl.org/serialization.html#pathset-fields>
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct PathStep {
+    // fields are private since it is validated when the PathStep value is created
+    account: Option<AccountId>,
+    currency: Option<CurrencyCode>,
+    issuer: Option<AccountId>,
+}
+
+impl PathStep {
+    /// Creates a step with the given fields, at least one of which must be present
+    pub fn new(
+        account: Option<AccountId>,
+        currency: Option<CurrencyCode>,
+        issuer: Option<AccountId>,
+    ) -> Result<Self, Error> {
+        if account.is_none() && currency.is_none() && issuer.is_none() {
+            return Err(Error::InvalidData(
+                "Path step must have an account, currency or issuer".to_string(),
+            ));
+        }
+        Ok(Self {
+            account,
+            currency,
+            issuer,
+        })
+    }
+
+    /// Step rippling through the given account
+    pub fn account(account: AccountId) -> Self {
+        Self {
+            account: Some(account),
+            currency: None,
+            issuer: None,
+        }
+    }
+
+    /// Step converting to the given currency, through the order book of the given issuer
+    pub fn currency(currency: CurrencyCode, issuer: Option<AccountId>) -> Self {
+        Self {
+            account: None,
+            currency: Some(currency),
+            issuer,
+        }
+    }
+
+    /// Account to ripple through
+    pub fn account_id(&self) -> Option<AccountId> {
+        self.account
+    }
+
+    /// Currency to convert to
+    pub fn currency_code(&self) -> Option<CurrencyCode> {
+        self.currency
+    }
+
+    /// Issuer of the currency to convert to
+    pub fn issuer(&self) -> Option<AccountId> {
+        self.issuer
+    }
+}
+
+/// Payment path, see <https://xrpl.org/paths.html>. A path set is a list of paths.
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct Path(pub Vec<PathStep>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assert_matches::assert_matches;
+
+    #[test]
+    fn test_empty_path_step() {
+        let result = PathStep::new(None, None, None);
+        assert_matches!(result, Err(Error::InvalidData(message)) => {
+            assert!(message.contains("Path step must have an account, currency or issuer"), "message: {}", message);
+        });
+    }
+}
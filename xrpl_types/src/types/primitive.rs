@@ -99,6 +99,15 @@ impl Hash256 {
     pub fn to_hex(&self) -> String {
         hex::encode_upper(self.0)
     }
+
+    /// Hash with all bits zero, used e.g. for empty SHAMap branches
+    pub const fn zero() -> Self {
+        Hash256([0; 32])
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0 == [0; 32]
+    }
 }
 
 impl Blob {
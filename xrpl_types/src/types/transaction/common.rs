@@ -1,14 +1,24 @@
 use crate::alloc::vec::Vec;
+use crate::deserialize::{required, Deserialize, Deserializer};
 use crate::serialize::{Serialize, SerializeArray, Serializer};
+use crate::types::transaction::deserialize_drops;
 use crate::{AccountId, Amount, Blob, DropsAmount, Hash256, UInt32};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Memo {
     pub memo_type: Blob,
     pub memo_data: Blob,
     pub memo_format: Option<Blob>,
 }
 
+/// Signature of one signer of a multi-signed transaction <https://xrpl.org/transaction-common-fields.html#signers-field>
+#[derive(Debug, Clone)]
+pub struct Signer {
+    pub account: AccountId,
+    pub txn_signature: Blob,
+    pub signing_pub_key: Blob,
+}
+
 /// A ledger transaction <https://xrpl.org/transaction-formats.html>
 #[derive(Debug, Clone, Default)]
 pub struct TransactionCommon {
@@ -20,6 +30,7 @@ pub struct TransactionCommon {
     pub memos: Vec<Memo>,
     pub network_id: Option<UInt32>,
     pub source_tag: Option<UInt32>,
+    pub signers: Vec<Signer>,
     pub signing_pub_key: Option<Blob>,
     pub ticket_sequence: Option<UInt32>,
     pub txn_signature: Option<Blob>,
@@ -36,6 +47,7 @@ impl TransactionCommon {
             memos: Vec::default(),
             network_id: None,
             source_tag: None,
+            signers: Vec::default(),
             signing_pub_key: None,
             ticket_sequence: None,
             txn_signature: None,
@@ -64,6 +76,13 @@ impl Serialize for TransactionCommon {
             }
             array.end()?;
         }
+        if !self.signers.is_empty() {
+            let mut array = s.serialize_array("Signers")?;
+            for signer in &self.signers {
+                array.serialize_object("Signer", signer)?;
+            }
+            array.end()?;
+        }
         if let Some(ticket_sequence) = self.ticket_sequence {
             s.serialize_uint32("TicketSequence", ticket_sequence)?;
         }
@@ -94,3 +113,51 @@ impl Serialize for Memo {
         Ok(())
     }
 }
+
+impl Serialize for Signer {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_account_id("Account", self.account)?;
+        s.serialize_blob("TxnSignature", &self.txn_signature)?;
+        s.serialize_blob("SigningPubKey", &self.signing_pub_key)?;
+        Ok(())
+    }
+}
+
+impl Deserialize for TransactionCommon {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        Ok(Self {
+            account: required("Account", d.deserialize_account_id("Account")?)?,
+            fee: deserialize_drops(d, "Fee")?,
+            sequence: d.deserialize_uint32("Sequence")?,
+            account_txn_id: d.deserialize_hash256("AccountTxnID")?,
+            last_ledger_sequence: d.deserialize_uint32("LastLedgerSequence")?,
+            memos: d.deserialize_array("Memos", "Memo")?,
+            network_id: d.deserialize_uint32("NetworkID")?,
+            source_tag: d.deserialize_uint32("SourceTag")?,
+            signers: d.deserialize_array("Signers", "Signer")?,
+            signing_pub_key: d.deserialize_blob("SigningPubKey")?,
+            ticket_sequence: d.deserialize_uint32("TicketSequence")?,
+            txn_signature: d.deserialize_blob("TxnSignature")?,
+        })
+    }
+}
+
+impl Deserialize for Memo {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        Ok(Self {
+            memo_type: required("MemoType", d.deserialize_blob("MemoType")?)?,
+            memo_data: required("MemoData", d.deserialize_blob("MemoData")?)?,
+            memo_format: d.deserialize_blob("MemoFormat")?,
+        })
+    }
+}
+
+impl Deserialize for Signer {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        Ok(Self {
+            account: required("Account", d.deserialize_account_id("Account")?)?,
+            txn_signature: required("TxnSignature", d.deserialize_blob("TxnSignature")?)?,
+            signing_pub_key: required("SigningPubKey", d.deserialize_blob("SigningPubKey")?)?,
+        })
+    }
+}
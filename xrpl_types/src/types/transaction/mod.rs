@@ -1,8 +1,12 @@
 mod common;
 mod variants;
 
-use crate::serialize::Serialize;
+use crate::alloc::format;
+use crate::deserialize::{required, Deserialize, Deserializer};
+use crate::serialize::{Serialize, Serializer};
+use crate::{Amount, DropsAmount, Error};
 pub use common::*;
+use enumflags2::{BitFlag, BitFlags};
 pub use variants::*;
 
 /// XRPL transaction
@@ -64,3 +68,376 @@ pub enum TransactionType {
     SetFee = 101,
     UNLModify = 102,
 }
+
+impl TransactionType {
+    /// All transaction types, in the order of their codes
+    pub const VALUES: &'static [Self] = &[
+        Self::Payment,
+        Self::EscrowCreate,
+        Self::EscrowFinish,
+        Self::AccountSet,
+        Self::EscrowCancel,
+        Self::SetRegularKey,
+        Self::NickNameSet,
+        Self::OfferCreate,
+        Self::OfferCancel,
+        Self::Contract,
+        Self::TicketCreate,
+        Self::TicketCancel,
+        Self::SignerListSet,
+        Self::PaymentChannelCreate,
+        Self::PaymentChannelFund,
+        Self::PaymentChannelClaim,
+        Self::CheckCreate,
+        Self::CheckCash,
+        Self::CheckCancel,
+        Self::DepositPreauth,
+        Self::TrustSet,
+        Self::AccountDelete,
+        Self::SetHook,
+        Self::NFTokenMint,
+        Self::NFTokenBurn,
+        Self::NFTokenCreateOffer,
+        Self::NFTokenCancelOffer,
+        Self::NFTokenAcceptOffer,
+        Self::Clawback,
+        Self::AMMCreate,
+        Self::AMMDeposit,
+        Self::AMMWithdraw,
+        Self::AMMVote,
+        Self::AMMBid,
+        Self::AMMDelete,
+        Self::XChainCreateClaimID,
+        Self::XChainCommit,
+        Self::XChainClaim,
+        Self::XChainAccountCreateCommit,
+        Self::XChainAddClaimAttestation,
+        Self::XChainAddAccountCreateAttestation,
+        Self::XChainModifyBridge,
+        Self::XChainCreateBridge,
+        Self::DIDSet,
+        Self::DIDDelete,
+        Self::EnableAmendment,
+        Self::SetFee,
+        Self::UNLModify,
+    ];
+}
+
+impl TryFrom<u16> for TransactionType {
+    type Error = Error;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Payment),
+            1 => Ok(Self::EscrowCreate),
+            2 => Ok(Self::EscrowFinish),
+            3 => Ok(Self::AccountSet),
+            4 => Ok(Self::EscrowCancel),
+            5 => Ok(Self::SetRegularKey),
+            6 => Ok(Self::NickNameSet),
+            7 => Ok(Self::OfferCreate),
+            8 => Ok(Self::OfferCancel),
+            9 => Ok(Self::Contract),
+            10 => Ok(Self::TicketCreate),
+            11 => Ok(Self::TicketCancel),
+            12 => Ok(Self::SignerListSet),
+            13 => Ok(Self::PaymentChannelCreate),
+            14 => Ok(Self::PaymentChannelFund),
+            15 => Ok(Self::PaymentChannelClaim),
+            16 => Ok(Self::CheckCreate),
+            17 => Ok(Self::CheckCash),
+            18 => Ok(Self::CheckCancel),
+            19 => Ok(Self::DepositPreauth),
+            20 => Ok(Self::TrustSet),
+            21 => Ok(Self::AccountDelete),
+            22 => Ok(Self::SetHook),
+            25 => Ok(Self::NFTokenMint),
+            26 => Ok(Self::NFTokenBurn),
+            27 => Ok(Self::NFTokenCreateOffer),
+            28 => Ok(Self::NFTokenCancelOffer),
+            29 => Ok(Self::NFTokenAcceptOffer),
+            30 => Ok(Self::Clawback),
+            35 => Ok(Self::AMMCreate),
+            36 => Ok(Self::AMMDeposit),
+            37 => Ok(Self::AMMWithdraw),
+            38 => Ok(Self::AMMVote),
+            39 => Ok(Self::AMMBid),
+            40 => Ok(Self::AMMDelete),
+            41 => Ok(Self::XChainCreateClaimID),
+            42 => Ok(Self::XChainCommit),
+            43 => Ok(Self::XChainClaim),
+            44 => Ok(Self::XChainAccountCreateCommit),
+            45 => Ok(Self::XChainAddClaimAttestation),
+            46 => Ok(Self::XChainAddAccountCreateAttestation),
+            47 => Ok(Self::XChainModifyBridge),
+            48 => Ok(Self::XChainCreateBridge),
+            49 => Ok(Self::DIDSet),
+            50 => Ok(Self::DIDDelete),
+            100 => Ok(Self::EnableAmendment),
+            101 => Ok(Self::SetFee),
+            102 => Ok(Self::UNLModify),
+            _ => Err(Error::InvalidData(format!(
+                "Unknown transaction type: {}",
+                value
+            ))),
+        }
+    }
+}
+
+fn deserialize_transaction_type<D: Deserializer>(d: &mut D) -> Result<TransactionType, D::Error> {
+    let transaction_type = required("TransactionType", d.deserialize_uint16("TransactionType")?)?;
+    Ok(TransactionType::try_from(transaction_type)?)
+}
+
+/// Deserializes the `TransactionType` field and checks that it is the expected type
+pub(crate) fn expect_transaction_type<D: Deserializer>(
+    d: &mut D,
+    expected: TransactionType,
+) -> Result<(), D::Error> {
+    let transaction_type = deserialize_transaction_type(d)?;
+    if transaction_type != expected {
+        return Err(Error::InvalidData(format!(
+            "Expected transaction type {:?}, found {:?}",
+            expected, transaction_type
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+/// Deserializes the `Flags` field. Flags not defined for the transaction type are rejected.
+pub(crate) fn deserialize_flags<D: Deserializer, T: BitFlag<Numeric = u32>>(
+    d: &mut D,
+) -> Result<BitFlags<T>, D::Error> {
+    let bits = d.deserialize_uint32("Flags")?.unwrap_or_default();
+    Ok(BitFlags::from_bits(bits)
+        .map_err(|_| Error::InvalidData(format!("Unknown flags: {:#010x}", bits)))?)
+}
+
+/// Deserializes an amount field that must be an XRP amount
+pub(crate) fn deserialize_drops<D: Deserializer>(
+    d: &mut D,
+    field_name: &str,
+) -> Result<Option<DropsAmount>, D::Error> {
+    match d.deserialize_amount(field_name)? {
+        Some(Amount::Drops(drops)) => Ok(Some(drops)),
+        Some(Amount::Issued(_)) => {
+            Err(Error::InvalidData(format!("{} must be an XRP amount", field_name)).into())
+        }
+        None => Ok(None),
+    }
+}
+
+/// A transaction of any of the modelled transaction types, selected by `TransactionType`
+#[derive(Debug, Clone)]
+pub enum TransactionVariant {
+    AMMBid(AMMBidTransaction),
+    AMMCreate(AMMCreateTransaction),
+    AMMDelete(AMMDeleteTransaction),
+    AMMDeposit(AMMDepositTransaction),
+    AMMVote(AMMVoteTransaction),
+    AMMWithdraw(AMMWithdrawTransaction),
+    AccountDelete(AccountDeleteTransaction),
+    AccountSet(AccountSetTransaction),
+    CheckCancel(CheckCancelTransaction),
+    CheckCash(CheckCashTransaction),
+    CheckCreate(CheckCreateTransaction),
+    EscrowCancel(EscrowCancelTransaction),
+    EscrowCreate(EscrowCreateTransaction),
+    EscrowFinish(EscrowFinishTransaction),
+    NFTokenAcceptOffer(NFTokenAcceptOfferTransaction),
+    NFTokenBurn(NFTokenBurnTransaction),
+    NFTokenCancelOffer(NFTokenCancelOfferTransaction),
+    NFTokenCreateOffer(NFTokenCreateOfferTransaction),
+    NFTokenMint(NFTokenMintTransaction),
+    OfferCancel(OfferCancelTransaction),
+    OfferCreate(OfferCreateTransaction),
+    Payment(PaymentTransaction),
+    PaymentChannelClaim(PaymentChannelClaimTransaction),
+    PaymentChannelCreate(PaymentChannelCreateTransaction),
+    PaymentChannelFund(PaymentChannelFundTransaction),
+    TrustSet(TrustSetTransaction),
+}
+
+impl TransactionVariant {
+    pub fn transaction_type(&self) -> TransactionType {
+        match self {
+            Self::AMMBid(_) => TransactionType::AMMBid,
+            Self::AMMCreate(_) => TransactionType::AMMCreate,
+            Self::AMMDelete(_) => TransactionType::AMMDelete,
+            Self::AMMDeposit(_) => TransactionType::AMMDeposit,
+            Self::AMMVote(_) => TransactionType::AMMVote,
+            Self::AMMWithdraw(_) => TransactionType::AMMWithdraw,
+            Self::AccountDelete(_) => TransactionType::AccountDelete,
+            Self::AccountSet(_) => TransactionType::AccountSet,
+            Self::CheckCancel(_) => TransactionType::CheckCancel,
+            Self::CheckCash(_) => TransactionType::CheckCash,
+            Self::CheckCreate(_) => TransactionType::CheckCreate,
+            Self::EscrowCancel(_) => TransactionType::EscrowCancel,
+            Self::EscrowCreate(_) => TransactionType::EscrowCreate,
+            Self::EscrowFinish(_) => TransactionType::EscrowFinish,
+            Self::NFTokenAcceptOffer(_) => TransactionType::NFTokenAcceptOffer,
+            Self::NFTokenBurn(_) => TransactionType::NFTokenBurn,
+            Self::NFTokenCancelOffer(_) => TransactionType::NFTokenCancelOffer,
+            Self::NFTokenCreateOffer(_) => TransactionType::NFTokenCreateOffer,
+            Self::NFTokenMint(_) => TransactionType::NFTokenMint,
+            Self::OfferCancel(_) => TransactionType::OfferCancel,
+            Self::OfferCreate(_) => TransactionType::OfferCreate,
+            Self::Payment(_) => TransactionType::Payment,
+            Self::PaymentChannelClaim(_) => TransactionType::PaymentChannelClaim,
+            Self::PaymentChannelCreate(_) => TransactionType::PaymentChannelCreate,
+            Self::PaymentChannelFund(_) => TransactionType::PaymentChannelFund,
+            Self::TrustSet(_) => TransactionType::TrustSet,
+        }
+    }
+}
+
+impl Transaction for TransactionVariant {
+    fn common(&self) -> &TransactionCommon {
+        match self {
+            Self::AMMBid(tx) => tx.common(),
+            Self::AMMCreate(tx) => tx.common(),
+            Self::AMMDelete(tx) => tx.common(),
+            Self::AMMDeposit(tx) => tx.common(),
+            Self::AMMVote(tx) => tx.common(),
+            Self::AMMWithdraw(tx) => tx.common(),
+            Self::AccountDelete(tx) => tx.common(),
+            Self::AccountSet(tx) => tx.common(),
+            Self::CheckCancel(tx) => tx.common(),
+            Self::CheckCash(tx) => tx.common(),
+            Self::CheckCreate(tx) => tx.common(),
+            Self::EscrowCancel(tx) => tx.common(),
+            Self::EscrowCreate(tx) => tx.common(),
+            Self::EscrowFinish(tx) => tx.common(),
+            Self::NFTokenAcceptOffer(tx) => tx.common(),
+            Self::NFTokenBurn(tx) => tx.common(),
+            Self::NFTokenCancelOffer(tx) => tx.common(),
+            Self::NFTokenCreateOffer(tx) => tx.common(),
+            Self::NFTokenMint(tx) => tx.common(),
+            Self::OfferCancel(tx) => tx.common(),
+            Self::OfferCreate(tx) => tx.common(),
+            Self::Payment(tx) => tx.common(),
+            Self::PaymentChannelClaim(tx) => tx.common(),
+            Self::PaymentChannelCreate(tx) => tx.common(),
+            Self::PaymentChannelFund(tx) => tx.common(),
+            Self::TrustSet(tx) => tx.common(),
+        }
+    }
+
+    fn common_mut(&mut self) -> &mut TransactionCommon {
+        match self {
+            Self::AMMBid(tx) => tx.common_mut(),
+            Self::AMMCreate(tx) => tx.common_mut(),
+            Self::AMMDelete(tx) => tx.common_mut(),
+            Self::AMMDeposit(tx) => tx.common_mut(),
+            Self::AMMVote(tx) => tx.common_mut(),
+            Self::AMMWithdraw(tx) => tx.common_mut(),
+            Self::AccountDelete(tx) => tx.common_mut(),
+            Self::AccountSet(tx) => tx.common_mut(),
+            Self::CheckCancel(tx) => tx.common_mut(),
+            Self::CheckCash(tx) => tx.common_mut(),
+            Self::CheckCreate(tx) => tx.common_mut(),
+            Self::EscrowCancel(tx) => tx.common_mut(),
+            Self::EscrowCreate(tx) => tx.common_mut(),
+            Self::EscrowFinish(tx) => tx.common_mut(),
+            Self::NFTokenAcceptOffer(tx) => tx.common_mut(),
+            Self::NFTokenBurn(tx) => tx.common_mut(),
+            Self::NFTokenCancelOffer(tx) => tx.common_mut(),
+            Self::NFTokenCreateOffer(tx) => tx.common_mut(),
+            Self::NFTokenMint(tx) => tx.common_mut(),
+            Self::OfferCancel(tx) => tx.common_mut(),
+            Self::OfferCreate(tx) => tx.common_mut(),
+            Self::Payment(tx) => tx.common_mut(),
+            Self::PaymentChannelClaim(tx) => tx.common_mut(),
+            Self::PaymentChannelCreate(tx) => tx.common_mut(),
+            Self::PaymentChannelFund(tx) => tx.common_mut(),
+            Self::TrustSet(tx) => tx.common_mut(),
+        }
+    }
+}
+
+impl Serialize for TransactionVariant {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        match self {
+            Self::AMMBid(tx) => tx.serialize(s),
+            Self::AMMCreate(tx) => tx.serialize(s),
+            Self::AMMDelete(tx) => tx.serialize(s),
+            Self::AMMDeposit(tx) => tx.serialize(s),
+            Self::AMMVote(tx) => tx.serialize(s),
+            Self::AMMWithdraw(tx) => tx.serialize(s),
+            Self::AccountDelete(tx) => tx.serialize(s),
+            Self::AccountSet(tx) => tx.serialize(s),
+            Self::CheckCancel(tx) => tx.serialize(s),
+            Self::CheckCash(tx) => tx.serialize(s),
+            Self::CheckCreate(tx) => tx.serialize(s),
+            Self::EscrowCancel(tx) => tx.serialize(s),
+            Self::EscrowCreate(tx) => tx.serialize(s),
+            Self::EscrowFinish(tx) => tx.serialize(s),
+            Self::NFTokenAcceptOffer(tx) => tx.serialize(s),
+            Self::NFTokenBurn(tx) => tx.serialize(s),
+            Self::NFTokenCancelOffer(tx) => tx.serialize(s),
+            Self::NFTokenCreateOffer(tx) => tx.serialize(s),
+            Self::NFTokenMint(tx) => tx.serialize(s),
+            Self::OfferCancel(tx) => tx.serialize(s),
+            Self::OfferCreate(tx) => tx.serialize(s),
+            Self::Payment(tx) => tx.serialize(s),
+            Self::PaymentChannelClaim(tx) => tx.serialize(s),
+            Self::PaymentChannelCreate(tx) => tx.serialize(s),
+            Self::PaymentChannelFund(tx) => tx.serialize(s),
+            Self::TrustSet(tx) => tx.serialize(s),
+        }
+    }
+}
+
+impl Deserialize for TransactionVariant {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        let transaction_type = deserialize_transaction_type(d)?;
+        Ok(match transaction_type {
+            TransactionType::AMMBid => Self::AMMBid(Deserialize::deserialize(d)?),
+            TransactionType::AMMCreate => Self::AMMCreate(Deserialize::deserialize(d)?),
+            TransactionType::AMMDelete => Self::AMMDelete(Deserialize::deserialize(d)?),
+            TransactionType::AMMDeposit => Self::AMMDeposit(Deserialize::deserialize(d)?),
+            TransactionType::AMMVote => Self::AMMVote(Deserialize::deserialize(d)?),
+            TransactionType::AMMWithdraw => Self::AMMWithdraw(Deserialize::deserialize(d)?),
+            TransactionType::AccountDelete => Self::AccountDelete(Deserialize::deserialize(d)?),
+            TransactionType::AccountSet => Self::AccountSet(Deserialize::deserialize(d)?),
+            TransactionType::CheckCancel => Self::CheckCancel(Deserialize::deserialize(d)?),
+            TransactionType::CheckCash => Self::CheckCash(Deserialize::deserialize(d)?),
+            TransactionType::CheckCreate => Self::CheckCreate(Deserialize::deserialize(d)?),
+            TransactionType::EscrowCancel => Self::EscrowCancel(Deserialize::deserialize(d)?),
+            TransactionType::EscrowCreate => Self::EscrowCreate(Deserialize::deserialize(d)?),
+            TransactionType::EscrowFinish => Self::EscrowFinish(Deserialize::deserialize(d)?),
+            TransactionType::NFTokenAcceptOffer => {
+                Self::NFTokenAcceptOffer(Deserialize::deserialize(d)?)
+            }
+            TransactionType::NFTokenBurn => Self::NFTokenBurn(Deserialize::deserialize(d)?),
+            TransactionType::NFTokenCancelOffer => {
+                Self::NFTokenCancelOffer(Deserialize::deserialize(d)?)
+            }
+            TransactionType::NFTokenCreateOffer => {
+                Self::NFTokenCreateOffer(Deserialize::deserialize(d)?)
+            }
+            TransactionType::NFTokenMint => Self::NFTokenMint(Deserialize::deserialize(d)?),
+            TransactionType::OfferCancel => Self::OfferCancel(Deserialize::deserialize(d)?),
+            TransactionType::OfferCreate => Self::OfferCreate(Deserialize::deserialize(d)?),
+            TransactionType::Payment => Self::Payment(Deserialize::deserialize(d)?),
+            TransactionType::PaymentChannelClaim => {
+                Self::PaymentChannelClaim(Deserialize::deserialize(d)?)
+            }
+            TransactionType::PaymentChannelCreate => {
+                Self::PaymentChannelCreate(Deserialize::deserialize(d)?)
+            }
+            TransactionType::PaymentChannelFund => {
+                Self::PaymentChannelFund(Deserialize::deserialize(d)?)
+            }
+            TransactionType::TrustSet => Self::TrustSet(Deserialize::deserialize(d)?),
+            _ => {
+                return Err(Error::InvalidData(format!(
+                    "Transaction type {:?} is not supported",
+                    transaction_type
+                ))
+                .into())
+            }
+        })
+    }
+}
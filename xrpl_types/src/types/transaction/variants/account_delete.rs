@@ -1,4 +1,6 @@
+use crate::deserialize::{required, Deserialize, Deserializer};
 use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_flags, expect_transaction_type};
 use crate::{AccountId, Transaction, TransactionCommon, TransactionType, UInt32};
 use enumflags2::{bitflags, BitFlags};
 
@@ -51,3 +53,15 @@ impl Serialize for AccountDeleteTransaction {
         Ok(())
     }
 }
+
+impl Deserialize for AccountDeleteTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::AccountDelete)?;
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            destination: required("Destination", d.deserialize_account_id("Destination")?)?,
+            destination_tag: d.deserialize_uint32("DestinationTag")?,
+        })
+    }
+}
@@ -1,7 +1,10 @@
+use crate::alloc::format;
+use crate::deserialize::{Deserialize, Deserializer};
 use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_flags, expect_transaction_type};
 use crate::{
-    AccountId, Blob, Hash128, Hash256, Transaction, TransactionCommon, TransactionType, UInt32,
-    UInt8,
+    AccountId, Blob, Error, Hash128, Hash256, Transaction, TransactionCommon, TransactionType,
+    UInt32, UInt8,
 };
 use enumflags2::{bitflags, BitFlags};
 
@@ -124,3 +127,57 @@ impl Serialize for AccountSetTransaction {
         Ok(())
     }
 }
+
+impl TryFrom<UInt32> for AccountSetFlag {
+    type Error = Error;
+
+    fn try_from(value: UInt32) -> Result<Self, Self::Error> {
+        match value {
+            5 => Ok(Self::AccountTxnID),
+            16 => Ok(Self::AllowTrustLineClawback),
+            10 => Ok(Self::AuthorizedNFTokenMinter),
+            8 => Ok(Self::DefaultRipple),
+            9 => Ok(Self::DepositAuth),
+            4 => Ok(Self::DisableMaster),
+            13 => Ok(Self::DisallowIncomingCheck),
+            12 => Ok(Self::DisallowIncomingNFTokenOffer),
+            14 => Ok(Self::DisallowIncomingPayChan),
+            15 => Ok(Self::DisallowIncomingTrustline),
+            3 => Ok(Self::DisallowXRP),
+            7 => Ok(Self::GlobalFreeze),
+            6 => Ok(Self::NoFreeze),
+            2 => Ok(Self::RequireAuth),
+            1 => Ok(Self::RequireDest),
+            _ => Err(Error::InvalidData(format!(
+                "Unknown AccountSet flag: {}",
+                value
+            ))),
+        }
+    }
+}
+
+impl Deserialize for AccountSetTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::AccountSet)?;
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            clear_flag: d
+                .deserialize_uint32("ClearFlag")?
+                .map(AccountSetFlag::try_from)
+                .transpose()?,
+            domain: d.deserialize_blob("Domain")?,
+            email_hash: d.deserialize_hash128("EmailHash")?,
+            message_key: d.deserialize_blob("MessageKey")?,
+            nf_token_minter: d.deserialize_blob("NFTokenMinter")?,
+            set_flag: d
+                .deserialize_uint32("SetFlag")?
+                .map(AccountSetFlag::try_from)
+                .transpose()?,
+            transfer_rate: d.deserialize_uint32("TransferRate")?,
+            tick_size: d.deserialize_uint8("TickSize")?,
+            wallet_locator: d.deserialize_hash256("WalletLocator")?,
+            wallet_size: d.deserialize_uint32("WalletSize")?,
+        })
+    }
+}
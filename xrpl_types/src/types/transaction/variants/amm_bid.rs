@@ -0,0 +1,138 @@
+use crate::alloc::vec::Vec;
+use crate::deserialize::{required, Deserialize, Deserializer};
+use crate::serialize::{Serialize, SerializeArray, Serializer};
+use crate::types::transaction::{deserialize_flags, expect_transaction_type};
+use crate::{AccountId, Amount, Currency, Transaction, TransactionCommon, TransactionType};
+use enumflags2::{bitflags, BitFlags};
+
+/// An `AMMBid` transaction <https://xrpl.org/ammbid.html>
+#[derive(Debug, Clone)]
+pub struct AMMBidTransaction {
+    pub common: TransactionCommon,
+    pub flags: BitFlags<AMMBidFlags>,
+    /// First asset of the pool
+    pub asset: Currency,
+    /// Second asset of the pool
+    pub asset2: Currency,
+    /// Minimum amount of LP tokens to pay for the auction slot
+    pub bid_min: Option<Amount>,
+    /// Maximum amount of LP tokens to pay for the auction slot
+    pub bid_max: Option<Amount>,
+    /// Additional accounts that trade at the discounted fee, at most 4
+    pub auth_accounts: Vec<AuthAccount>,
+}
+
+/// Account authorized to trade at the discounted fee of an auction slot
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct AuthAccount {
+    pub account: AccountId,
+}
+
+impl AMMBidTransaction {
+    pub fn new(account_id: AccountId, asset: Currency, asset2: Currency) -> Self {
+        Self {
+            common: TransactionCommon::new(account_id),
+            flags: Default::default(),
+            asset,
+            asset2,
+            bid_min: None,
+            bid_max: None,
+            auth_accounts: Vec::default(),
+        }
+    }
+
+    pub fn bid_min(self, bid_min: Amount) -> Self {
+        Self {
+            bid_min: Some(bid_min),
+            ..self
+        }
+    }
+
+    pub fn bid_max(self, bid_max: Amount) -> Self {
+        Self {
+            bid_max: Some(bid_max),
+            ..self
+        }
+    }
+
+    pub fn auth_accounts(self, auth_accounts: impl IntoIterator<Item = AccountId>) -> Self {
+        Self {
+            auth_accounts: auth_accounts
+                .into_iter()
+                .map(|account| AuthAccount { account })
+                .collect(),
+            ..self
+        }
+    }
+}
+
+impl Transaction for AMMBidTransaction {
+    fn common(&self) -> &TransactionCommon {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut TransactionCommon {
+        &mut self.common
+    }
+}
+
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AMMBidFlags {
+    FullyCanonicalSig = 0x80000000,
+}
+
+impl Serialize for AMMBidTransaction {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("TransactionType", TransactionType::AMMBid as u16)?;
+        self.common.serialize(s)?;
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        s.serialize_issue("Asset", self.asset)?;
+        s.serialize_issue("Asset2", self.asset2)?;
+        if let Some(bid_min) = self.bid_min {
+            s.serialize_amount("BidMin", bid_min)?;
+        }
+        if let Some(bid_max) = self.bid_max {
+            s.serialize_amount("BidMax", bid_max)?;
+        }
+        if !self.auth_accounts.is_empty() {
+            let mut array = s.serialize_array("AuthAccounts")?;
+            for auth_account in &self.auth_accounts {
+                array.serialize_object("AuthAccount", auth_account)?;
+            }
+            array.end()?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for AuthAccount {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_account_id("Account", self.account)?;
+        Ok(())
+    }
+}
+
+impl Deserialize for AMMBidTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::AMMBid)?;
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            asset: required("Asset", d.deserialize_issue("Asset")?)?,
+            asset2: required("Asset2", d.deserialize_issue("Asset2")?)?,
+            bid_min: d.deserialize_amount("BidMin")?,
+            bid_max: d.deserialize_amount("BidMax")?,
+            auth_accounts: d.deserialize_array("AuthAccounts", "AuthAccount")?,
+        })
+    }
+}
+
+impl Deserialize for AuthAccount {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        Ok(Self {
+            account: required("Account", d.deserialize_account_id("Account")?)?,
+        })
+    }
+}
@@ -0,0 +1,77 @@
+use crate::deserialize::{required, Deserialize, Deserializer};
+use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_flags, expect_transaction_type};
+use crate::{AccountId, Amount, Transaction, TransactionCommon, TransactionType, UInt16};
+use enumflags2::{bitflags, BitFlags};
+
+/// An `AMMCreate` transaction <https://xrpl.org/ammcreate.html>
+#[derive(Debug, Clone)]
+pub struct AMMCreateTransaction {
+    pub common: TransactionCommon,
+    pub flags: BitFlags<AMMCreateFlags>,
+    /// Initial deposit of the first asset of the pool
+    pub amount: Amount,
+    /// Initial deposit of the second asset of the pool
+    pub amount2: Amount,
+    /// Trading fee of the pool, in units of 1/100,000. At most 1000, a 1% fee.
+    pub trading_fee: UInt16,
+}
+
+impl AMMCreateTransaction {
+    pub fn new(
+        account_id: AccountId,
+        amount: Amount,
+        amount2: Amount,
+        trading_fee: UInt16,
+    ) -> Self {
+        Self {
+            common: TransactionCommon::new(account_id),
+            flags: Default::default(),
+            amount,
+            amount2,
+            trading_fee,
+        }
+    }
+}
+
+impl Transaction for AMMCreateTransaction {
+    fn common(&self) -> &TransactionCommon {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut TransactionCommon {
+        &mut self.common
+    }
+}
+
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AMMCreateFlags {
+    FullyCanonicalSig = 0x80000000,
+}
+
+impl Serialize for AMMCreateTransaction {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("TransactionType", TransactionType::AMMCreate as u16)?;
+        self.common.serialize(s)?;
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        s.serialize_amount("Amount", self.amount)?;
+        s.serialize_amount("Amount2", self.amount2)?;
+        s.serialize_uint16("TradingFee", self.trading_fee)?;
+        Ok(())
+    }
+}
+
+impl Deserialize for AMMCreateTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::AMMCreate)?;
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            amount: required("Amount", d.deserialize_amount("Amount")?)?,
+            amount2: required("Amount2", d.deserialize_amount("Amount2")?)?,
+            trading_fee: required("TradingFee", d.deserialize_uint16("TradingFee")?)?,
+        })
+    }
+}
@@ -0,0 +1,68 @@
+use crate::deserialize::{required, Deserialize, Deserializer};
+use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_flags, expect_transaction_type};
+use crate::{AccountId, Currency, Transaction, TransactionCommon, TransactionType};
+use enumflags2::{bitflags, BitFlags};
+
+/// An `AMMDelete` transaction <https://xrpl.org/ammdelete.html>. Deletes an empty pool that
+/// could not be fully deleted by the last withdrawal because of too many trust lines.
+#[derive(Debug, Clone)]
+pub struct AMMDeleteTransaction {
+    pub common: TransactionCommon,
+    pub flags: BitFlags<AMMDeleteFlags>,
+    /// First asset of the pool
+    pub asset: Currency,
+    /// Second asset of the pool
+    pub asset2: Currency,
+}
+
+impl AMMDeleteTransaction {
+    pub fn new(account_id: AccountId, asset: Currency, asset2: Currency) -> Self {
+        Self {
+            common: TransactionCommon::new(account_id),
+            flags: Default::default(),
+            asset,
+            asset2,
+        }
+    }
+}
+
+impl Transaction for AMMDeleteTransaction {
+    fn common(&self) -> &TransactionCommon {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut TransactionCommon {
+        &mut self.common
+    }
+}
+
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AMMDeleteFlags {
+    FullyCanonicalSig = 0x80000000,
+}
+
+impl Serialize for AMMDeleteTransaction {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("TransactionType", TransactionType::AMMDelete as u16)?;
+        self.common.serialize(s)?;
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        s.serialize_issue("Asset", self.asset)?;
+        s.serialize_issue("Asset2", self.asset2)?;
+        Ok(())
+    }
+}
+
+impl Deserialize for AMMDeleteTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::AMMDelete)?;
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            asset: required("Asset", d.deserialize_issue("Asset")?)?,
+            asset2: required("Asset2", d.deserialize_issue("Asset2")?)?,
+        })
+    }
+}
@@ -0,0 +1,208 @@
+use crate::deserialize::{required, Deserialize, Deserializer};
+use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_flags, expect_transaction_type};
+use crate::{AccountId, Amount, Currency, Transaction, TransactionCommon, TransactionType, UInt16};
+use enumflags2::{bitflags, BitFlags};
+
+/// An `AMMDeposit` transaction <https://xrpl.org/ammdeposit.html>. The deposit mode is
+/// selected by exactly one of the mode flags, see the `new_*` constructors.
+#[derive(Debug, Clone)]
+pub struct AMMDepositTransaction {
+    pub common: TransactionCommon,
+    pub flags: BitFlags<AMMDepositFlags>,
+    /// First asset of the pool
+    pub asset: Currency,
+    /// Second asset of the pool
+    pub asset2: Currency,
+    /// Amount of one asset to deposit, or the maximum to deposit
+    pub amount: Option<Amount>,
+    /// Amount of the other asset to deposit, or the maximum to deposit
+    pub amount2: Option<Amount>,
+    /// Maximum effective price per LP token paid
+    pub e_price: Option<Amount>,
+    /// Amount of LP tokens to receive
+    pub lp_token_out: Option<Amount>,
+    /// Trading fee to set when depositing into an empty pool
+    pub trading_fee: Option<UInt16>,
+}
+
+impl AMMDepositTransaction {
+    fn with_mode(
+        account_id: AccountId,
+        asset: Currency,
+        asset2: Currency,
+        mode: AMMDepositFlags,
+    ) -> Self {
+        Self {
+            common: TransactionCommon::new(account_id),
+            flags: mode.into(),
+            asset,
+            asset2,
+            amount: None,
+            amount2: None,
+            e_price: None,
+            lp_token_out: None,
+            trading_fee: None,
+        }
+    }
+
+    /// Deposits both assets in proportion to the pool, to receive the given amount of LP tokens
+    pub fn new_lp_token(
+        account_id: AccountId,
+        asset: Currency,
+        asset2: Currency,
+        lp_token_out: Amount,
+    ) -> Self {
+        Self {
+            lp_token_out: Some(lp_token_out),
+            ..Self::with_mode(account_id, asset, asset2, AMMDepositFlags::LPToken)
+        }
+    }
+
+    /// Deposits the given amount of one asset
+    pub fn new_single_asset(
+        account_id: AccountId,
+        asset: Currency,
+        asset2: Currency,
+        amount: Amount,
+    ) -> Self {
+        Self {
+            amount: Some(amount),
+            ..Self::with_mode(account_id, asset, asset2, AMMDepositFlags::SingleAsset)
+        }
+    }
+
+    /// Deposits both assets in proportion to the pool, up to the given amounts
+    pub fn new_two_asset(
+        account_id: AccountId,
+        asset: Currency,
+        asset2: Currency,
+        amount: Amount,
+        amount2: Amount,
+    ) -> Self {
+        Self {
+            amount: Some(amount),
+            amount2: Some(amount2),
+            ..Self::with_mode(account_id, asset, asset2, AMMDepositFlags::TwoAsset)
+        }
+    }
+
+    /// Deposits up to the given amount of one asset, to receive the given amount of LP tokens
+    pub fn new_one_asset_lp_token(
+        account_id: AccountId,
+        asset: Currency,
+        asset2: Currency,
+        amount: Amount,
+        lp_token_out: Amount,
+    ) -> Self {
+        Self {
+            amount: Some(amount),
+            lp_token_out: Some(lp_token_out),
+            ..Self::with_mode(account_id, asset, asset2, AMMDepositFlags::OneAssetLPToken)
+        }
+    }
+
+    /// Deposits up to the given amount of one asset, paying at most the given effective
+    /// price per LP token
+    pub fn new_limit_lp_token(
+        account_id: AccountId,
+        asset: Currency,
+        asset2: Currency,
+        amount: Amount,
+        e_price: Amount,
+    ) -> Self {
+        Self {
+            amount: Some(amount),
+            e_price: Some(e_price),
+            ..Self::with_mode(account_id, asset, asset2, AMMDepositFlags::LimitLPToken)
+        }
+    }
+
+    /// Deposits the given amounts of both assets into a pool that is empty
+    pub fn new_two_asset_if_empty(
+        account_id: AccountId,
+        asset: Currency,
+        asset2: Currency,
+        amount: Amount,
+        amount2: Amount,
+    ) -> Self {
+        Self {
+            amount: Some(amount),
+            amount2: Some(amount2),
+            ..Self::with_mode(account_id, asset, asset2, AMMDepositFlags::TwoAssetIfEmpty)
+        }
+    }
+
+    pub fn trading_fee(self, trading_fee: UInt16) -> Self {
+        Self {
+            trading_fee: Some(trading_fee),
+            ..self
+        }
+    }
+}
+
+impl Transaction for AMMDepositTransaction {
+    fn common(&self) -> &TransactionCommon {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut TransactionCommon {
+        &mut self.common
+    }
+}
+
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AMMDepositFlags {
+    FullyCanonicalSig = 0x80000000,
+    LPToken = 0x00010000,
+    SingleAsset = 0x00080000,
+    TwoAsset = 0x00100000,
+    OneAssetLPToken = 0x00200000,
+    LimitLPToken = 0x00400000,
+    TwoAssetIfEmpty = 0x00800000,
+}
+
+impl Serialize for AMMDepositTransaction {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("TransactionType", TransactionType::AMMDeposit as u16)?;
+        self.common.serialize(s)?;
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        s.serialize_issue("Asset", self.asset)?;
+        s.serialize_issue("Asset2", self.asset2)?;
+        if let Some(amount) = self.amount {
+            s.serialize_amount("Amount", amount)?;
+        }
+        if let Some(amount2) = self.amount2 {
+            s.serialize_amount("Amount2", amount2)?;
+        }
+        if let Some(e_price) = self.e_price {
+            s.serialize_amount("EPrice", e_price)?;
+        }
+        if let Some(lp_token_out) = self.lp_token_out {
+            s.serialize_amount("LPTokenOut", lp_token_out)?;
+        }
+        if let Some(trading_fee) = self.trading_fee {
+            s.serialize_uint16("TradingFee", trading_fee)?;
+        }
+        Ok(())
+    }
+}
+
+impl Deserialize for AMMDepositTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::AMMDeposit)?;
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            asset: required("Asset", d.deserialize_issue("Asset")?)?,
+            asset2: required("Asset2", d.deserialize_issue("Asset2")?)?,
+            amount: d.deserialize_amount("Amount")?,
+            amount2: d.deserialize_amount("Amount2")?,
+            e_price: d.deserialize_amount("EPrice")?,
+            lp_token_out: d.deserialize_amount("LPTokenOut")?,
+            trading_fee: d.deserialize_uint16("TradingFee")?,
+        })
+    }
+}
@@ -0,0 +1,77 @@
+use crate::deserialize::{required, Deserialize, Deserializer};
+use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_flags, expect_transaction_type};
+use crate::{AccountId, Currency, Transaction, TransactionCommon, TransactionType, UInt16};
+use enumflags2::{bitflags, BitFlags};
+
+/// An `AMMVote` transaction <https://xrpl.org/ammvote.html>
+#[derive(Debug, Clone)]
+pub struct AMMVoteTransaction {
+    pub common: TransactionCommon,
+    pub flags: BitFlags<AMMVoteFlags>,
+    /// First asset of the pool
+    pub asset: Currency,
+    /// Second asset of the pool
+    pub asset2: Currency,
+    /// Proposed trading fee, in units of 1/100,000. At most 1000, a 1% fee.
+    pub trading_fee: UInt16,
+}
+
+impl AMMVoteTransaction {
+    pub fn new(
+        account_id: AccountId,
+        asset: Currency,
+        asset2: Currency,
+        trading_fee: UInt16,
+    ) -> Self {
+        Self {
+            common: TransactionCommon::new(account_id),
+            flags: Default::default(),
+            asset,
+            asset2,
+            trading_fee,
+        }
+    }
+}
+
+impl Transaction for AMMVoteTransaction {
+    fn common(&self) -> &TransactionCommon {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut TransactionCommon {
+        &mut self.common
+    }
+}
+
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AMMVoteFlags {
+    FullyCanonicalSig = 0x80000000,
+}
+
+impl Serialize for AMMVoteTransaction {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("TransactionType", TransactionType::AMMVote as u16)?;
+        self.common.serialize(s)?;
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        s.serialize_issue("Asset", self.asset)?;
+        s.serialize_issue("Asset2", self.asset2)?;
+        s.serialize_uint16("TradingFee", self.trading_fee)?;
+        Ok(())
+    }
+}
+
+impl Deserialize for AMMVoteTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::AMMVote)?;
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            asset: required("Asset", d.deserialize_issue("Asset")?)?,
+            asset2: required("Asset2", d.deserialize_issue("Asset2")?)?,
+            trading_fee: required("TradingFee", d.deserialize_uint16("TradingFee")?)?,
+        })
+    }
+}
@@ -0,0 +1,203 @@
+use crate::deserialize::{required, Deserialize, Deserializer};
+use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_flags, expect_transaction_type};
+use crate::{AccountId, Amount, Currency, Transaction, TransactionCommon, TransactionType};
+use enumflags2::{bitflags, BitFlags};
+
+/// An `AMMWithdraw` transaction <https://xrpl.org/ammwithdraw.html>. The withdrawal mode is
+/// selected by exactly one of the mode flags, see the `new_*` constructors.
+#[derive(Debug, Clone)]
+pub struct AMMWithdrawTransaction {
+    pub common: TransactionCommon,
+    pub flags: BitFlags<AMMWithdrawFlags>,
+    /// First asset of the pool
+    pub asset: Currency,
+    /// Second asset of the pool
+    pub asset2: Currency,
+    /// Amount of one asset to withdraw, or the minimum to withdraw
+    pub amount: Option<Amount>,
+    /// Amount of the other asset to withdraw, or the minimum to withdraw
+    pub amount2: Option<Amount>,
+    /// Minimum effective price per LP token returned
+    pub e_price: Option<Amount>,
+    /// Amount of LP tokens to return
+    pub lp_token_in: Option<Amount>,
+}
+
+impl AMMWithdrawTransaction {
+    fn with_mode(
+        account_id: AccountId,
+        asset: Currency,
+        asset2: Currency,
+        mode: AMMWithdrawFlags,
+    ) -> Self {
+        Self {
+            common: TransactionCommon::new(account_id),
+            flags: mode.into(),
+            asset,
+            asset2,
+            amount: None,
+            amount2: None,
+            e_price: None,
+            lp_token_in: None,
+        }
+    }
+
+    /// Returns the given amount of LP tokens, to withdraw both assets in proportion to the pool
+    pub fn new_lp_token(
+        account_id: AccountId,
+        asset: Currency,
+        asset2: Currency,
+        lp_token_in: Amount,
+    ) -> Self {
+        Self {
+            lp_token_in: Some(lp_token_in),
+            ..Self::with_mode(account_id, asset, asset2, AMMWithdrawFlags::LPToken)
+        }
+    }
+
+    /// Returns all LP tokens, to withdraw both assets in proportion to the pool
+    pub fn new_withdraw_all(account_id: AccountId, asset: Currency, asset2: Currency) -> Self {
+        Self::with_mode(account_id, asset, asset2, AMMWithdrawFlags::WithdrawAll)
+    }
+
+    /// Returns all LP tokens, to withdraw at least the given amount of one asset
+    pub fn new_one_asset_withdraw_all(
+        account_id: AccountId,
+        asset: Currency,
+        asset2: Currency,
+        amount: Amount,
+    ) -> Self {
+        Self {
+            amount: Some(amount),
+            ..Self::with_mode(
+                account_id,
+                asset,
+                asset2,
+                AMMWithdrawFlags::OneAssetWithdrawAll,
+            )
+        }
+    }
+
+    /// Withdraws the given amount of one asset
+    pub fn new_single_asset(
+        account_id: AccountId,
+        asset: Currency,
+        asset2: Currency,
+        amount: Amount,
+    ) -> Self {
+        Self {
+            amount: Some(amount),
+            ..Self::with_mode(account_id, asset, asset2, AMMWithdrawFlags::SingleAsset)
+        }
+    }
+
+    /// Withdraws both assets in proportion to the pool, up to the given amounts
+    pub fn new_two_asset(
+        account_id: AccountId,
+        asset: Currency,
+        asset2: Currency,
+        amount: Amount,
+        amount2: Amount,
+    ) -> Self {
+        Self {
+            amount: Some(amount),
+            amount2: Some(amount2),
+            ..Self::with_mode(account_id, asset, asset2, AMMWithdrawFlags::TwoAsset)
+        }
+    }
+
+    /// Returns the given amount of LP tokens, to withdraw at least the given amount of one asset
+    pub fn new_one_asset_lp_token(
+        account_id: AccountId,
+        asset: Currency,
+        asset2: Currency,
+        amount: Amount,
+        lp_token_in: Amount,
+    ) -> Self {
+        Self {
+            amount: Some(amount),
+            lp_token_in: Some(lp_token_in),
+            ..Self::with_mode(account_id, asset, asset2, AMMWithdrawFlags::OneAssetLPToken)
+        }
+    }
+
+    /// Withdraws up to the given amount of one asset, receiving at least the given effective
+    /// price per LP token
+    pub fn new_limit_lp_token(
+        account_id: AccountId,
+        asset: Currency,
+        asset2: Currency,
+        amount: Amount,
+        e_price: Amount,
+    ) -> Self {
+        Self {
+            amount: Some(amount),
+            e_price: Some(e_price),
+            ..Self::with_mode(account_id, asset, asset2, AMMWithdrawFlags::LimitLPToken)
+        }
+    }
+}
+
+impl Transaction for AMMWithdrawTransaction {
+    fn common(&self) -> &TransactionCommon {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut TransactionCommon {
+        &mut self.common
+    }
+}
+
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AMMWithdrawFlags {
+    FullyCanonicalSig = 0x80000000,
+    LPToken = 0x00010000,
+    WithdrawAll = 0x00020000,
+    OneAssetWithdrawAll = 0x00040000,
+    SingleAsset = 0x00080000,
+    TwoAsset = 0x00100000,
+    OneAssetLPToken = 0x00200000,
+    LimitLPToken = 0x00400000,
+}
+
+impl Serialize for AMMWithdrawTransaction {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("TransactionType", TransactionType::AMMWithdraw as u16)?;
+        self.common.serialize(s)?;
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        s.serialize_issue("Asset", self.asset)?;
+        s.serialize_issue("Asset2", self.asset2)?;
+        if let Some(amount) = self.amount {
+            s.serialize_amount("Amount", amount)?;
+        }
+        if let Some(amount2) = self.amount2 {
+            s.serialize_amount("Amount2", amount2)?;
+        }
+        if let Some(e_price) = self.e_price {
+            s.serialize_amount("EPrice", e_price)?;
+        }
+        if let Some(lp_token_in) = self.lp_token_in {
+            s.serialize_amount("LPTokenIn", lp_token_in)?;
+        }
+        Ok(())
+    }
+}
+
+impl Deserialize for AMMWithdrawTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::AMMWithdraw)?;
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            asset: required("Asset", d.deserialize_issue("Asset")?)?,
+            asset2: required("Asset2", d.deserialize_issue("Asset2")?)?,
+            amount: d.deserialize_amount("Amount")?,
+            amount2: d.deserialize_amount("Amount2")?,
+            e_price: d.deserialize_amount("EPrice")?,
+            lp_token_in: d.deserialize_amount("LPTokenIn")?,
+        })
+    }
+}
@@ -0,0 +1,61 @@
+use crate::deserialize::{required, Deserialize, Deserializer};
+use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_flags, expect_transaction_type};
+use crate::{AccountId, Hash256, Transaction, TransactionCommon, TransactionType};
+use enumflags2::{bitflags, BitFlags};
+
+/// A `CheckCancel` transaction <https://xrpl.org/checkcancel.html>
+#[derive(Debug, Clone)]
+pub struct CheckCancelTransaction {
+    pub common: TransactionCommon,
+    pub flags: BitFlags<CheckCancelFlags>,
+    pub check_id: Hash256,
+}
+
+impl CheckCancelTransaction {
+    pub fn new(account_id: AccountId, check_id: Hash256) -> Self {
+        Self {
+            common: TransactionCommon::new(account_id),
+            flags: Default::default(),
+            check_id,
+        }
+    }
+}
+
+impl Transaction for CheckCancelTransaction {
+    fn common(&self) -> &TransactionCommon {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut TransactionCommon {
+        &mut self.common
+    }
+}
+
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CheckCancelFlags {
+    FullyCanonicalSig = 0x80000000,
+}
+
+impl Serialize for CheckCancelTransaction {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("TransactionType", TransactionType::CheckCancel as u16)?;
+        self.common.serialize(s)?;
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        s.serialize_hash256("CheckID", self.check_id)?;
+        Ok(())
+    }
+}
+
+impl Deserialize for CheckCancelTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::CheckCancel)?;
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            check_id: required("CheckID", d.deserialize_hash256("CheckID")?)?,
+        })
+    }
+}
@@ -0,0 +1,95 @@
+use crate::alloc::format;
+use crate::deserialize::{required, Deserialize, Deserializer};
+use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_flags, expect_transaction_type};
+use crate::{AccountId, Amount, Error, Hash256, Transaction, TransactionCommon, TransactionType};
+use enumflags2::{bitflags, BitFlags};
+
+/// A `CheckCash` transaction <https://xrpl.org/checkcash.html>
+#[derive(Debug, Clone)]
+pub struct CheckCashTransaction {
+    pub common: TransactionCommon,
+    pub flags: BitFlags<CheckCashFlags>,
+    pub check_id: Hash256,
+    pub amount: CheckCashAmount,
+}
+
+/// The amount to cash a check for, exactly one of `Amount` or `DeliverMin`
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CheckCashAmount {
+    /// Cash the check for exactly this amount
+    Amount(Amount),
+    /// Cash the check for as much as possible, but at least this amount
+    DeliverMin(Amount),
+}
+
+impl CheckCashTransaction {
+    pub fn new(account_id: AccountId, check_id: Hash256, amount: CheckCashAmount) -> Self {
+        Self {
+            common: TransactionCommon::new(account_id),
+            flags: Default::default(),
+            check_id,
+            amount,
+        }
+    }
+}
+
+impl Transaction for CheckCashTransaction {
+    fn common(&self) -> &TransactionCommon {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut TransactionCommon {
+        &mut self.common
+    }
+}
+
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CheckCashFlags {
+    FullyCanonicalSig = 0x80000000,
+}
+
+impl Serialize for CheckCashTransaction {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("TransactionType", TransactionType::CheckCash as u16)?;
+        self.common.serialize(s)?;
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        s.serialize_hash256("CheckID", self.check_id)?;
+        match self.amount {
+            CheckCashAmount::Amount(amount) => s.serialize_amount("Amount", amount)?,
+            CheckCashAmount::DeliverMin(amount) => s.serialize_amount("DeliverMin", amount)?,
+        }
+        Ok(())
+    }
+}
+
+impl Deserialize for CheckCashTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::CheckCash)?;
+        let common = TransactionCommon::deserialize(d)?;
+        let flags = deserialize_flags(d)?;
+        let check_id = required("CheckID", d.deserialize_hash256("CheckID")?)?;
+        let amount = match (
+            d.deserialize_amount("Amount")?,
+            d.deserialize_amount("DeliverMin")?,
+        ) {
+            (Some(amount), None) => CheckCashAmount::Amount(amount),
+            (None, Some(deliver_min)) => CheckCashAmount::DeliverMin(deliver_min),
+            (amount, _) => {
+                return Err(Error::InvalidData(format!(
+                    "Expected exactly one of Amount and DeliverMin, found {}",
+                    if amount.is_some() { "both" } else { "neither" }
+                ))
+                .into())
+            }
+        };
+        Ok(Self {
+            common,
+            flags,
+            check_id,
+            amount,
+        })
+    }
+}
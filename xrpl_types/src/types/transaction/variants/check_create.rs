@@ -0,0 +1,109 @@
+use crate::deserialize::{required, Deserialize, Deserializer};
+use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_flags, expect_transaction_type};
+use crate::{
+    AccountId, Amount, Hash256, LedgerTimestamp, Transaction, TransactionCommon, TransactionType,
+    UInt32,
+};
+use enumflags2::{bitflags, BitFlags};
+
+/// A `CheckCreate` transaction <https://xrpl.org/checkcreate.html>
+#[derive(Debug, Clone)]
+pub struct CheckCreateTransaction {
+    pub common: TransactionCommon,
+    pub flags: BitFlags<CheckCreateFlags>,
+    pub destination: AccountId,
+    /// Maximum amount the check can debit from the sender, including transfer fees
+    pub send_max: Amount,
+    pub destination_tag: Option<UInt32>,
+    pub expiration: Option<LedgerTimestamp>,
+    /// Arbitrary 256-bit hash identifying the reason for the check
+    pub invoice_id: Option<Hash256>,
+}
+
+impl CheckCreateTransaction {
+    pub fn new(account_id: AccountId, destination: AccountId, send_max: Amount) -> Self {
+        Self {
+            common: TransactionCommon::new(account_id),
+            flags: Default::default(),
+            destination,
+            send_max,
+            destination_tag: None,
+            expiration: None,
+            invoice_id: None,
+        }
+    }
+
+    pub fn destination_tag(self, tag: UInt32) -> Self {
+        Self {
+            destination_tag: Some(tag),
+            ..self
+        }
+    }
+
+    pub fn expiration(self, expiration: LedgerTimestamp) -> Self {
+        Self {
+            expiration: Some(expiration),
+            ..self
+        }
+    }
+
+    pub fn invoice_id(self, invoice_id: Hash256) -> Self {
+        Self {
+            invoice_id: Some(invoice_id),
+            ..self
+        }
+    }
+}
+
+impl Transaction for CheckCreateTransaction {
+    fn common(&self) -> &TransactionCommon {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut TransactionCommon {
+        &mut self.common
+    }
+}
+
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CheckCreateFlags {
+    FullyCanonicalSig = 0x80000000,
+}
+
+impl Serialize for CheckCreateTransaction {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("TransactionType", TransactionType::CheckCreate as u16)?;
+        self.common.serialize(s)?;
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        s.serialize_account_id("Destination", self.destination)?;
+        s.serialize_amount("SendMax", self.send_max)?;
+        if let Some(destination_tag) = self.destination_tag {
+            s.serialize_uint32("DestinationTag", destination_tag)?;
+        }
+        if let Some(expiration) = self.expiration {
+            s.serialize_uint32("Expiration", expiration)?;
+        }
+        if let Some(invoice_id) = self.invoice_id {
+            s.serialize_hash256("InvoiceID", invoice_id)?;
+        }
+        Ok(())
+    }
+}
+
+impl Deserialize for CheckCreateTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::CheckCreate)?;
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            destination: required("Destination", d.deserialize_account_id("Destination")?)?,
+            send_max: required("SendMax", d.deserialize_amount("SendMax")?)?,
+            destination_tag: d.deserialize_uint32("DestinationTag")?,
+            expiration: d.deserialize_uint32("Expiration")?,
+            invoice_id: d.deserialize_hash256("InvoiceID")?,
+        })
+    }
+}
@@ -0,0 +1,67 @@
+use crate::deserialize::{required, Deserialize, Deserializer};
+use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_flags, expect_transaction_type};
+use crate::{AccountId, Transaction, TransactionCommon, TransactionType, UInt32};
+use enumflags2::{bitflags, BitFlags};
+
+/// An `EscrowCancel` transaction <https://xrpl.org/escrowcancel.html>
+#[derive(Debug, Clone)]
+pub struct EscrowCancelTransaction {
+    pub common: TransactionCommon,
+    pub flags: BitFlags<EscrowCancelFlags>,
+    /// Account that created the escrow
+    pub owner: AccountId,
+    /// Sequence number of the `EscrowCreate` transaction that created the escrow
+    pub offer_sequence: UInt32,
+}
+
+impl EscrowCancelTransaction {
+    pub fn new(account_id: AccountId, owner: AccountId, offer_sequence: UInt32) -> Self {
+        Self {
+            common: TransactionCommon::new(account_id),
+            flags: Default::default(),
+            owner,
+            offer_sequence,
+        }
+    }
+}
+
+impl Transaction for EscrowCancelTransaction {
+    fn common(&self) -> &TransactionCommon {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut TransactionCommon {
+        &mut self.common
+    }
+}
+
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum EscrowCancelFlags {
+    FullyCanonicalSig = 0x80000000,
+}
+
+impl Serialize for EscrowCancelTransaction {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("TransactionType", TransactionType::EscrowCancel as u16)?;
+        self.common.serialize(s)?;
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        s.serialize_account_id("Owner", self.owner)?;
+        s.serialize_uint32("OfferSequence", self.offer_sequence)?;
+        Ok(())
+    }
+}
+
+impl Deserialize for EscrowCancelTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::EscrowCancel)?;
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            owner: required("Owner", d.deserialize_account_id("Owner")?)?,
+            offer_sequence: required("OfferSequence", d.deserialize_uint32("OfferSequence")?)?,
+        })
+    }
+}
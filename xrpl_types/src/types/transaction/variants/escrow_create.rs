@@ -0,0 +1,123 @@
+use crate::deserialize::{required, Deserialize, Deserializer};
+use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_flags, expect_transaction_type};
+use crate::{
+    AccountId, Amount, Blob, LedgerTimestamp, Transaction, TransactionCommon, TransactionType,
+    UInt32,
+};
+use enumflags2::{bitflags, BitFlags};
+
+/// An `EscrowCreate` transaction <https://xrpl.org/escrowcreate.html>
+#[derive(Debug, Clone)]
+pub struct EscrowCreateTransaction {
+    pub common: TransactionCommon,
+    pub flags: BitFlags<EscrowCreateFlags>,
+    pub amount: Amount,
+    pub destination: AccountId,
+    pub destination_tag: Option<UInt32>,
+    /// Time after which the escrow expires and can be cancelled
+    pub cancel_after: Option<LedgerTimestamp>,
+    /// Time after which the escrow can be finished
+    pub finish_after: Option<LedgerTimestamp>,
+    /// PREIMAGE-SHA-256 crypto-condition that must be fulfilled to finish the escrow
+    pub condition: Option<Blob>,
+}
+
+impl EscrowCreateTransaction {
+    pub fn new(account_id: AccountId, amount: Amount, destination: AccountId) -> Self {
+        Self {
+            common: TransactionCommon::new(account_id),
+            flags: Default::default(),
+            amount,
+            destination,
+            destination_tag: None,
+            cancel_after: None,
+            finish_after: None,
+            condition: None,
+        }
+    }
+
+    pub fn destination_tag(self, tag: UInt32) -> Self {
+        Self {
+            destination_tag: Some(tag),
+            ..self
+        }
+    }
+
+    pub fn cancel_after(self, cancel_after: LedgerTimestamp) -> Self {
+        Self {
+            cancel_after: Some(cancel_after),
+            ..self
+        }
+    }
+
+    pub fn finish_after(self, finish_after: LedgerTimestamp) -> Self {
+        Self {
+            finish_after: Some(finish_after),
+            ..self
+        }
+    }
+
+    pub fn condition(self, condition: Blob) -> Self {
+        Self {
+            condition: Some(condition),
+            ..self
+        }
+    }
+}
+
+impl Transaction for EscrowCreateTransaction {
+    fn common(&self) -> &TransactionCommon {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut TransactionCommon {
+        &mut self.common
+    }
+}
+
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum EscrowCreateFlags {
+    FullyCanonicalSig = 0x80000000,
+}
+
+impl Serialize for EscrowCreateTransaction {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("TransactionType", TransactionType::EscrowCreate as u16)?;
+        self.common.serialize(s)?;
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        s.serialize_amount("Amount", self.amount)?;
+        s.serialize_account_id("Destination", self.destination)?;
+        if let Some(destination_tag) = self.destination_tag {
+            s.serialize_uint32("DestinationTag", destination_tag)?;
+        }
+        if let Some(cancel_after) = self.cancel_after {
+            s.serialize_uint32("CancelAfter", cancel_after)?;
+        }
+        if let Some(finish_after) = self.finish_after {
+            s.serialize_uint32("FinishAfter", finish_after)?;
+        }
+        if let Some(condition) = &self.condition {
+            s.serialize_blob("Condition", condition)?;
+        }
+        Ok(())
+    }
+}
+
+impl Deserialize for EscrowCreateTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::EscrowCreate)?;
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            amount: required("Amount", d.deserialize_amount("Amount")?)?,
+            destination: required("Destination", d.deserialize_account_id("Destination")?)?,
+            destination_tag: d.deserialize_uint32("DestinationTag")?,
+            cancel_after: d.deserialize_uint32("CancelAfter")?,
+            finish_after: d.deserialize_uint32("FinishAfter")?,
+            condition: d.deserialize_blob("Condition")?,
+        })
+    }
+}
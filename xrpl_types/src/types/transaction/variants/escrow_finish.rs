@@ -0,0 +1,90 @@
+use crate::deserialize::{required, Deserialize, Deserializer};
+use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_flags, expect_transaction_type};
+use crate::{AccountId, Blob, Transaction, TransactionCommon, TransactionType, UInt32};
+use enumflags2::{bitflags, BitFlags};
+
+/// An `EscrowFinish` transaction <https://xrpl.org/escrowfinish.html>
+#[derive(Debug, Clone)]
+pub struct EscrowFinishTransaction {
+    pub common: TransactionCommon,
+    pub flags: BitFlags<EscrowFinishFlags>,
+    /// Account that created the escrow
+    pub owner: AccountId,
+    /// Sequence number of the `EscrowCreate` transaction that created the escrow
+    pub offer_sequence: UInt32,
+    /// Condition of the escrow, required for conditional escrows
+    pub condition: Option<Blob>,
+    /// Fulfillment matching the condition of the escrow
+    pub fulfillment: Option<Blob>,
+}
+
+impl EscrowFinishTransaction {
+    pub fn new(account_id: AccountId, owner: AccountId, offer_sequence: UInt32) -> Self {
+        Self {
+            common: TransactionCommon::new(account_id),
+            flags: Default::default(),
+            owner,
+            offer_sequence,
+            condition: None,
+            fulfillment: None,
+        }
+    }
+
+    /// Finishes a conditional escrow with the given condition and matching fulfillment
+    pub fn fulfill(self, condition: Blob, fulfillment: Blob) -> Self {
+        Self {
+            condition: Some(condition),
+            fulfillment: Some(fulfillment),
+            ..self
+        }
+    }
+}
+
+impl Transaction for EscrowFinishTransaction {
+    fn common(&self) -> &TransactionCommon {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut TransactionCommon {
+        &mut self.common
+    }
+}
+
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum EscrowFinishFlags {
+    FullyCanonicalSig = 0x80000000,
+}
+
+impl Serialize for EscrowFinishTransaction {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("TransactionType", TransactionType::EscrowFinish as u16)?;
+        self.common.serialize(s)?;
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        s.serialize_account_id("Owner", self.owner)?;
+        s.serialize_uint32("OfferSequence", self.offer_sequence)?;
+        if let Some(condition) = &self.condition {
+            s.serialize_blob("Condition", condition)?;
+        }
+        if let Some(fulfillment) = &self.fulfillment {
+            s.serialize_blob("Fulfillment", fulfillment)?;
+        }
+        Ok(())
+    }
+}
+
+impl Deserialize for EscrowFinishTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::EscrowFinish)?;
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            owner: required("Owner", d.deserialize_account_id("Owner")?)?,
+            offer_sequence: required("OfferSequence", d.deserialize_uint32("OfferSequence")?)?,
+            condition: d.deserialize_blob("Condition")?,
+            fulfillment: d.deserialize_blob("Fulfillment")?,
+        })
+    }
+}
@@ -1,13 +1,53 @@
 mod account_delete;
 mod account_set;
+mod amm_bid;
+mod amm_create;
+mod amm_delete;
+mod amm_deposit;
+mod amm_vote;
+mod amm_withdraw;
+mod check_cancel;
+mod check_cash;
+mod check_create;
+mod escrow_cancel;
+mod escrow_create;
+mod escrow_finish;
+mod nftoken_accept_offer;
+mod nftoken_burn;
+mod nftoken_cancel_offer;
+mod nftoken_create_offer;
+mod nftoken_mint;
 mod offer_cancel;
 mod offer_create;
 mod payment;
+mod payment_channel_claim;
+mod payment_channel_create;
+mod payment_channel_fund;
 mod trust_set;
 
 pub use account_delete::*;
 pub use account_set::*;
+pub use amm_bid::*;
+pub use amm_create::*;
+pub use amm_delete::*;
+pub use amm_deposit::*;
+pub use amm_vote::*;
+pub use amm_withdraw::*;
+pub use check_cancel::*;
+pub use check_cash::*;
+pub use check_create::*;
+pub use escrow_cancel::*;
+pub use escrow_create::*;
+pub use escrow_finish::*;
+pub use nftoken_accept_offer::*;
+pub use nftoken_burn::*;
+pub use nftoken_cancel_offer::*;
+pub use nftoken_create_offer::*;
+pub use nftoken_mint::*;
 pub use offer_cancel::*;
 pub use offer_create::*;
 pub use payment::*;
+pub use payment_channel_claim::*;
+pub use payment_channel_create::*;
+pub use payment_channel_fund::*;
 pub use trust_set::*;
@@ -0,0 +1,108 @@
+use crate::deserialize::{Deserialize, Deserializer};
+use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_flags, expect_transaction_type};
+use crate::{AccountId, Amount, Hash256, Transaction, TransactionCommon, TransactionType};
+use enumflags2::{bitflags, BitFlags};
+
+/// An `NFTokenAcceptOffer` transaction <https://xrpl.org/nftokenacceptoffer.html>.
+/// Accepts a sell offer, a buy offer, or both in brokered mode.
+#[derive(Debug, Clone)]
+pub struct NFTokenAcceptOfferTransaction {
+    pub common: TransactionCommon,
+    pub flags: BitFlags<NFTokenAcceptOfferFlags>,
+    pub nftoken_sell_offer: Option<Hash256>,
+    pub nftoken_buy_offer: Option<Hash256>,
+    /// Fee kept by the broker in brokered mode, in the currency of the offers
+    pub nftoken_broker_fee: Option<Amount>,
+}
+
+impl NFTokenAcceptOfferTransaction {
+    pub fn new_sell_offer(account_id: AccountId, sell_offer: Hash256) -> Self {
+        Self {
+            common: TransactionCommon::new(account_id),
+            flags: Default::default(),
+            nftoken_sell_offer: Some(sell_offer),
+            nftoken_buy_offer: None,
+            nftoken_broker_fee: None,
+        }
+    }
+
+    pub fn new_buy_offer(account_id: AccountId, buy_offer: Hash256) -> Self {
+        Self {
+            common: TransactionCommon::new(account_id),
+            flags: Default::default(),
+            nftoken_sell_offer: None,
+            nftoken_buy_offer: Some(buy_offer),
+            nftoken_broker_fee: None,
+        }
+    }
+
+    /// Matches the given sell and buy offers for the same token, brokered by the account
+    pub fn new_brokered(account_id: AccountId, sell_offer: Hash256, buy_offer: Hash256) -> Self {
+        Self {
+            common: TransactionCommon::new(account_id),
+            flags: Default::default(),
+            nftoken_sell_offer: Some(sell_offer),
+            nftoken_buy_offer: Some(buy_offer),
+            nftoken_broker_fee: None,
+        }
+    }
+
+    pub fn broker_fee(self, broker_fee: Amount) -> Self {
+        Self {
+            nftoken_broker_fee: Some(broker_fee),
+            ..self
+        }
+    }
+}
+
+impl Transaction for NFTokenAcceptOfferTransaction {
+    fn common(&self) -> &TransactionCommon {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut TransactionCommon {
+        &mut self.common
+    }
+}
+
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum NFTokenAcceptOfferFlags {
+    FullyCanonicalSig = 0x80000000,
+}
+
+impl Serialize for NFTokenAcceptOfferTransaction {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16(
+            "TransactionType",
+            TransactionType::NFTokenAcceptOffer as u16,
+        )?;
+        self.common.serialize(s)?;
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        if let Some(sell_offer) = self.nftoken_sell_offer {
+            s.serialize_hash256("NFTokenSellOffer", sell_offer)?;
+        }
+        if let Some(buy_offer) = self.nftoken_buy_offer {
+            s.serialize_hash256("NFTokenBuyOffer", buy_offer)?;
+        }
+        if let Some(broker_fee) = self.nftoken_broker_fee {
+            s.serialize_amount("NFTokenBrokerFee", broker_fee)?;
+        }
+        Ok(())
+    }
+}
+
+impl Deserialize for NFTokenAcceptOfferTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::NFTokenAcceptOffer)?;
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            nftoken_sell_offer: d.deserialize_hash256("NFTokenSellOffer")?,
+            nftoken_buy_offer: d.deserialize_hash256("NFTokenBuyOffer")?,
+            nftoken_broker_fee: d.deserialize_amount("NFTokenBrokerFee")?,
+        })
+    }
+}
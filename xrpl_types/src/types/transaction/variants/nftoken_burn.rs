@@ -0,0 +1,75 @@
+use crate::deserialize::{required, Deserialize, Deserializer};
+use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_flags, expect_transaction_type};
+use crate::{AccountId, Hash256, Transaction, TransactionCommon, TransactionType};
+use enumflags2::{bitflags, BitFlags};
+
+/// An `NFTokenBurn` transaction <https://xrpl.org/nftokenburn.html>
+#[derive(Debug, Clone)]
+pub struct NFTokenBurnTransaction {
+    pub common: TransactionCommon,
+    pub flags: BitFlags<NFTokenBurnFlags>,
+    pub nftoken_id: Hash256,
+    /// Owner of the token, if burned by the issuer of a burnable token
+    pub owner: Option<AccountId>,
+}
+
+impl NFTokenBurnTransaction {
+    pub fn new(account_id: AccountId, nftoken_id: Hash256) -> Self {
+        Self {
+            common: TransactionCommon::new(account_id),
+            flags: Default::default(),
+            nftoken_id,
+            owner: None,
+        }
+    }
+
+    pub fn owner(self, owner: AccountId) -> Self {
+        Self {
+            owner: Some(owner),
+            ..self
+        }
+    }
+}
+
+impl Transaction for NFTokenBurnTransaction {
+    fn common(&self) -> &TransactionCommon {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut TransactionCommon {
+        &mut self.common
+    }
+}
+
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum NFTokenBurnFlags {
+    FullyCanonicalSig = 0x80000000,
+}
+
+impl Serialize for NFTokenBurnTransaction {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("TransactionType", TransactionType::NFTokenBurn as u16)?;
+        self.common.serialize(s)?;
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        s.serialize_hash256("NFTokenID", self.nftoken_id)?;
+        if let Some(owner) = self.owner {
+            s.serialize_account_id("Owner", owner)?;
+        }
+        Ok(())
+    }
+}
+
+impl Deserialize for NFTokenBurnTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::NFTokenBurn)?;
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            nftoken_id: required("NFTokenID", d.deserialize_hash256("NFTokenID")?)?,
+            owner: d.deserialize_account_id("Owner")?,
+        })
+    }
+}
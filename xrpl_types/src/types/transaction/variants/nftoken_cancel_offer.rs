@@ -0,0 +1,66 @@
+use crate::alloc::vec::Vec;
+use crate::deserialize::{required, Deserialize, Deserializer};
+use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_flags, expect_transaction_type};
+use crate::{AccountId, Hash256, Transaction, TransactionCommon, TransactionType};
+use enumflags2::{bitflags, BitFlags};
+
+/// An `NFTokenCancelOffer` transaction <https://xrpl.org/nftokencanceloffer.html>
+#[derive(Debug, Clone)]
+pub struct NFTokenCancelOfferTransaction {
+    pub common: TransactionCommon,
+    pub flags: BitFlags<NFTokenCancelOfferFlags>,
+    /// Ids of the `NFTokenOffer` objects to cancel
+    pub nftoken_offers: Vec<Hash256>,
+}
+
+impl NFTokenCancelOfferTransaction {
+    pub fn new(account_id: AccountId, nftoken_offers: Vec<Hash256>) -> Self {
+        Self {
+            common: TransactionCommon::new(account_id),
+            flags: Default::default(),
+            nftoken_offers,
+        }
+    }
+}
+
+impl Transaction for NFTokenCancelOfferTransaction {
+    fn common(&self) -> &TransactionCommon {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut TransactionCommon {
+        &mut self.common
+    }
+}
+
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum NFTokenCancelOfferFlags {
+    FullyCanonicalSig = 0x80000000,
+}
+
+impl Serialize for NFTokenCancelOfferTransaction {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16(
+            "TransactionType",
+            TransactionType::NFTokenCancelOffer as u16,
+        )?;
+        self.common.serialize(s)?;
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        s.serialize_vector256("NFTokenOffers", &self.nftoken_offers)?;
+        Ok(())
+    }
+}
+
+impl Deserialize for NFTokenCancelOfferTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::NFTokenCancelOffer)?;
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            nftoken_offers: required("NFTokenOffers", d.deserialize_vector256("NFTokenOffers")?)?,
+        })
+    }
+}
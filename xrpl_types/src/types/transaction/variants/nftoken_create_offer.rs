@@ -0,0 +1,126 @@
+use crate::deserialize::{required, Deserialize, Deserializer};
+use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_flags, expect_transaction_type};
+use crate::{
+    AccountId, Amount, Hash256, LedgerTimestamp, Transaction, TransactionCommon, TransactionType,
+};
+use enumflags2::{bitflags, BitFlags};
+
+/// An `NFTokenCreateOffer` transaction <https://xrpl.org/nftokencreateoffer.html>
+#[derive(Debug, Clone)]
+pub struct NFTokenCreateOfferTransaction {
+    pub common: TransactionCommon,
+    pub flags: BitFlags<NFTokenCreateOfferFlags>,
+    pub nftoken_id: Hash256,
+    /// Amount asked for a sell offer, or offered for a buy offer
+    pub amount: Amount,
+    /// Owner of the token, required for buy offers
+    pub owner: Option<AccountId>,
+    pub expiration: Option<LedgerTimestamp>,
+    /// The only account that can accept the offer
+    pub destination: Option<AccountId>,
+}
+
+impl NFTokenCreateOfferTransaction {
+    /// Offer to sell the token owned by the account
+    pub fn new_sell_offer(account_id: AccountId, nftoken_id: Hash256, amount: Amount) -> Self {
+        Self {
+            common: TransactionCommon::new(account_id),
+            flags: NFTokenCreateOfferFlags::SellNFToken.into(),
+            nftoken_id,
+            amount,
+            owner: None,
+            expiration: None,
+            destination: None,
+        }
+    }
+
+    /// Offer to buy the token from its owner
+    pub fn new_buy_offer(
+        account_id: AccountId,
+        nftoken_id: Hash256,
+        amount: Amount,
+        owner: AccountId,
+    ) -> Self {
+        Self {
+            common: TransactionCommon::new(account_id),
+            flags: Default::default(),
+            nftoken_id,
+            amount,
+            owner: Some(owner),
+            expiration: None,
+            destination: None,
+        }
+    }
+
+    pub fn expiration(self, expiration: LedgerTimestamp) -> Self {
+        Self {
+            expiration: Some(expiration),
+            ..self
+        }
+    }
+
+    pub fn destination(self, destination: AccountId) -> Self {
+        Self {
+            destination: Some(destination),
+            ..self
+        }
+    }
+}
+
+impl Transaction for NFTokenCreateOfferTransaction {
+    fn common(&self) -> &TransactionCommon {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut TransactionCommon {
+        &mut self.common
+    }
+}
+
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum NFTokenCreateOfferFlags {
+    FullyCanonicalSig = 0x80000000,
+    /// The offer is a sell offer, otherwise a buy offer
+    SellNFToken = 0x00000001,
+}
+
+impl Serialize for NFTokenCreateOfferTransaction {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16(
+            "TransactionType",
+            TransactionType::NFTokenCreateOffer as u16,
+        )?;
+        self.common.serialize(s)?;
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        s.serialize_hash256("NFTokenID", self.nftoken_id)?;
+        s.serialize_amount("Amount", self.amount)?;
+        if let Some(owner) = self.owner {
+            s.serialize_account_id("Owner", owner)?;
+        }
+        if let Some(expiration) = self.expiration {
+            s.serialize_uint32("Expiration", expiration)?;
+        }
+        if let Some(destination) = self.destination {
+            s.serialize_account_id("Destination", destination)?;
+        }
+        Ok(())
+    }
+}
+
+impl Deserialize for NFTokenCreateOfferTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::NFTokenCreateOffer)?;
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            nftoken_id: required("NFTokenID", d.deserialize_hash256("NFTokenID")?)?,
+            amount: required("Amount", d.deserialize_amount("Amount")?)?,
+            owner: d.deserialize_account_id("Owner")?,
+            expiration: d.deserialize_uint32("Expiration")?,
+            destination: d.deserialize_account_id("Destination")?,
+        })
+    }
+}
@@ -0,0 +1,146 @@
+use crate::alloc::format;
+use crate::deserialize::{required, Deserialize, Deserializer};
+use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_flags, expect_transaction_type};
+use crate::{
+    AccountId, Blob, Error, Transaction, TransactionCommon, TransactionType, UInt16, UInt32,
+};
+use enumflags2::{bitflags, BitFlags};
+
+/// An `NFTokenMint` transaction <https://xrpl.org/nftokenmint.html>
+#[derive(Debug, Clone)]
+pub struct NFTokenMintTransaction {
+    pub common: TransactionCommon,
+    pub flags: BitFlags<NFTokenMintFlags>,
+    /// Issuer defined taxon of the token
+    pub nftoken_taxon: UInt32,
+    /// Issuer of the token, if minted on behalf of the issuer by an authorized minter
+    pub issuer: Option<AccountId>,
+    /// Fee charged by the issuer on secondary sales, in units of 1/100,000, up to 50000 (50%).
+    /// Requires the `Transferable` flag.
+    pub transfer_fee: Option<UInt16>,
+    /// URI of the token data, at most 256 bytes
+    pub uri: Option<Blob>,
+}
+
+/// Maximum transfer fee, 50%
+const MAX_TRANSFER_FEE: UInt16 = 50000;
+
+impl NFTokenMintTransaction {
+    pub fn new(account_id: AccountId, nftoken_taxon: UInt32) -> Self {
+        Self {
+            common: TransactionCommon::new(account_id),
+            flags: Default::default(),
+            nftoken_taxon,
+            issuer: None,
+            transfer_fee: None,
+            uri: None,
+        }
+    }
+
+    pub fn issuer(self, issuer: AccountId) -> Self {
+        Self {
+            issuer: Some(issuer),
+            ..self
+        }
+    }
+
+    /// Sets the transfer fee and the `Transferable` flag it requires
+    pub fn transfer_fee(self, transfer_fee: UInt16) -> Result<Self, Error> {
+        check_transfer_fee(transfer_fee)?;
+        Ok(Self {
+            flags: self.flags | NFTokenMintFlags::Transferable,
+            transfer_fee: Some(transfer_fee),
+            ..self
+        })
+    }
+
+    pub fn uri(self, uri: Blob) -> Self {
+        Self {
+            uri: Some(uri),
+            ..self
+        }
+    }
+}
+
+impl Transaction for NFTokenMintTransaction {
+    fn common(&self) -> &TransactionCommon {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut TransactionCommon {
+        &mut self.common
+    }
+}
+
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum NFTokenMintFlags {
+    FullyCanonicalSig = 0x80000000,
+    /// The issuer can burn the token
+    Burnable = 0x00000001,
+    /// The token can only be offered or sold for XRP
+    OnlyXRP = 0x00000002,
+    /// Automatically create trust lines to hold transfer fees (deprecated)
+    TrustLine = 0x00000004,
+    /// The token can be transferred to accounts other than the issuer
+    Transferable = 0x00000008,
+}
+
+impl Serialize for NFTokenMintTransaction {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16("TransactionType", TransactionType::NFTokenMint as u16)?;
+        self.common.serialize(s)?;
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        s.serialize_uint32("NFTokenTaxon", self.nftoken_taxon)?;
+        if let Some(issuer) = self.issuer {
+            s.serialize_account_id("Issuer", issuer)?;
+        }
+        if let Some(transfer_fee) = self.transfer_fee {
+            s.serialize_uint16("TransferFee", transfer_fee)?;
+        }
+        if let Some(uri) = &self.uri {
+            s.serialize_blob("URI", uri)?;
+        }
+        Ok(())
+    }
+}
+
+impl Deserialize for NFTokenMintTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::NFTokenMint)?;
+        let common = TransactionCommon::deserialize(d)?;
+        let flags: BitFlags<NFTokenMintFlags> = deserialize_flags(d)?;
+        let nftoken_taxon = required("NFTokenTaxon", d.deserialize_uint32("NFTokenTaxon")?)?;
+        let issuer = d.deserialize_account_id("Issuer")?;
+        let transfer_fee = d.deserialize_uint16("TransferFee")?;
+        if let Some(transfer_fee) = transfer_fee {
+            check_transfer_fee(transfer_fee)?;
+            if !flags.contains(NFTokenMintFlags::Transferable) {
+                return Err(Error::InvalidData(
+                    "TransferFee requires the Transferable flag".into(),
+                )
+                .into());
+            }
+        }
+        Ok(Self {
+            common,
+            flags,
+            nftoken_taxon,
+            issuer,
+            transfer_fee,
+            uri: d.deserialize_blob("URI")?,
+        })
+    }
+}
+
+fn check_transfer_fee(transfer_fee: UInt16) -> Result<(), Error> {
+    if transfer_fee > MAX_TRANSFER_FEE {
+        return Err(Error::InvalidData(format!(
+            "Transfer fee {} exceeds the maximum of {}",
+            transfer_fee, MAX_TRANSFER_FEE
+        )));
+    }
+    Ok(())
+}
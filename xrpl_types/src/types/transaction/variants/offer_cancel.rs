@@ -1,4 +1,6 @@
+use crate::deserialize::{required, Deserialize, Deserializer};
 use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_flags, expect_transaction_type};
 use crate::{AccountId, Transaction, TransactionCommon, TransactionType, UInt32};
 use enumflags2::{bitflags, BitFlags};
 
@@ -46,3 +48,14 @@ impl Serialize for OfferCancelTransaction {
         Ok(())
     }
 }
+
+impl Deserialize for OfferCancelTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::OfferCancel)?;
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            offer_sequence: required("OfferSequence", d.deserialize_uint32("OfferSequence")?)?,
+        })
+    }
+}
@@ -1,4 +1,6 @@
+use crate::deserialize::{required, Deserialize, Deserializer};
 use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_flags, expect_transaction_type};
 use crate::{AccountId, Amount, Transaction, TransactionCommon, TransactionType, UInt32};
 use enumflags2::{bitflags, BitFlags};
 
@@ -64,3 +66,17 @@ impl Serialize for OfferCreateTransaction {
         Ok(())
     }
 }
+
+impl Deserialize for OfferCreateTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::OfferCreate)?;
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            expiration: d.deserialize_uint32("Expiration")?,
+            offer_sequence: d.deserialize_uint32("OfferSequence")?,
+            taker_gets: required("TakerGets", d.deserialize_amount("TakerGets")?)?,
+            taker_pays: required("TakerPays", d.deserialize_amount("TakerPays")?)?,
+        })
+    }
+}
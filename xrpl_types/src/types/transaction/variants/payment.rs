@@ -1,5 +1,10 @@
+use crate::alloc::vec::Vec;
+use crate::deserialize::{required, Deserialize, Deserializer};
 use crate::serialize::{Serialize, Serializer};
-use crate::{AccountId, Amount, Hash256, Transaction, TransactionCommon, TransactionType, UInt32};
+use crate::types::transaction::{deserialize_flags, expect_transaction_type};
+use crate::{
+    AccountId, Amount, Hash256, Path, Transaction, TransactionCommon, TransactionType, UInt32,
+};
 use enumflags2::{bitflags, BitFlags};
 
 /// An `Payment` transaction <https://xrpl.org/payment.html>
@@ -13,6 +18,9 @@ pub struct PaymentTransaction {
     pub invoice_id: Option<Hash256>,
     pub send_max: Option<Amount>,
     pub deliver_min: Option<Amount>,
+    /// Paths for cross-currency payments, the default path is used if empty
+    /// <https://xrpl.org/paths.html>
+    pub paths: Vec<Path>,
 }
 
 impl PaymentTransaction {
@@ -26,6 +34,7 @@ impl PaymentTransaction {
             invoice_id: None,
             send_max: None,
             deliver_min: None,
+            paths: Vec::new(),
         }
     }
 
@@ -44,6 +53,7 @@ impl PaymentTransaction {
             invoice_id: None,
             send_max: None,
             deliver_min: None,
+            paths: Vec::new(),
         }
     }
 
@@ -53,6 +63,17 @@ impl PaymentTransaction {
             ..self
         }
     }
+
+    pub fn send_max(self, send_max: Amount) -> Self {
+        Self {
+            send_max: Some(send_max),
+            ..self
+        }
+    }
+
+    pub fn paths(self, paths: Vec<Path>) -> Self {
+        Self { paths, ..self }
+    }
 }
 
 impl Transaction for PaymentTransaction {
@@ -95,6 +116,26 @@ impl Serialize for PaymentTransaction {
         if let Some(deliver_min) = self.deliver_min {
             s.serialize_amount("DeliverMin", deliver_min)?;
         }
+        if !self.paths.is_empty() {
+            s.serialize_path_set("Paths", &self.paths)?;
+        }
         Ok(())
     }
 }
+
+impl Deserialize for PaymentTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::Payment)?;
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            amount: required("Amount", d.deserialize_amount("Amount")?)?,
+            destination: required("Destination", d.deserialize_account_id("Destination")?)?,
+            destination_tag: d.deserialize_uint32("DestinationTag")?,
+            invoice_id: d.deserialize_hash256("InvoiceID")?,
+            send_max: d.deserialize_amount("SendMax")?,
+            deliver_min: d.deserialize_amount("DeliverMin")?,
+            paths: d.deserialize_path_set("Paths")?.unwrap_or_default(),
+        })
+    }
+}
@@ -0,0 +1,115 @@
+use crate::deserialize::{required, Deserialize, Deserializer};
+use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_drops, deserialize_flags, expect_transaction_type};
+use crate::{
+    AccountId, Amount, Blob, DropsAmount, Hash256, Transaction, TransactionCommon, TransactionType,
+};
+use enumflags2::{bitflags, BitFlags};
+
+/// A `PaymentChannelClaim` transaction <https://xrpl.org/paymentchannelclaim.html>
+#[derive(Debug, Clone)]
+pub struct PaymentChannelClaimTransaction {
+    pub common: TransactionCommon,
+    pub flags: BitFlags<PaymentChannelClaimFlags>,
+    pub channel: Hash256,
+    /// Total amount of XRP delivered by the channel after this claim
+    pub balance: Option<DropsAmount>,
+    /// Amount of XRP authorized by the claim signature
+    pub amount: Option<DropsAmount>,
+    /// Claim signature, required when the claim is submitted by the destination
+    pub signature: Option<Blob>,
+    /// Public key the claim is signed with, required together with the signature
+    pub public_key: Option<Blob>,
+}
+
+impl PaymentChannelClaimTransaction {
+    pub fn new(account_id: AccountId, channel: Hash256) -> Self {
+        Self {
+            common: TransactionCommon::new(account_id),
+            flags: Default::default(),
+            channel,
+            balance: None,
+            amount: None,
+            signature: None,
+            public_key: None,
+        }
+    }
+
+    pub fn balance(self, balance: DropsAmount) -> Self {
+        Self {
+            balance: Some(balance),
+            ..self
+        }
+    }
+
+    /// Redeems a claim of the given amount, signed with the given public key
+    pub fn claim(self, amount: DropsAmount, signature: Blob, public_key: Blob) -> Self {
+        Self {
+            amount: Some(amount),
+            signature: Some(signature),
+            public_key: Some(public_key),
+            ..self
+        }
+    }
+}
+
+impl Transaction for PaymentChannelClaimTransaction {
+    fn common(&self) -> &TransactionCommon {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut TransactionCommon {
+        &mut self.common
+    }
+}
+
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PaymentChannelClaimFlags {
+    FullyCanonicalSig = 0x80000000,
+    /// Clear the expiration time of the channel, only the source can set it
+    Renew = 0x00010000,
+    /// Request to close the channel
+    Close = 0x00020000,
+}
+
+impl Serialize for PaymentChannelClaimTransaction {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16(
+            "TransactionType",
+            TransactionType::PaymentChannelClaim as u16,
+        )?;
+        self.common.serialize(s)?;
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        s.serialize_hash256("Channel", self.channel)?;
+        if let Some(balance) = self.balance {
+            s.serialize_amount("Balance", Amount::Drops(balance))?;
+        }
+        if let Some(amount) = self.amount {
+            s.serialize_amount("Amount", Amount::Drops(amount))?;
+        }
+        if let Some(signature) = &self.signature {
+            s.serialize_blob("Signature", signature)?;
+        }
+        if let Some(public_key) = &self.public_key {
+            s.serialize_blob("PublicKey", public_key)?;
+        }
+        Ok(())
+    }
+}
+
+impl Deserialize for PaymentChannelClaimTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::PaymentChannelClaim)?;
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            channel: required("Channel", d.deserialize_hash256("Channel")?)?,
+            balance: deserialize_drops(d, "Balance")?,
+            amount: deserialize_drops(d, "Amount")?,
+            signature: d.deserialize_blob("Signature")?,
+            public_key: d.deserialize_blob("PublicKey")?,
+        })
+    }
+}
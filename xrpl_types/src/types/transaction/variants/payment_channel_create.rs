@@ -0,0 +1,114 @@
+use crate::deserialize::{required, Deserialize, Deserializer};
+use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_drops, deserialize_flags, expect_transaction_type};
+use crate::{
+    AccountId, Amount, Blob, DropsAmount, LedgerTimestamp, Transaction, TransactionCommon,
+    TransactionType, UInt32,
+};
+use enumflags2::{bitflags, BitFlags};
+
+/// A `PaymentChannelCreate` transaction <https://xrpl.org/paymentchannelcreate.html>
+#[derive(Debug, Clone)]
+pub struct PaymentChannelCreateTransaction {
+    pub common: TransactionCommon,
+    pub flags: BitFlags<PaymentChannelCreateFlags>,
+    /// Amount of XRP to set aside in the channel
+    pub amount: DropsAmount,
+    pub destination: AccountId,
+    /// Seconds the source must wait before closing a channel with unclaimed XRP
+    pub settle_delay: UInt32,
+    /// Public key of the key pair that signs claims against the channel
+    pub public_key: Blob,
+    pub cancel_after: Option<LedgerTimestamp>,
+    pub destination_tag: Option<UInt32>,
+}
+
+impl PaymentChannelCreateTransaction {
+    pub fn new(
+        account_id: AccountId,
+        amount: DropsAmount,
+        destination: AccountId,
+        settle_delay: UInt32,
+        public_key: Blob,
+    ) -> Self {
+        Self {
+            common: TransactionCommon::new(account_id),
+            flags: Default::default(),
+            amount,
+            destination,
+            settle_delay,
+            public_key,
+            cancel_after: None,
+            destination_tag: None,
+        }
+    }
+
+    pub fn cancel_after(self, cancel_after: LedgerTimestamp) -> Self {
+        Self {
+            cancel_after: Some(cancel_after),
+            ..self
+        }
+    }
+
+    pub fn destination_tag(self, tag: UInt32) -> Self {
+        Self {
+            destination_tag: Some(tag),
+            ..self
+        }
+    }
+}
+
+impl Transaction for PaymentChannelCreateTransaction {
+    fn common(&self) -> &TransactionCommon {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut TransactionCommon {
+        &mut self.common
+    }
+}
+
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PaymentChannelCreateFlags {
+    FullyCanonicalSig = 0x80000000,
+}
+
+impl Serialize for PaymentChannelCreateTransaction {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16(
+            "TransactionType",
+            TransactionType::PaymentChannelCreate as u16,
+        )?;
+        self.common.serialize(s)?;
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        s.serialize_amount("Amount", Amount::Drops(self.amount))?;
+        s.serialize_account_id("Destination", self.destination)?;
+        s.serialize_uint32("SettleDelay", self.settle_delay)?;
+        s.serialize_blob("PublicKey", &self.public_key)?;
+        if let Some(cancel_after) = self.cancel_after {
+            s.serialize_uint32("CancelAfter", cancel_after)?;
+        }
+        if let Some(destination_tag) = self.destination_tag {
+            s.serialize_uint32("DestinationTag", destination_tag)?;
+        }
+        Ok(())
+    }
+}
+
+impl Deserialize for PaymentChannelCreateTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::PaymentChannelCreate)?;
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            amount: required("Amount", deserialize_drops(d, "Amount")?)?,
+            destination: required("Destination", d.deserialize_account_id("Destination")?)?,
+            settle_delay: required("SettleDelay", d.deserialize_uint32("SettleDelay")?)?,
+            public_key: required("PublicKey", d.deserialize_blob("PublicKey")?)?,
+            cancel_after: d.deserialize_uint32("CancelAfter")?,
+            destination_tag: d.deserialize_uint32("DestinationTag")?,
+        })
+    }
+}
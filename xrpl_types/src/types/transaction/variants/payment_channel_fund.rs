@@ -0,0 +1,86 @@
+use crate::deserialize::{required, Deserialize, Deserializer};
+use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_drops, deserialize_flags, expect_transaction_type};
+use crate::{
+    AccountId, Amount, DropsAmount, Hash256, LedgerTimestamp, Transaction, TransactionCommon,
+    TransactionType,
+};
+use enumflags2::{bitflags, BitFlags};
+
+/// A `PaymentChannelFund` transaction <https://xrpl.org/paymentchannelfund.html>
+#[derive(Debug, Clone)]
+pub struct PaymentChannelFundTransaction {
+    pub common: TransactionCommon,
+    pub flags: BitFlags<PaymentChannelFundFlags>,
+    pub channel: Hash256,
+    /// Amount of XRP to add to the channel
+    pub amount: DropsAmount,
+    /// New expiration time of the channel
+    pub expiration: Option<LedgerTimestamp>,
+}
+
+impl PaymentChannelFundTransaction {
+    pub fn new(account_id: AccountId, channel: Hash256, amount: DropsAmount) -> Self {
+        Self {
+            common: TransactionCommon::new(account_id),
+            flags: Default::default(),
+            channel,
+            amount,
+            expiration: None,
+        }
+    }
+
+    pub fn expiration(self, expiration: LedgerTimestamp) -> Self {
+        Self {
+            expiration: Some(expiration),
+            ..self
+        }
+    }
+}
+
+impl Transaction for PaymentChannelFundTransaction {
+    fn common(&self) -> &TransactionCommon {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut TransactionCommon {
+        &mut self.common
+    }
+}
+
+#[bitflags]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PaymentChannelFundFlags {
+    FullyCanonicalSig = 0x80000000,
+}
+
+impl Serialize for PaymentChannelFundTransaction {
+    fn serialize<S: Serializer>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.serialize_uint16(
+            "TransactionType",
+            TransactionType::PaymentChannelFund as u16,
+        )?;
+        self.common.serialize(s)?;
+        s.serialize_uint32("Flags", self.flags.bits())?;
+        s.serialize_hash256("Channel", self.channel)?;
+        s.serialize_amount("Amount", Amount::Drops(self.amount))?;
+        if let Some(expiration) = self.expiration {
+            s.serialize_uint32("Expiration", expiration)?;
+        }
+        Ok(())
+    }
+}
+
+impl Deserialize for PaymentChannelFundTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::PaymentChannelFund)?;
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            channel: required("Channel", d.deserialize_hash256("Channel")?)?,
+            amount: required("Amount", deserialize_drops(d, "Amount")?)?,
+            expiration: d.deserialize_uint32("Expiration")?,
+        })
+    }
+}
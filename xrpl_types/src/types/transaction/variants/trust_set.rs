@@ -1,6 +1,8 @@
+use crate::deserialize::{required, Deserialize, Deserializer};
 use crate::serialize::{Serialize, Serializer};
+use crate::types::transaction::{deserialize_flags, expect_transaction_type};
 use crate::{
-    AccountId, Amount, IssuedAmount, Transaction, TransactionCommon, TransactionType, UInt32,
+    AccountId, Amount, Error, IssuedAmount, Transaction, TransactionCommon, TransactionType, UInt32,
 };
 use enumflags2::{bitflags, make_bitflags, BitFlags};
 
@@ -76,3 +78,24 @@ impl Serialize for TrustSetTransaction {
         Ok(())
     }
 }
+
+impl Deserialize for TrustSetTransaction {
+    fn deserialize<D: Deserializer>(d: &mut D) -> Result<Self, D::Error> {
+        expect_transaction_type(d, TransactionType::TrustSet)?;
+        let limit_amount = match required("LimitAmount", d.deserialize_amount("LimitAmount")?)? {
+            Amount::Issued(limit_amount) => limit_amount,
+            Amount::Drops(_) => {
+                return Err(
+                    Error::InvalidData("LimitAmount must be an issued amount".into()).into(),
+                )
+            }
+        };
+        Ok(Self {
+            common: TransactionCommon::deserialize(d)?,
+            flags: deserialize_flags(d)?,
+            limit_amount,
+            quality_in: d.deserialize_uint32("QualityIn")?,
+            quality_out: d.deserialize_uint32("QualityOut")?,
+        })
+    }
+}
@@ -0,0 +1,16 @@
+use crate::{AccountId, Currency};
+
+/// Bridge between a locking chain and an issuing chain, the value of `XChainBridge` fields.
+/// See <https://xrpl.org/docs/concepts/xrpl-sidechains/cross-chain-bridges> and
+/// <https://xrpl.org/docs/references/protocol/binary-format#xchainbridge-fields>
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct XChainBridge {
+    /// Door account on the locking chain
+    pub locking_chain_door: AccountId,
+    /// Asset that is locked on the locking chain
+    pub locking_chain_issue: Currency,
+    /// Door account on the issuing chain
+    pub issuing_chain_door: AccountId,
+    /// Asset that is issued on the issuing chain
+    pub issuing_chain_issue: Currency,
+}